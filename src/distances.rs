@@ -10,7 +10,8 @@ use crate::ebi_traits::{
     ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage,
 };
 use crate::math::fraction::Fraction;
-use crate::math::levenshtein;
+use crate::ebi_framework::activity_key::ActivityKey;
+use crate::math::trace_distance::TraceDistance;
 
 pub struct TriangularDistanceMatrix {
     len: usize, //lenght of one side of the matrix
@@ -19,11 +20,11 @@ pub struct TriangularDistanceMatrix {
 }
 
 impl TriangularDistanceMatrix {
-    pub fn new<T>(log: &T) -> Self
+    pub fn new<T>(log: &T, activity_key: &ActivityKey, distance: &TraceDistance) -> Self
     where
         T: IndexTrace + ?Sized,
     {
-        log::info!("Compute distances");
+        log::info!("Compute {} distances", distance);
         let progress_bar =
             EbiCommand::get_progress_bar_ticks(Self::get_number_of_distances(log.len()));
 
@@ -40,7 +41,7 @@ impl TriangularDistanceMatrix {
                 let trace1 = log.get_trace(i).unwrap();
                 let trace2 = log.get_trace(j).unwrap();
 
-                let result = levenshtein::normalised(trace1, trace2);
+                let result = distance.distance(activity_key, trace1, trace2);
                 progress_bar.inc(1);
                 result
             })
@@ -182,7 +183,7 @@ pub struct DistanceMatrix {
 }
 
 impl DistanceMatrix {
-    pub fn new<L, K>(lang_a: &L, lang_b: &K) -> Self
+    pub fn new<L, K>(lang_a: &L, lang_b: &K, distance: &TraceDistance) -> Self
     where
         L: EbiTraitFiniteStochasticLanguage + ?Sized,
         K: EbiTraitFiniteStochasticLanguage + ?Sized,
@@ -192,7 +193,7 @@ impl DistanceMatrix {
         //lang_b.translate(&mut target_activity_key);
         let lang_b_translated = lang_b.translate_using_activity_key(&mut target_activity_key);
 
-        log::info!("Compute distances");
+        log::info!("Compute {} distances", distance);
         let len_a = lang_a.len();
         let len_b = lang_b_translated.len();
 
@@ -223,7 +224,7 @@ impl DistanceMatrix {
                         .into_par_iter()
                         .map(|j| {
                             let trace_b = &traces_b[j];
-                            let result = levenshtein::normalised(trace_a, trace_b);
+                            let result = distance.distance(&target_activity_key, trace_a, trace_b);
                             progress_bar.inc(1);
                             Arc::new(result)
                         })
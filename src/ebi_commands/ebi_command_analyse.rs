@@ -1,6 +1,8 @@
-use anyhow::Context;
+use std::collections::HashMap;
+use anyhow::{anyhow, Context, Result};
+use clap::{builder::PossibleValuesParser, Arg, ArgAction, ArgMatches, Command};
 
-use crate::{ebi_framework::{ebi_command::EbiCommand, ebi_input::{EbiInput, EbiInputType}, ebi_object::{EbiObject, EbiObjectType, EbiTraitObject}, ebi_output::{EbiOutput, EbiOutputType}, ebi_trait::EbiTrait}, ebi_traits::{ebi_trait_event_log::EbiTraitEventLog, ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_stochastic_deterministic_semantics::EbiTraitStochasticDeterministicSemantics}, math::fraction::Fraction, medoid, techniques::{completeness::Completeness, probability_queries::ProbabilityQueries, process_variety::ProcessVariety}};
+use crate::{ebi_framework::{ebi_command::EbiCommand, ebi_input::{EbiInput, EbiInputType}, ebi_object::{EbiObject, EbiObjectType, EbiTraitObject}, ebi_output::{EbiOutput, EbiOutputType}, ebi_trait::EbiTrait}, ebi_traits::{ebi_trait_event_log::EbiTraitEventLog, ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_stochastic_deterministic_semantics::EbiTraitStochasticDeterministicSemantics}, math::{fraction::Fraction, trace_distance::{TraceDistance, TRACE_DISTANCE_NAMES}}, medoid, techniques::{completeness::Completeness, probability_queries::ProbabilityQueries, process_variety::ProcessVariety}};
 
 pub const EBI_ANALYSE: EbiCommand = EbiCommand::Group {
     name_short: "ana",
@@ -204,10 +206,10 @@ pub const EBI_ANALYSE_MEDOID: EbiCommand = EbiCommand::Command {
     name_short: "med", 
     name_long: Some("medoid"),
     explanation_short: "Find the traces with the least distance to the other traces.", 
-    explanation_long: Some("Find the traces with the lowest average normalised Levenshtein distance to the other traces.
+    explanation_long: Some("Find the traces with the lowest average distance to the other traces; by default, the normalised Levenshtein distance is used.
 If there are more than one such trace, an arbitrary one is returned."), 
     latex_link: None, 
-    cli_command: None, 
+    cli_command: Some(|command| cli_distance(command, "normalised-levenshtein")), 
     exact_arithmetic: true, 
     input_types: &[ 
         &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)], 
@@ -215,10 +217,11 @@ If there are more than one such trace, an arbitrary one is returned."),
     ],
     input_names: &[ "FILE", "NUMBER_OF_TRACES"],
     input_helps: &[ "Any object with a finite stochastic language.", "The number of traces that should be extracted."],
-    execute: |mut objects, _| {
+    execute: |mut objects, cli_matches| {
         let language = objects.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
         let number_of_traces = objects.remove(0).to_type::<usize>()?;
-        let result = medoid::medoid(language.as_ref(), &number_of_traces)?;
+        let distance = cli_matches.map_or(Ok(TraceDistance::default()), get_distance)?;
+        let result = medoid::medoid(language.as_ref(), &number_of_traces, &distance)?;
         return Ok(EbiOutput::Object(EbiObject::FiniteLanguage(result)));
     }, 
     output_type: &EbiOutputType::ObjectType(EbiObjectType::FiniteLanguage)
@@ -228,19 +231,58 @@ pub const EBI_ANALYSE_VARIETY: EbiCommand = EbiCommand::Command {
     name_short: "var", 
     name_long: Some("variety"), 
     explanation_short: "Compute the variety of a stochastic language. That is, the average distance between two arbitrary traces in the language.", 
-    explanation_long: None,
-    cli_command: None, 
+    explanation_long: Some("Compute the variety of a stochastic language. That is, the average distance between two arbitrary traces in the language; by default, the Levenshtein distance is used."),
+    cli_command: Some(|command| cli_distance(command, "levenshtein")), 
     latex_link: None,
     exact_arithmetic: true,
     input_types: &[ &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage) ] ],
     input_names: &[ "FILE" ],
     input_helps: &[ "An event log."],
-    execute: |mut objects, _| {
+    execute: |mut objects, cli_matches| {
         let log = objects.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let distance = cli_matches.map_or(Ok(TraceDistance::Levenshtein), get_distance)?;
         
-        let result = log.process_variety();
+        let result = log.process_variety(&distance);
         
         return Ok(EbiOutput::Fraction(result));
     }, 
     output_type: &EbiOutputType::Fraction
-};
\ No newline at end of file
+};
+
+pub fn cli_distance(command: Command, default: &'static str) -> Command {
+    command.arg(
+        Arg::new("distance")
+        .action(ArgAction::Set)
+        .value_name("DISTANCE")
+        .long("distance")
+        .help("Use this distance between traces.")
+        .default_value(default)
+        .value_parser(PossibleValuesParser::new(TRACE_DISTANCE_NAMES.iter().copied()))
+        .required(false)
+    ).arg(
+        Arg::new("activitycost")
+        .action(ArgAction::Append)
+        .value_name("ACTIVITY=COST")
+        .long("activity-cost")
+        .help("Use this cost for inserting and deleting the activity in the weighted-edit distance; may be given multiple times. Activities without a cost cost 1.")
+        .required(false)
+    )
+}
+
+pub fn get_distance(cli_matches: &ArgMatches) -> Result<TraceDistance> {
+    let name = cli_matches.get_one::<String>("distance").unwrap();
+
+    let mut activity_costs = HashMap::new();
+    if let Some(values) = cli_matches.get_many::<String>("activitycost") {
+        for value in values {
+            let (activity, cost) = value.rsplit_once('=').ok_or_else(|| anyhow!("activity cost `{}` is not of the form ACTIVITY=COST", value))?;
+            let cost = cost.parse::<Fraction>().with_context(|| format!("parsing the cost of activity `{}`", activity))?;
+            if cost.is_negative() {
+                return Err(anyhow!("the cost of activity `{}` is negative", activity));
+            }
+            activity_costs.insert(activity.to_string(), cost);
+        }
+    }
+
+    TraceDistance::new(name, activity_costs)
+}
@@ -1,4 +1,5 @@
-use crate::{ebi_framework::{ebi_command::EbiCommand, ebi_input::EbiInputType, ebi_object::{EbiObject, EbiObjectType}, ebi_output::{EbiOutput, EbiOutputType}, ebi_trait::EbiTrait}, ebi_traits::{ebi_trait_event_log::EbiTraitEventLog, ebi_trait_finite_language::EbiTraitFiniteLanguage, ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_semantics::EbiTraitSemantics}, techniques::{align::Align, executions::FindExecutions, medoid_non_stochastic::MedoidNonStochastic}};
+use super::ebi_command_analyse::{cli_distance, get_distance};
+use crate::{ebi_framework::{ebi_command::EbiCommand, ebi_input::{EbiInput, EbiInputType}, ebi_object::{EbiObject, EbiObjectType}, ebi_output::{EbiOutput, EbiOutputType}, ebi_trait::EbiTrait}, ebi_traits::{ebi_trait_event_log::EbiTraitEventLog, ebi_trait_finite_language::EbiTraitFiniteLanguage, ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_semantics::EbiTraitSemantics}, ebi_objects::labelled_petri_net::LabelledPetriNet, math::trace_distance::TraceDistance, techniques::{align::Align, coverability::Coverability, executions::FindExecutions, invariants::Invariants, medoid_non_stochastic::MedoidNonStochastic, soundness::Soundness}};
 
 
 pub const EBI_ANALYSE_NON_STOCHASTIC: EbiCommand = EbiCommand::Group {
@@ -7,10 +8,13 @@ pub const EBI_ANALYSE_NON_STOCHASTIC: EbiCommand = EbiCommand::Group {
     explanation_short: "Analyse a language without considering its stochastic perspective.",
     explanation_long: None,
     children: &[
+        &EBI_ANALYSE_NON_STOCHASTIC_BOUNDEDNESS,
         &EBI_ANALYSE_NON_STOCHASTIC_CLUSTER,
         &EBI_ANALYSE_NON_STOCHASTIC_MEDOID,
         &EBI_ANALYSE_NON_STOCHASTIC_ALIGNMENT,
         &EBI_ANALYSE_NON_STOCHASTIC_EXECUTIONS,
+        &EBI_ANALYSE_NON_STOCHASTIC_INVARIANTS,
+        &EBI_ANALYSE_NON_STOCHASTIC_SOUNDNESS,
     ],
 };
 
@@ -18,9 +22,9 @@ pub const EBI_ANALYSE_NON_STOCHASTIC_MEDOID: EbiCommand = EbiCommand::Command {
     name_short: "med", 
     name_long: Some("medoid"),
     explanation_short: "Find the traces with the least distance to the other traces, without considering the stochastic perspective.", 
-    explanation_long: Some("Find the traces with the lowest average normalised Levenshtein distance to the other traces; ties are resolved arbritrarily. The computation is random and does not take into account how often each trace occurs."), 
+    explanation_long: Some("Find the traces with the lowest average distance to the other traces; by default, the normalised Levenshtein distance is used. Ties are resolved arbritrarily. The computation is random and does not take into account how often each trace occurs."), 
     latex_link: None, 
-    cli_command: None, 
+    cli_command: Some(|command| cli_distance(command, "normalised-levenshtein")), 
     exact_arithmetic: true, 
     input_types: &[ 
         &[ &EbiInputType::Trait(EbiTrait::FiniteLanguage)], 
@@ -28,11 +32,12 @@ pub const EBI_ANALYSE_NON_STOCHASTIC_MEDOID: EbiCommand = EbiCommand::Command {
     ],
     input_names: &[ "FILE", "NUMBER_OF_TRACES"],
     input_helps: &[ "The finite stochastic language.", "The number of traces that should be extracted."],
-    execute: |mut objects, _| {
+    execute: |mut objects, cli_matches| {
         let language = objects.remove(0).to_type::<dyn EbiTraitFiniteLanguage>()?;
         let number_of_traces = objects.remove(0).to_type::<usize>()?;
+        let distance = cli_matches.map_or(Ok(TraceDistance::default()), get_distance)?;
         let language: Box<dyn EbiTraitFiniteLanguage> = language;
-        let result = language.medoid(*number_of_traces)?;
+        let result = language.medoid(*number_of_traces, &distance)?;
         return Ok(EbiOutput::Object(EbiObject::FiniteLanguage(result)));
     }, 
     output_type: &EbiOutputType::ObjectType(EbiObjectType::FiniteLanguage)
@@ -44,7 +49,7 @@ pub const EBI_ANALYSE_NON_STOCHASTIC_CLUSTER: EbiCommand = EbiCommand::Command {
     explanation_short: "Apply k-medoid clustering on a finite set of traces, without considering the stochastic perspective.", 
     explanation_long: Some("Apply k-medoid clustering: group the traces into a given number of clusters, such that the average distance of each trace to its closest medoid is minimal. The computation is random and does not take into account how often each trace occurs."), 
     latex_link: Some("~\\cite{DBLP:journals/is/SchubertR21}"), 
-    cli_command: None, 
+    cli_command: Some(|command| cli_distance(command, "normalised-levenshtein")), 
     exact_arithmetic: true, 
     input_types: &[ 
         &[ &EbiInputType::Trait(EbiTrait::FiniteLanguage)], 
@@ -52,10 +57,11 @@ pub const EBI_ANALYSE_NON_STOCHASTIC_CLUSTER: EbiCommand = EbiCommand::Command {
     ],
     input_names: &[ "LANGUAGE", "NUMBER_OF_CLUSTERS"],
     input_helps: &[ "The finite stochastic language.", "The number of clusters."],
-    execute: |mut objects, _| {
+    execute: |mut objects, cli_matches| {
         let language = objects.remove(0).to_type::<dyn EbiTraitFiniteLanguage>()?;
         let number_of_clusters = objects.remove(0).to_type::<usize>()?;
-        let result = language.k_medoids_clustering(*number_of_clusters)?;
+        let distance = cli_matches.map_or(Ok(TraceDistance::default()), get_distance)?;
+        let result = language.k_medoids_clustering(*number_of_clusters, &distance)?;
         return Ok(EbiOutput::Object(EbiObject::FiniteLanguage(result)));
     }, 
     output_type: &EbiOutputType::ObjectType(EbiObjectType::FiniteLanguage)
@@ -109,4 +115,90 @@ pub const EBI_ANALYSE_NON_STOCHASTIC_EXECUTIONS: EbiCommand = EbiCommand::Comman
         return Ok(EbiOutput::Object(EbiObject::Executions(result)));
     }, 
     output_type: &EbiOutputType::ObjectType(EbiObjectType::Executions)
-};
\ No newline at end of file
+};
+
+pub const EBI_ANALYSE_NON_STOCHASTIC_SOUNDNESS: EbiCommand = EbiCommand::Command {
+    name_short: "sound", 
+    name_long: Some("soundness"),
+    explanation_short: "Check whether a workflow net is sound.", 
+    explanation_long: Some("Check whether a workflow net is sound, that is, whether the final marking can be reached from every reachable marking (option to complete), whether the sink place is never marked together with other tokens (proper completion), and whether every transition can fire (no dead transitions).\nThe net must have a single source place, which holds the only token of the initial marking, and a single sink place, which marks the final marking. The check uses the coverability graph, such that it terminates on unbounded nets as well, though it may take a long time for large nets. The report lists the dead transitions and the improper final markings."), 
+    latex_link: Some("Soundness according to the definition of Van der Aalst~\\cite{DBLP:journals/fac/AalstHHSVVW11}."), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Object(EbiObjectType::LabelledPetriNet)],
+    ],
+    input_names: &[ "FILE"],
+    input_helps: &[ "The workflow net."],
+    execute: |mut objects, _| {
+        let net = objects.remove(0).to_type::<LabelledPetriNet>()?;
+        
+        let result = net.check_soundness()?;
+        
+        return Ok(EbiOutput::String(result.to_string()));
+    }, 
+    output_type: &EbiOutputType::String
+};
+
+pub const EBI_ANALYSE_NON_STOCHASTIC_BOUNDEDNESS: EbiCommand = EbiCommand::Command {
+    name_short: "bnd", 
+    name_long: Some("boundedness"),
+    explanation_short: "Compute for each place of a Petri net whether it is bounded, and its bound.", 
+    explanation_long: Some("Compute the Karp-Miller coverability graph of a Petri net, and report for each place the maximum number of tokens it can hold, or that it can hold an unbounded number of tokens.\nFor stochastic labelled Petri nets, transitions with weight zero are not considered, as they can never fire. The coverability graph is finite for unbounded nets as well, though it may be large."), 
+    latex_link: Some("~\\cite{DBLP:journals/jcss/KarpM69}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ 
+            &EbiInputType::Object(EbiObjectType::StochasticLabelledPetriNet),
+            &EbiInputType::Object(EbiObjectType::LabelledPetriNet),
+        ],
+    ],
+    input_names: &[ "FILE"],
+    input_helps: &[ "The Petri net."],
+    execute: |mut objects, _| {
+        let graph = match objects.remove(0) {
+            EbiInput::Object(EbiObject::StochasticLabelledPetriNet(slpn), _) => slpn.get_coverability_graph()?,
+            input => input.to_type::<LabelledPetriNet>()?.get_coverability_graph()?,
+        };
+        
+        let bounds = graph.get_place_bounds();
+        let mut result = if bounds.iter().all(|bound| bound.is_some()) {
+            "The net is bounded.\n".to_string()
+        } else {
+            "The net is unbounded.\n".to_string()
+        };
+        for (place, bound) in bounds.into_iter().enumerate() {
+            match bound {
+                Some(bound) => result.push_str(&format!("place p{}\tbounded by {}\n", place, bound)),
+                None => result.push_str(&format!("place p{}\tunbounded\n", place)),
+            }
+        }
+        
+        return Ok(EbiOutput::String(result));
+    }, 
+    output_type: &EbiOutputType::String
+};
+
+pub const EBI_ANALYSE_NON_STOCHASTIC_INVARIANTS: EbiCommand = EbiCommand::Command {
+    name_short: "inv", 
+    name_long: Some("invariants"),
+    explanation_short: "Compute the minimal place and transition invariants of a Petri net.", 
+    explanation_long: Some("Compute the minimal place invariants (P-semiflows) and transition invariants (T-semiflows) of a Petri net using the Farkas algorithm with exact integer arithmetic.\nA place invariant is a weighting of the places such that the weighted sum of tokens is the same in every reachable marking. A transition invariant is a number of firings for each transition such that firing them all returns the net to the marking in which it started.\nThe number of minimal invariants may be exponential in the size of the net."), 
+    latex_link: Some("~\\cite{DBLP:journals/pieee/Murata89}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Object(EbiObjectType::LabelledPetriNet)],
+    ],
+    input_names: &[ "FILE"],
+    input_helps: &[ "The Petri net."],
+    execute: |mut objects, _| {
+        let net = objects.remove(0).to_type::<LabelledPetriNet>()?;
+        
+        let result = net.get_invariants()?;
+        
+        return Ok(EbiOutput::String(result.to_string()));
+    }, 
+    output_type: &EbiOutputType::String
+};
@@ -0,0 +1,66 @@
+use anyhow::Context;
+
+use crate::{ebi_framework::{ebi_command::EbiCommand, ebi_input::EbiInputType, ebi_object::{EbiObject, EbiObjectType}, ebi_output::{EbiOutput, EbiOutputType}, ebi_trait::EbiTrait}, ebi_traits::ebi_trait_semantics::EbiTraitSemantics, techniques::language_comparison::LanguageComparison};
+
+pub const EBI_COMPARE: EbiCommand = EbiCommand::Group {
+    name_short: "comp",
+    name_long: Some("compare"),
+    explanation_short: "Compare the languages of two models.",
+    explanation_long: None,
+    children: &[
+        &EBI_COMPARE_EQUIVALENCE,
+        &EBI_COMPARE_INCLUSION,
+    ]
+};
+
+pub const EBI_COMPARE_INCLUSION: EbiCommand = EbiCommand::Command {
+    name_short: "incl",
+    name_long: Some("inclusion"),
+    explanation_short: "Check whether the language of the first model is included in the language of the second model.",
+    explanation_long: Some("Check whether every trace of the first model is supported by the second model.
+Both models are determinised and minimised, and their product is searched breadth-first.
+The result is empty if the language is included, and otherwise contains a shortest trace of the first model that the second model does not support.
+The models must be bounded."),
+    latex_link: None,
+    cli_command: None,
+    exact_arithmetic: true,
+    input_types: &[
+        &[ &EbiInputType::Trait(EbiTrait::Semantics) ],
+        &[ &EbiInputType::Trait(EbiTrait::Semantics) ],
+    ],
+    input_names: &[ "FILE_1", "FILE_2" ],
+    input_helps: &[ "The model of which the language should be included.", "The model of which the language should include the other language." ],
+    execute: |mut inputs, _| {
+        let semantics1 = inputs.remove(0).to_type::<EbiTraitSemantics>()?;
+        let semantics2 = inputs.remove(0).to_type::<EbiTraitSemantics>()?;
+        let result = semantics1.check_inclusion(&semantics2).context("Checking language inclusion.")?;
+        Ok(EbiOutput::Object(EbiObject::FiniteLanguage(result)))
+    },
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::FiniteLanguage)
+};
+
+pub const EBI_COMPARE_EQUIVALENCE: EbiCommand = EbiCommand::Command {
+    name_short: "eq",
+    name_long: Some("equivalence"),
+    explanation_short: "Check whether two models have the same language.",
+    explanation_long: Some("Check whether two models support exactly the same traces.
+Both models are determinised and minimised, and their product is searched breadth-first.
+The result is empty if the languages are equal, and otherwise contains a shortest trace that is supported by one model but not by the other.
+The models must be bounded."),
+    latex_link: None,
+    cli_command: None,
+    exact_arithmetic: true,
+    input_types: &[
+        &[ &EbiInputType::Trait(EbiTrait::Semantics) ],
+        &[ &EbiInputType::Trait(EbiTrait::Semantics) ],
+    ],
+    input_names: &[ "FILE_1", "FILE_2" ],
+    input_helps: &[ "A model.", "A model." ],
+    execute: |mut inputs, _| {
+        let semantics1 = inputs.remove(0).to_type::<EbiTraitSemantics>()?;
+        let semantics2 = inputs.remove(0).to_type::<EbiTraitSemantics>()?;
+        let result = semantics1.check_equivalence(&semantics2).context("Checking language equivalence.")?;
+        Ok(EbiOutput::Object(EbiObject::FiniteLanguage(result)))
+    },
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::FiniteLanguage)
+};
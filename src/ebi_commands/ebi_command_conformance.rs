@@ -1,6 +1,6 @@
 use anyhow::{anyhow, Context};
 
-use super::ebi_command_sample::{self, SAMPLED_OBJECT_INPUTS};
+use super::{ebi_command_analyse::{cli_distance, get_distance}, ebi_command_sample::{self, SAMPLED_OBJECT_INPUTS}};
 use crate::{
     ebi_framework::{
         ebi_command::EbiCommand,
@@ -13,6 +13,7 @@ use crate::{
         ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage,
         ebi_trait_queriable_stochastic_language::EbiTraitQueriableStochasticLanguage,
     },
+    math::trace_distance::TraceDistance,
     techniques::{
         earth_movers_stochastic_conformance::EarthMoversStochasticConformance,
         entropic_relevance::EntropicRelvance,
@@ -265,9 +266,9 @@ pub const CONFORMANCE_EMSC: EbiCommand = EbiCommand::Command {
     name_short: "emsc",
     name_long: Some("earth-mover"),
     explanation_short: "Compute Earth Mover's stochastic conformance.",
-    explanation_long: None,
+    explanation_long: Some("Compute Earth Mover's stochastic conformance; by default, the normalised Levenshtein distance is used as the distance between traces. With the zero-one distance, this is one minus the total variation distance."),
     latex_link: None,
-    cli_command: None,
+    cli_command: Some(|command| cli_distance(command, "normalised-levenshtein")),
     exact_arithmetic: true,
     input_types: &[
         &[&EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)],
@@ -278,10 +279,11 @@ pub const CONFORMANCE_EMSC: EbiCommand = EbiCommand::Command {
         "A finite stochastic language to compare.",
         "A finite stochastic language to compare.",
     ],
-    execute: |mut inputs, _| {
+    execute: |mut inputs, cli_matches| {
         let lang_a = inputs
             .remove(0)
             .to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let distance = cli_matches.map_or(Ok(TraceDistance::default()), get_distance)?;
 
         let mut lang_b = inputs
             .remove(0)
@@ -290,7 +292,7 @@ pub const CONFORMANCE_EMSC: EbiCommand = EbiCommand::Command {
         // Compute EMSC
         Ok(EbiOutput::Fraction(
             lang_a
-                .earth_movers_stochastic_conformance(lang_b.as_mut())
+                .earth_movers_stochastic_conformance(lang_b.as_mut(), &distance)
                 .context("Compute EMSC.")?,
         ))
     },
@@ -301,9 +303,9 @@ pub const CONFORMANCE_EMSC_SAMPLE: EbiCommand = EbiCommand::Command {
     name_short: "emsc-sample",
     name_long: Some("earth-mover-sample"),
     explanation_short: "Compute Earth Mover's stochastic conformance with sampling.",
-    explanation_long: None,
+    explanation_long: Some("Compute Earth Mover's stochastic conformance with sampling; by default, the normalised Levenshtein distance is used as the distance between traces."),
     latex_link: None,
-    cli_command: None,
+    cli_command: Some(|command| cli_distance(command, "normalised-levenshtein")),
     exact_arithmetic: true,
     input_types: &[
         &[&EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)],
@@ -316,10 +318,11 @@ pub const CONFORMANCE_EMSC_SAMPLE: EbiCommand = EbiCommand::Command {
         "A queriable stochastic language to compare.",
         "Number of traces to sample.",
     ],
-    execute: |mut inputs, _| {
+    execute: |mut inputs, cli_matches| {
         let lang_a = inputs
             .remove(0)
             .to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let distance = cli_matches.map_or(Ok(TraceDistance::default()), get_distance)?;
 
         let sampled_object = inputs.remove(0);
         let number_of_traces = inputs.remove(0).to_type::<usize>()?;
@@ -333,7 +336,7 @@ pub const CONFORMANCE_EMSC_SAMPLE: EbiCommand = EbiCommand::Command {
         // Compute EMSC
         Ok(EbiOutput::Fraction(
             lang_a
-                .earth_movers_stochastic_conformance(lang_b.as_mut())
+                .earth_movers_stochastic_conformance(lang_b.as_mut(), &distance)
                 .context("Compute EMSC.")?,
         ))
     },
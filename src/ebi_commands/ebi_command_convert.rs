@@ -1,6 +1,7 @@
+use std::path::PathBuf;
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
 
-
-use crate::{ebi_framework::{ebi_command::EbiCommand, ebi_input::EbiInputType, ebi_object::{EbiObject, EbiObjectType}, ebi_output::{EbiOutput, EbiOutputType}}, ebi_objects::{finite_stochastic_language::FiniteStochasticLanguage, labelled_petri_net::LabelledPetriNet, stochastic_deterministic_finite_automaton::StochasticDeterministicFiniteAutomaton}};
+use crate::{ebi_framework::{ebi_command::EbiCommand, ebi_input::{self, EbiInput, EbiInputType}, ebi_object::{EbiObject, EbiObjectType, EbiTraitObject}, ebi_output::{EbiOutput, EbiOutputType}, ebi_trait::EbiTrait}, ebi_info, ebi_objects::{csv_event_log::{CsvEventLog, CsvImportOptions}, deterministic_finite_automaton::DeterministicFiniteAutomaton, finite_stochastic_language::FiniteStochasticLanguage, labelled_petri_net::LabelledPetriNet, object_centric_event_log::ObjectCentricEventLog, stochastic_deterministic_finite_automaton::StochasticDeterministicFiniteAutomaton, stochastic_directly_follows_model::StochasticDirectlyFollowsModel, stochastic_labelled_petri_net::DEFAULT_MAX_SDFA_STATES}, techniques::determinisation::{default_max_states, Determinise, DEFAULT_MAX_STATES}};
 
 pub const EBI_CONVERT: EbiCommand = EbiCommand::Group { 
     name_short: "conv",
@@ -8,12 +9,126 @@ pub const EBI_CONVERT: EbiCommand = EbiCommand::Group {
     explanation_short: "Convert an object into something else.", 
     explanation_long: None, 
     children: &[ 
+        &EBI_CONVERT_DFA,
+        &EBI_CONVERT_FLATTEN,
+        &EBI_CONVERT_LOG,
         &EBI_CONVERT_LPN,
         &EBI_CONVERT_SLANG,
-        &EBI_CONVERT_SDFA
+        &EBI_CONVERT_SDFA,
+        &EBI_CONVERT_SDFM,
     ]
 };
 
+pub const EBI_CONVERT_DFA: EbiCommand = EbiCommand::Command { 
+    name_short: "dfa", 
+    name_long: Some("deterministic-finite-automaton"),
+    explanation_short: "Convert an object to a minimal deterministic finite automaton.", 
+    explanation_long: Some(concat!("Convert an object to a deterministic finite automaton, and minimise it.
+For a model, its reachability graph is explored, silent transitions are removed by epsilon-closure, and the result is determinised.
+The conversion fails if the model has more than ", default_max_states!(), " reachable states, which is the case for unbounded models.")), 
+    latex_link: None, 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[
+            &EbiInputType::Object(EbiObjectType::DeterministicFiniteAutomaton),
+            &EbiInputType::Trait(EbiTrait::Semantics),
+        ] ], 
+    input_names: &[ "FILE" ], 
+    input_helps: &[ "Any file supported by Ebi that can be converted." ], 
+    execute: |mut inputs, _| {
+        let dfa = match inputs.remove(0) {
+            EbiInput::Trait(EbiTraitObject::Semantics(semantics), _) => semantics.determinise(DEFAULT_MAX_STATES)?,
+            input => *input.to_type::<DeterministicFiniteAutomaton>()?,
+        };
+        Ok(EbiOutput::Object(EbiObject::DeterministicFiniteAutomaton(dfa.minimise())))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::DeterministicFiniteAutomaton) 
+};
+
+pub const EBI_CONVERT_FLATTEN: EbiCommand = EbiCommand::Command { 
+    name_short: "flat", 
+    name_long: Some("flatten"),
+    explanation_short: "Flatten an object-centric event log on an object type.", 
+    explanation_long: Some("Flatten an object-centric event log on an object type into an event log.
+Each object of the given type becomes a trace, which consists of the events that refer to the object, ordered by time."), 
+    latex_link: None, 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Object(EbiObjectType::ObjectCentricEventLog) ],
+        &[ &EbiInputType::String ],
+    ], 
+    input_names: &[ "FILE", "OBJECT_TYPE" ], 
+    input_helps: &[ "An object-centric event log.", concat!(concat!("The object type to flatten on. The object types of a log can be found using `Ebi ", ebi_info!()), "`.") ], 
+    execute: |mut inputs, _| {
+        let log = inputs.remove(0).to_type::<ObjectCentricEventLog>()?;
+        let object_type = inputs.remove(0).to_type::<String>()?;
+        Ok(EbiOutput::Object(EbiObject::EventLog(log.flatten_on(&object_type)?)))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::EventLog) 
+};
+
+pub const EBI_CONVERT_LOG: EbiCommand = EbiCommand::Command { 
+    name_short: "log", 
+    name_long: Some("event-log"),
+    explanation_short: "Convert a CSV file to an event log.", 
+    explanation_long: Some("Convert a CSV file to an event log, using the given case, activity and timestamp columns.
+Columns that are not given are recognised by their names, as when a CSV file is read directly."), 
+    latex_link: None, 
+    cli_command: Some(|command| cli_csv_columns(command)), 
+    exact_arithmetic: true, 
+    input_types: &[ &[ &EbiInputType::String ] ], 
+    input_names: &[ "FILE" ], 
+    input_helps: &[ "A CSV file, or - to read from standard input." ], 
+    execute: |mut inputs, cli_matches| {
+        //the columns are not known to the importers of the framework, so this command reads the file itself
+        let file = inputs.remove(0).to_type::<String>()?;
+        let options = cli_matches.map_or(CsvImportOptions::default(), get_csv_columns);
+        let mut reader = ebi_input::get_reader_file(&PathBuf::from(*file))?;
+        let log = CsvEventLog::import_with_options(reader.get()?.as_mut(), &options)?;
+        Ok(EbiOutput::Object(EbiObject::EventLog(log)))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::EventLog) 
+};
+
+pub fn cli_csv_columns(command: Command) -> Command {
+    command.arg(
+        Arg::new("case")
+        .action(ArgAction::Set)
+        .value_name("COLUMN")
+        .long("case-column")
+        .help("The column that identifies the case of each event.")
+        .required(false)
+    ).arg(
+        Arg::new("activity")
+        .action(ArgAction::Set)
+        .value_name("COLUMN")
+        .long("activity-column")
+        .help("The column that contains the activity of each event.")
+        .required(false)
+    ).arg(
+        Arg::new("timestamp")
+        .action(ArgAction::Set)
+        .value_name("COLUMN")
+        .long("timestamp-column")
+        .help("The column that contains the timestamp of each event.")
+        .required(false)
+    )
+}
+
+/**
+ * Returns the columns given by the arguments of cli_csv_columns; columns of which the argument is not given are None.
+ */
+pub fn get_csv_columns(cli_matches: &ArgMatches) -> CsvImportOptions {
+    let get_column = |id: &str| cli_matches.get_one::<String>(id).cloned();
+    CsvImportOptions {
+        case_column: get_column("case"),
+        activity_column: get_column("activity"),
+        timestamp_column: get_column("timestamp"),
+    }
+}
+
 pub const EBI_CONVERT_LPN: EbiCommand = EbiCommand::Command { 
     name_short: "lpn", 
     name_long: Some("labelled-Petri-net"),
@@ -60,19 +175,70 @@ pub const EBI_CONVERT_SDFA: EbiCommand = EbiCommand::Command {
     name_short: "sdfa", 
     name_long: Some("stochastic-finite-deterministic-automaton"), 
     explanation_short: "Convert an object to a stochastic deterministic finite automaton.", 
-    explanation_long: None, 
+    explanation_long: Some("Convert an object to a stochastic deterministic finite automaton.
+A bounded stochastic labelled Petri net is converted by exploring its deterministic stochastic semantics.
+This fails if the net is unbounded, if it has a silent livelock, or if the automaton would have more states than the maximum number of states."), 
     latex_link: None, 
-    cli_command: None, 
+    cli_command: Some(|command| cli_max_states(command)), 
     exact_arithmetic: true, 
     input_types: &[ 
         &[
             &EbiInputType::Object(EbiObjectType::StochasticDeterministicFiniteAutomaton), //every object that can be imported as an SDFA will be supported by the framework
+            &EbiInputType::Object(EbiObjectType::StochasticLabelledPetriNet), //not every SLPN has a finite SDFA, so this conversion is not an importer
         ] ], 
     input_names: &[ "FILE" ], 
     input_helps: &[ "Any file supported by Ebi that can be converted." ], 
-    execute: |mut inputs, _| {
-        let sdfa = inputs.remove(0).to_type::<StochasticDeterministicFiniteAutomaton>()?;
-        Ok(EbiOutput::Object(EbiObject::StochasticDeterministicFiniteAutomaton(*sdfa)))
+    execute: |mut inputs, cli_matches| {
+        match inputs.remove(0) {
+            EbiInput::Object(EbiObject::StochasticLabelledPetriNet(slpn), _) => {
+                let max_states = cli_matches.map_or(DEFAULT_MAX_SDFA_STATES, |cli_matches| *cli_matches.get_one::<usize>("maxstates").unwrap());
+                let sdfa = slpn.get_stochastic_deterministic_finite_automaton(max_states)?;
+                Ok(EbiOutput::Object(EbiObject::StochasticDeterministicFiniteAutomaton(sdfa)))
+            },
+            input => {
+                let sdfa = input.to_type::<StochasticDeterministicFiniteAutomaton>()?;
+                Ok(EbiOutput::Object(EbiObject::StochasticDeterministicFiniteAutomaton(*sdfa)))
+            }
+        }
     }, 
     output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticDeterministicFiniteAutomaton)
-};
\ No newline at end of file
+};
+
+pub fn cli_max_states(command: Command) -> Command {
+    command.arg(
+        Arg::new("maxstates")
+        .action(ArgAction::Set)
+        .value_name("NUMBER")
+        .long("max-states")
+        .help("The maximum number of states of the automaton, when converting a stochastic labelled Petri net.")
+        .default_value(DEFAULT_MAX_SDFA_STATES.to_string())
+        .value_parser(value_parser!(usize))
+        .required(false)
+    )
+}
+
+pub const EBI_CONVERT_SDFM: EbiCommand = EbiCommand::Command { 
+    name_short: "sdfm", 
+    name_long: Some("stochastic-directly-follows-model"), 
+    explanation_short: "Convert an object to a stochastic directly follows model.", 
+    explanation_long: Some("Convert an object to a stochastic directly follows model.
+A finite stochastic language, such as an event log, is summarised: each start activity, end activity and directly-follows pair gets the sum probability of the traces in which it occurs, counted per occurrence."), 
+    latex_link: None, 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[
+            &EbiInputType::Object(EbiObjectType::StochasticDirectlyFollowsModel), //every object that can be imported as an SDFM will be supported by the framework
+            &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage),
+        ] ], 
+    input_names: &[ "FILE" ], 
+    input_helps: &[ "Any file supported by Ebi that can be converted." ], 
+    execute: |mut inputs, _| {
+        let sdfm = match inputs.remove(0) {
+            EbiInput::Trait(EbiTraitObject::FiniteStochasticLanguage(slang), _) => StochasticDirectlyFollowsModel::from_finite_stochastic_language(slang.as_ref()),
+            input => *input.to_type::<StochasticDirectlyFollowsModel>()?,
+        };
+        Ok(EbiOutput::Object(EbiObject::StochasticDirectlyFollowsModel(sdfm)))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticDirectlyFollowsModel)
+};
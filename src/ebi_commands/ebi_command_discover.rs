@@ -1,5 +1,7 @@
+use anyhow::{anyhow, Result};
+use clap::{Arg, ArgAction, ArgMatches, Command};
 
-use crate::{ebi_framework::{ebi_command::EbiCommand, ebi_input::EbiInputType, ebi_object::{EbiObject, EbiObjectType}, ebi_output::{EbiOutput, EbiOutputType}, ebi_trait::EbiTrait}, ebi_objects::labelled_petri_net::LabelledPetriNet, ebi_traits::ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, techniques::{alignment_stochastic_miner::AlignmentMiner, occurrences_stochastic_miner::OccurrencesStochasticMiner, uniform_stochastic_miner::UniformStochasticMiner}};
+use crate::{ebi_framework::{ebi_command::EbiCommand, ebi_input::{EbiInput, EbiInputType}, ebi_object::{EbiObject, EbiObjectType, EbiTraitObject}, ebi_output::{EbiOutput, EbiOutputType}, ebi_trait::EbiTrait}, ebi_objects::{executions::Executions, labelled_petri_net::LabelledPetriNet, process_tree::ProcessTree}, ebi_traits::{ebi_trait_event_log::EbiTraitEventLog, ebi_trait_finite_language::EbiTraitFiniteLanguage, ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage}, math::fraction::Fraction, techniques::{alergia_miner::AlergiaMiner, alignment_stochastic_miner::AlignmentMiner, alpha_miner::AlphaMiner, directly_follows_miner::DirectlyFollowsMiner, estimator_stochastic_miner::{EstimatorStochasticMiner, WeightEstimator}, executions_stochastic_miner::ExecutionsStochasticMiner, inductive_miner::InductiveMiner, maximum_likelihood_stochastic_miner::MaximumLikelihoodStochasticMiner, occurrences_stochastic_miner::OccurrencesStochasticMiner, split_miner::SplitMiner, uniform_stochastic_miner::UniformStochasticMiner}};
 
 pub const EBI_DISCOVER: EbiCommand = EbiCommand::Group {
     name_short: "disc",
@@ -7,12 +9,55 @@ pub const EBI_DISCOVER: EbiCommand = EbiCommand::Group {
     explanation_short: "Discover a process model.",
     explanation_long: None,
     children: &[
+        &EBI_DISCOVER_ALERGIA,
         &EBI_DISCOVER_ALIGNMENTS,
+        &EBI_DISCOVER_ALIGNMENTS_TREE,
+        &EBI_DISCOVER_ALPHA,
+        &EBI_DISCOVER_BILL_CLINTON,
+        &EBI_DISCOVER_DIRECTLY_FOLLOWS,
+        &EBI_DISCOVER_EXECUTIONS,
+        &EBI_DISCOVER_EXECUTIONS_REPORT,
+        &EBI_DISCOVER_FORK,
+        &EBI_DISCOVER_FREQUENCY,
+        &EBI_DISCOVER_INDUCTIVE,
+        &EBI_DISCOVER_INDUCTIVE_INFREQUENT,
+        &EBI_DISCOVER_LEFT_HAND_PAIR,
+        &EBI_DISCOVER_MAXIMUM_LIKELIHOOD,
+        &EBI_DISCOVER_MAXIMUM_LIKELIHOOD_REPORT,
         &EBI_DISCOVER_OCCURRENCE,
-        &EBI_DISCOVER_UNIFORM
+        &EBI_DISCOVER_OCCURRENCE_TREE,
+        &EBI_DISCOVER_PAIR_SCALE,
+        &EBI_DISCOVER_RIGHT_HAND_PAIR,
+        &EBI_DISCOVER_SPLIT,
+        &EBI_DISCOVER_UNIFORM,
+        &EBI_DISCOVER_UNIFORM_TREE,
     ],
 };
 
+pub const EBI_DISCOVER_ALERGIA: EbiCommand = EbiCommand::Command { 
+    name_short: "alg", 
+    name_long: Some("alergia"), 
+    explanation_short: "Discover a stochastic deterministic finite automaton by merging the states of the prefix tree of the log using ALERGIA.", 
+    explanation_long: Some("Discover a stochastic deterministic finite automaton using ALERGIA.
+The algorithm starts with the prefix tree of the log, in which each state records how often it is passed, and merges two states if their outgoing frequencies are not significantly different, according to a Hoeffding bound with confidence parameter alpha.
+A lower alpha leads to more merges, and thus a more general and smaller automaton."), 
+    latex_link: Some("~\\cite{DBLP:conf/icgi/CarrascoO94}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::EventLog)],
+        &[ &EbiInputType::Fraction]
+    ], 
+    input_names: &[ "FILE", "ALPHA" ], 
+    input_helps: &[ "An event log.", "The confidence parameter alpha, larger than 0 and at most 1." ], 
+    execute: |mut inputs, _| {
+        let log = inputs.remove(0).to_type::<dyn EbiTraitEventLog>()?;
+        let alpha = inputs.remove(0).to_type::<Fraction>()?;
+        Ok(EbiOutput::Object(EbiObject::StochasticDeterministicFiniteAutomaton(log.mine_alergia(&alpha)?)))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticDeterministicFiniteAutomaton)
+};
+
 pub const EBI_DISCOVER_ALIGNMENTS: EbiCommand = EbiCommand::Command { 
     name_short: "ali", 
     name_long: Some("alignments"), 
@@ -35,6 +80,344 @@ pub const EBI_DISCOVER_ALIGNMENTS: EbiCommand = EbiCommand::Command {
     output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticLabelledPetriNet)
 };
 
+pub const EBI_DISCOVER_ALIGNMENTS_TREE: EbiCommand = EbiCommand::Command { 
+    name_short: "alitree", 
+    name_long: Some("alignments-tree"), 
+    explanation_short: "Give each option of the xors, loops and ors of a process tree a weight that matches how often it is chosen in the alignments.", 
+    explanation_long: None, 
+    latex_link: Some("~\\cite{DBLP:conf/icpm/BurkeLW20}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)], 
+        &[ &EbiInputType::Object(EbiObjectType::ProcessTree)]
+    ], 
+    input_names: &[ "FILE_1", "FILE_2" ], 
+    input_helps: &[ "A finite stochastic language (log) to get the occurrences from.", "A process tree with the control flow." ], 
+    execute: |mut inputs, _| {
+        let language = inputs.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let tree = inputs.remove(0).to_type::<ProcessTree>()?;
+        Ok(EbiOutput::Object(EbiObject::StochasticProcessTree(tree.mine_stochastic_alignment(language)?)))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticProcessTree)
+};
+
+pub const EBI_DISCOVER_ALPHA: EbiCommand = EbiCommand::Command { 
+    name_short: "alp", 
+    name_long: Some("alpha-plus"), 
+    explanation_short: "Discover a labelled Petri net using the Alpha+ algorithm.", 
+    explanation_long: Some("Discover a labelled Petri net using the Alpha+ algorithm, which extends the Alpha algorithm with the handling of short loops.
+Activities that directly follow themselves are removed from the log before discovery, and added afterwards as self-loops on the place between their neighbours; loops of length two are recognised from the pattern aba.
+Implicit dependencies (non-free choice constructs) are not discovered."), 
+    latex_link: Some("~\\cite{DBLP:journals/tkde/AalstWM04}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::FiniteLanguage)]
+    ], 
+    input_names: &[ "FILE" ], 
+    input_helps: &[ "A finite language (log)." ], 
+    execute: |mut inputs, _| {
+        let language = inputs.remove(0).to_type::<dyn EbiTraitFiniteLanguage>()?;
+        Ok(EbiOutput::Object(EbiObject::LabelledPetriNet(language.mine_alpha())))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::LabelledPetriNet)
+};
+
+pub const EBI_DISCOVER_BILL_CLINTON: EbiCommand = EbiCommand::Command { 
+    name_short: "bce", 
+    name_long: Some("bill-clinton"), 
+    explanation_short: "Give each transition the average of the weights of the frequency, left-hand pair and right-hand pair estimators; silent transitions get a weight of 1.", 
+    explanation_long: Some("Give each transition a weight using the Bill Clinton estimator, which triangulates the frequency, left-hand pair and right-hand pair estimators by taking the average of their weights.
+Silent transitions get a weight of 1."), 
+    latex_link: Some("~\\cite{DBLP:conf/icpm/BurkeLW20}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)], 
+        &[ &EbiInputType::Object(EbiObjectType::LabelledPetriNet)]
+    ], 
+    input_names: &[ "FILE_1", "FILE_2" ], 
+    input_helps: &[ "A finite stochastic language (log) to get the frequencies from.", "A labelled Petri net with the control flow." ], 
+    execute: |mut inputs, _| {
+        let language = inputs.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let lpn = inputs.remove(0).to_type::<LabelledPetriNet>()?;
+        Ok(EbiOutput::Object(EbiObject::StochasticLabelledPetriNet(lpn.mine_estimator_stochastic(language, WeightEstimator::BillClinton))))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticLabelledPetriNet)
+};
+
+pub const EBI_DISCOVER_DIRECTLY_FOLLOWS: EbiCommand = EbiCommand::Command { 
+    name_short: "dfm", 
+    name_long: Some("directly-follows"), 
+    explanation_short: "Discover a directly follows model.", 
+    explanation_long: Some("Discover a directly follows model.
+Activities that occur less often than the activity threshold times the occurrences of the most frequent activity are removed from the traces.
+Of the edges leaving each node (including the start and end activities), the ones that occur less often than the edge threshold times the most frequent of these edges are removed.
+Both thresholds are fractions between 0 and 1, and default to 0."), 
+    latex_link: None, 
+    cli_command: Some(|command| cli_directly_follows_thresholds(command)), 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::EventLog), &EbiInputType::Trait(EbiTrait::FiniteLanguage)]
+    ], 
+    input_names: &[ "FILE" ], 
+    input_helps: &[ "An event log or a finite language." ], 
+    execute: |mut inputs, cli_matches| {
+        let activity_threshold = get_threshold(cli_matches, "activity_threshold")?;
+        let edge_threshold = get_threshold(cli_matches, "edge_threshold")?;
+        let dfm = match inputs.remove(0) {
+            EbiInput::Trait(EbiTraitObject::EventLog(log), _) => log.mine_directly_follows(&activity_threshold, &edge_threshold)?,
+            EbiInput::Trait(EbiTraitObject::FiniteLanguage(language), _) => language.mine_directly_follows(&activity_threshold, &edge_threshold)?,
+            _ => unreachable!()
+        };
+        Ok(EbiOutput::Object(EbiObject::DirectlyFollowsModel(dfm)))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::DirectlyFollowsModel)
+};
+
+pub fn cli_directly_follows_thresholds(command: Command) -> Command {
+    command.arg(
+        Arg::new("activity_threshold")
+        .action(ArgAction::Set)
+        .value_name("FRACTION")
+        .long("activity-threshold")
+        .help("Keep only activities that occur at least this fraction of the occurrences of the most frequent activity.")
+        .required(false)
+    ).arg(
+        Arg::new("edge_threshold")
+        .action(ArgAction::Set)
+        .value_name("FRACTION")
+        .long("edge-threshold")
+        .help("Keep only edges that occur at least this fraction of the occurrences of the most frequent edge leaving the same node.")
+        .required(false)
+    )
+}
+
+fn get_threshold(cli_matches: Option<&ArgMatches>, id: &str) -> Result<Fraction> {
+    match cli_matches.and_then(|matches| matches.get_one::<String>(id)) {
+        Some(value) => value.parse::<Fraction>().map_err(|_| anyhow!("could not read {} as a fraction", value)),
+        None => Ok(Fraction::zero()),
+    }
+}
+
+pub const EBI_DISCOVER_EXECUTIONS: EbiCommand = EbiCommand::Command { 
+    name_short: "exe", 
+    name_long: Some("executions"), 
+    explanation_short: "Give each transition a weight that maximises the likelihood of the choices recorded in executions.", 
+    explanation_long: Some("Give each transition a weight that maximises the likelihood of the choices recorded in executions.
+Each execution of a transition is a choice of that transition among the transitions that were enabled at its enablement; executions that do not record their enabled transitions are ignored.
+The weights are estimated using minorise-maximise iterations, starting from uniform weights, until no weight changes by more than one billionth relative to its value, or for at most 1000 iterations.
+Transitions that are never enabled in an execution get a weight of 1.
+Whether the weights converged is given by `Ebi discover executions-report'.
+The executions must have been computed on the given model, for instance using `Ebi analyse-non-stochastic executions'."), 
+    latex_link: None, 
+    cli_command: None, 
+    exact_arithmetic: false, 
+    input_types: &[ 
+        &[ &EbiInputType::Object(EbiObjectType::Executions)], 
+        &[ &EbiInputType::Object(EbiObjectType::LabelledPetriNet)]
+    ], 
+    input_names: &[ "FILE_1", "FILE_2" ], 
+    input_helps: &[ "The executions.", "The labelled Petri net on which the executions were computed." ], 
+    execute: |mut inputs, _| {
+        let executions = inputs.remove(0).to_type::<Executions>()?;
+        let lpn = inputs.remove(0).to_type::<LabelledPetriNet>()?;
+        let (slpn, _) = lpn.mine_executions_stochastic(&executions)?;
+        Ok(EbiOutput::Object(EbiObject::StochasticLabelledPetriNet(slpn)))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticLabelledPetriNet)
+};
+
+pub const EBI_DISCOVER_EXECUTIONS_REPORT: EbiCommand = EbiCommand::Command { 
+    name_short: "exe-report", 
+    name_long: Some("executions-report"), 
+    explanation_short: "Report whether the weight estimation from executions converged.", 
+    explanation_long: Some("Give each transition a weight that maximises the likelihood of the choices recorded in executions, as `Ebi discover executions', and report the number of iterations and whether the weights converged."), 
+    latex_link: None, 
+    cli_command: None, 
+    exact_arithmetic: false, 
+    input_types: &[ 
+        &[ &EbiInputType::Object(EbiObjectType::Executions)], 
+        &[ &EbiInputType::Object(EbiObjectType::LabelledPetriNet)]
+    ], 
+    input_names: &[ "FILE_1", "FILE_2" ], 
+    input_helps: &[ "The executions.", "The labelled Petri net on which the executions were computed." ], 
+    execute: |mut inputs, _| {
+        let executions = inputs.remove(0).to_type::<Executions>()?;
+        let lpn = inputs.remove(0).to_type::<LabelledPetriNet>()?;
+        let (_, report) = lpn.mine_executions_stochastic(&executions)?;
+        Ok(EbiOutput::String(report.to_string()))
+    }, 
+    output_type: &EbiOutputType::String
+};
+
+pub const EBI_DISCOVER_FORK: EbiCommand = EbiCommand::Command { 
+    name_short: "fork", 
+    name_long: Some("fork-distribution"), 
+    explanation_short: "Give each transition a weight that matches the share of the occurrences of its label among the labels of the transitions that compete for its input places; silent transitions get a weight of 1.", 
+    explanation_long: Some("Give each transition a weight using the fork distribution estimator.
+For each input place of the transition, the share of the occurrences of its label among the occurrences of the labels of all output transitions of that place is computed, in which silent transitions count as 1.
+The weight is the average share over the input places.
+Silent transitions get a weight of 1."), 
+    latex_link: Some("~\\cite{DBLP:conf/icpm/BurkeLW20}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)], 
+        &[ &EbiInputType::Object(EbiObjectType::LabelledPetriNet)]
+    ], 
+    input_names: &[ "FILE_1", "FILE_2" ], 
+    input_helps: &[ "A finite stochastic language (log) to get the frequencies from.", "A labelled Petri net with the control flow." ], 
+    execute: |mut inputs, _| {
+        let language = inputs.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let lpn = inputs.remove(0).to_type::<LabelledPetriNet>()?;
+        Ok(EbiOutput::Object(EbiObject::StochasticLabelledPetriNet(lpn.mine_estimator_stochastic(language, WeightEstimator::Fork))))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticLabelledPetriNet)
+};
+
+pub const EBI_DISCOVER_FREQUENCY: EbiCommand = EbiCommand::Command { 
+    name_short: "freq", 
+    name_long: Some("frequency"), 
+    explanation_short: "Give each transition a weight that matches the occurrences of its label, divided over the transitions with that label; silent transitions get a weight of 1.", 
+    explanation_long: Some("Give each transition a weight using the frequency estimator: the occurrences of its label, divided over the transitions with that label.
+This differs from the occurrence estimator only for nets in which several transitions have the same label.
+Silent transitions get a weight of 1."), 
+    latex_link: Some("~\\cite{DBLP:conf/icpm/BurkeLW20}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)], 
+        &[ &EbiInputType::Object(EbiObjectType::LabelledPetriNet)]
+    ], 
+    input_names: &[ "FILE_1", "FILE_2" ], 
+    input_helps: &[ "A finite stochastic language (log) to get the frequencies from.", "A labelled Petri net with the control flow." ], 
+    execute: |mut inputs, _| {
+        let language = inputs.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let lpn = inputs.remove(0).to_type::<LabelledPetriNet>()?;
+        Ok(EbiOutput::Object(EbiObject::StochasticLabelledPetriNet(lpn.mine_estimator_stochastic(language, WeightEstimator::Frequency))))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticLabelledPetriNet)
+};
+
+pub const EBI_DISCOVER_INDUCTIVE: EbiCommand = EbiCommand::Command { 
+    name_short: "im", 
+    name_long: Some("inductive"), 
+    explanation_short: "Discover a process tree using the Inductive Miner. The language of the tree includes all traces of the log.", 
+    explanation_long: None, 
+    latex_link: Some("~\\cite{DBLP:conf/apn/LeemansFA13}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)]
+    ], 
+    input_names: &[ "FILE" ], 
+    input_helps: &[ "A finite stochastic language (log)." ], 
+    execute: |mut inputs, _| {
+        let language = inputs.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        Ok(EbiOutput::Object(EbiObject::ProcessTree(language.mine_inductive())))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::ProcessTree)
+};
+
+pub const EBI_DISCOVER_INDUCTIVE_INFREQUENT: EbiCommand = EbiCommand::Command { 
+    name_short: "imf", 
+    name_long: Some("inductive-infrequent"), 
+    explanation_short: "Discover a process tree using the Inductive Miner - infrequent (IMf), which filters infrequent behaviour.", 
+    explanation_long: Some("Discover a process tree using the Inductive Miner - infrequent (IMf, also known as IMi).
+If no cut can be found, edges of the directly follows graph that occur less often than the noise threshold times the most frequent edge from the same activity are removed, and the log is split according to the cut found on the filtered graph.
+Start and end activities are filtered similarly, and empty traces are removed if they occur less often than the noise threshold times the size of the log.
+A noise threshold of 0 yields the Inductive Miner."), 
+    latex_link: Some("~\\cite{DBLP:conf/bpm/LeemansFA13}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)],
+        &[ &EbiInputType::Fraction]
+    ], 
+    input_names: &[ "FILE", "NOISE_THRESHOLD" ], 
+    input_helps: &[ "A finite stochastic language (log).", "The noise threshold, between 0 and 1." ], 
+    execute: |mut inputs, _| {
+        let language = inputs.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let noise_threshold = inputs.remove(0).to_type::<Fraction>()?;
+        Ok(EbiOutput::Object(EbiObject::ProcessTree(language.mine_inductive_infrequent(&noise_threshold)?)))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::ProcessTree)
+};
+
+pub const EBI_DISCOVER_LEFT_HAND_PAIR: EbiCommand = EbiCommand::Command { 
+    name_short: "lhpair", 
+    name_long: Some("left-hand-pair"), 
+    explanation_short: "Give each transition a weight that matches the occurrences of its label directly followed by the label of a successor transition; silent transitions get a weight of 1.", 
+    explanation_long: Some("Give each transition a weight using the activity-pair left-hand estimator: the occurrences of the pairs in the log in which the label of the transition is directly followed by the label of a transition that can follow it in the net, or by the end of the trace.
+Silent transitions are looked through to find successors, and get a weight of 1."), 
+    latex_link: Some("~\\cite{DBLP:conf/icpm/BurkeLW20}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)], 
+        &[ &EbiInputType::Object(EbiObjectType::LabelledPetriNet)]
+    ], 
+    input_names: &[ "FILE_1", "FILE_2" ], 
+    input_helps: &[ "A finite stochastic language (log) to get the frequencies from.", "A labelled Petri net with the control flow." ], 
+    execute: |mut inputs, _| {
+        let language = inputs.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let lpn = inputs.remove(0).to_type::<LabelledPetriNet>()?;
+        Ok(EbiOutput::Object(EbiObject::StochasticLabelledPetriNet(lpn.mine_estimator_stochastic(language, WeightEstimator::LeftHandPair))))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticLabelledPetriNet)
+};
+
+pub const EBI_DISCOVER_MAXIMUM_LIKELIHOOD: EbiCommand = EbiCommand::Command { 
+    name_short: "mle", 
+    name_long: Some("maximum-likelihood"), 
+    explanation_short: "Give each transition a weight such that the log-likelihood of the log in the model is maximal.", 
+    explanation_long: Some("Give each transition a weight such that the log-likelihood of the log in the model is maximal.
+Starting from uniform weights, the logarithms of the weights are optimised using gradient ascent, in which the probability of each trace is computed using the cross product of the model and the trace.
+Traces that the model cannot produce are ignored, as their probability does not depend on the weights.
+The log-likelihood and the entropic relevance before and after optimisation are given by `Ebi discover maximum-likelihood-report'."), 
+    latex_link: None, 
+    cli_command: None, 
+    exact_arithmetic: false, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)], 
+        &[ &EbiInputType::Object(EbiObjectType::LabelledPetriNet)]
+    ], 
+    input_names: &[ "FILE_1", "FILE_2" ], 
+    input_helps: &[ "A finite stochastic language (log) to maximise the likelihood of.", "A labelled Petri net with the control flow." ], 
+    execute: |mut inputs, _| {
+        let language = inputs.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let lpn = inputs.remove(0).to_type::<LabelledPetriNet>()?;
+        let (slpn, _) = lpn.mine_maximum_likelihood_stochastic(language)?;
+        Ok(EbiOutput::Object(EbiObject::StochasticLabelledPetriNet(slpn)))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticLabelledPetriNet)
+};
+
+pub const EBI_DISCOVER_MAXIMUM_LIKELIHOOD_REPORT: EbiCommand = EbiCommand::Command { 
+    name_short: "mle-report", 
+    name_long: Some("maximum-likelihood-report"), 
+    explanation_short: "Report the log-likelihood and entropic relevance of the log before and after maximum likelihood weight estimation.", 
+    explanation_long: Some("Give each transition a weight such that the log-likelihood of the log in the model is maximal, as `Ebi discover maximum-likelihood', and report the log-likelihood and the entropic relevance of the log before (with uniform weights) and after optimisation."), 
+    latex_link: None, 
+    cli_command: None, 
+    exact_arithmetic: false, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)], 
+        &[ &EbiInputType::Object(EbiObjectType::LabelledPetriNet)]
+    ], 
+    input_names: &[ "FILE_1", "FILE_2" ], 
+    input_helps: &[ "A finite stochastic language (log) to maximise the likelihood of.", "A labelled Petri net with the control flow." ], 
+    execute: |mut inputs, _| {
+        let language = inputs.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let lpn = inputs.remove(0).to_type::<LabelledPetriNet>()?;
+        let (_, report) = lpn.mine_maximum_likelihood_stochastic(language)?;
+        Ok(EbiOutput::String(report.to_string()))
+    }, 
+    output_type: &EbiOutputType::String
+};
+
 pub const EBI_DISCOVER_OCCURRENCE: EbiCommand = EbiCommand::Command { 
     name_short: "occ", 
     name_long: Some("occurrence"), 
@@ -57,6 +440,102 @@ pub const EBI_DISCOVER_OCCURRENCE: EbiCommand = EbiCommand::Command {
     output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticLabelledPetriNet)
 };
 
+pub const EBI_DISCOVER_OCCURRENCE_TREE: EbiCommand = EbiCommand::Command { 
+    name_short: "occtree", 
+    name_long: Some("occurrence-tree"), 
+    explanation_short: "Give each option of the xors, loops and ors of a process tree a weight that matches how often the nodes below it are executed, as estimated from the occurrences of their labels.", 
+    explanation_long: None, 
+    latex_link: Some("~\\cite{DBLP:conf/icpm/BurkeLW20}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)], 
+        &[ &EbiInputType::Object(EbiObjectType::ProcessTree)]
+    ], 
+    input_names: &[ "FILE_1", "FILE_2" ], 
+    input_helps: &[ "A finite stochastic language (log) to get the occurrences from.", "A process tree with the control flow." ], 
+    execute: |mut inputs, _| {
+        let language = inputs.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let tree = inputs.remove(0).to_type::<ProcessTree>()?;
+        Ok(EbiOutput::Object(EbiObject::StochasticProcessTree(tree.mine_occurrences_stochastic(language)?)))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticProcessTree)
+};
+
+pub const EBI_DISCOVER_PAIR_SCALE: EbiCommand = EbiCommand::Command { 
+    name_short: "pairscale", 
+    name_long: Some("pair-scale"), 
+    explanation_short: "Give each transition a weight that matches the fraction of the occurrences of the labels of its predecessors that is directly followed by its label; silent transitions get a weight of 1.", 
+    explanation_long: Some("Give each transition a weight using the activity-pair scaled estimator.
+For each label of a transition that can precede it in the net, or the start of the trace, the fraction of its occurrences that is directly followed by the label of the transition is computed, and these fractions are summed.
+Silent transitions are looked through to find predecessors, and get a weight of 1."), 
+    latex_link: Some("~\\cite{DBLP:conf/icpm/BurkeLW20}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)], 
+        &[ &EbiInputType::Object(EbiObjectType::LabelledPetriNet)]
+    ], 
+    input_names: &[ "FILE_1", "FILE_2" ], 
+    input_helps: &[ "A finite stochastic language (log) to get the frequencies from.", "A labelled Petri net with the control flow." ], 
+    execute: |mut inputs, _| {
+        let language = inputs.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let lpn = inputs.remove(0).to_type::<LabelledPetriNet>()?;
+        Ok(EbiOutput::Object(EbiObject::StochasticLabelledPetriNet(lpn.mine_estimator_stochastic(language, WeightEstimator::PairScale))))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticLabelledPetriNet)
+};
+
+pub const EBI_DISCOVER_RIGHT_HAND_PAIR: EbiCommand = EbiCommand::Command { 
+    name_short: "rhpair", 
+    name_long: Some("right-hand-pair"), 
+    explanation_short: "Give each transition a weight that matches the occurrences of its label directly preceded by the label of a predecessor transition; silent transitions get a weight of 1.", 
+    explanation_long: Some("Give each transition a weight using the activity-pair right-hand estimator: the occurrences of the pairs in the log in which the label of the transition directly follows the label of a transition that can precede it in the net, or the start of the trace.
+Silent transitions are looked through to find predecessors, and get a weight of 1."), 
+    latex_link: Some("~\\cite{DBLP:conf/icpm/BurkeLW20}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)], 
+        &[ &EbiInputType::Object(EbiObjectType::LabelledPetriNet)]
+    ], 
+    input_names: &[ "FILE_1", "FILE_2" ], 
+    input_helps: &[ "A finite stochastic language (log) to get the frequencies from.", "A labelled Petri net with the control flow." ], 
+    execute: |mut inputs, _| {
+        let language = inputs.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let lpn = inputs.remove(0).to_type::<LabelledPetriNet>()?;
+        Ok(EbiOutput::Object(EbiObject::StochasticLabelledPetriNet(lpn.mine_estimator_stochastic(language, WeightEstimator::RightHandPair))))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticLabelledPetriNet)
+};
+
+pub const EBI_DISCOVER_SPLIT: EbiCommand = EbiCommand::Command { 
+    name_short: "spl", 
+    name_long: Some("split"), 
+    explanation_short: "Discover a labelled Petri net in the style of Split Miner, with filtering and a concurrency oracle.", 
+    explanation_long: Some("Discover a labelled Petri net in the style of Split Miner.
+First, two activities that directly follow one another in both directions, not in a loop of length two, and of which the relative difference of these frequencies is below the parallelism threshold, are considered concurrent.
+Second, of the directly follows graph, the most frequent incoming and outgoing edge of each activity are kept, as well as the edges that are at least as frequent as the filter percentile of these most frequent edges; a filter percentile of 0 keeps all edges.
+Third, the successors and predecessors of each activity are combined into nested and- and xor-splits and -joins, which are translated into silent transitions."), 
+    latex_link: Some("~\\cite{DBLP:journals/kais/AugustoCDRP19}"), 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Trait(EbiTrait::FiniteStochasticLanguage)],
+        &[ &EbiInputType::Fraction],
+        &[ &EbiInputType::Fraction]
+    ], 
+    input_names: &[ "FILE", "FILTER_PERCENTILE", "PARALLELISM_THRESHOLD" ], 
+    input_helps: &[ "A finite stochastic language (log).", "The filter percentile, between 0 and 1.", "The parallelism threshold, between 0 and 1." ], 
+    execute: |mut inputs, _| {
+        let language = inputs.remove(0).to_type::<dyn EbiTraitFiniteStochasticLanguage>()?;
+        let filter_percentile = inputs.remove(0).to_type::<Fraction>()?;
+        let parallelism_threshold = inputs.remove(0).to_type::<Fraction>()?;
+        Ok(EbiOutput::Object(EbiObject::LabelledPetriNet(language.mine_split(&filter_percentile, &parallelism_threshold)?)))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::LabelledPetriNet)
+};
+
 pub const EBI_DISCOVER_UNIFORM: EbiCommand = EbiCommand::Command { 
     name_short: "uni", 
     name_long: Some("uniform"), 
@@ -75,4 +554,24 @@ pub const EBI_DISCOVER_UNIFORM: EbiCommand = EbiCommand::Command {
         Ok(EbiOutput::Object(EbiObject::StochasticLabelledPetriNet(lpn.mine_uniform_stochastic())))
     }, 
     output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticLabelledPetriNet)
-};
\ No newline at end of file
+};
+
+pub const EBI_DISCOVER_UNIFORM_TREE: EbiCommand = EbiCommand::Command { 
+    name_short: "unitree", 
+    name_long: Some("uniform-tree"), 
+    explanation_short: "Make all options of the xors, loops and ors of a process tree equally likely.", 
+    explanation_long: None, 
+    latex_link: None, 
+    cli_command: None, 
+    exact_arithmetic: true, 
+    input_types: &[ 
+        &[ &EbiInputType::Object(EbiObjectType::ProcessTree)]
+    ], 
+    input_names: &["TREE_FILE" ], 
+    input_helps: &[ "A process tree." ], 
+    execute: |mut inputs, _| {
+        let tree = inputs.remove(0).to_type::<ProcessTree>()?;
+        Ok(EbiOutput::Object(EbiObject::StochasticProcessTree(tree.mine_uniform_stochastic()?)))
+    }, 
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticProcessTree)
+};
@@ -1,7 +1,7 @@
-use anyhow::{anyhow, Context};
+use anyhow::{anyhow, Context, Result};
 use clap::{value_parser, Arg, ArgAction};
 
-use crate::{ebi_framework::{ebi_command::EbiCommand, ebi_input::EbiInputType, ebi_object::{EbiObject, EbiObjectType}, ebi_output::{EbiOutput, EbiOutputType}, ebi_trait::EbiTrait}, ebi_traits::{ebi_trait_finite_language::EbiTraitFiniteLanguage, ebi_trait_queriable_stochastic_language::EbiTraitQueriableStochasticLanguage, ebi_trait_stochastic_semantics::EbiTraitStochasticSemantics}, follower_semantics::FollowerSemantics, math::fraction::Fraction, techniques::explain_trace::ExplainTrace};
+use crate::{ebi_framework::{ebi_command::EbiCommand, ebi_input::{EbiInput, EbiInputType}, ebi_object::{EbiObject, EbiObjectType}, ebi_output::{EbiOutput, EbiOutputType}, ebi_trait::EbiTrait}, ebi_traits::{ebi_trait_finite_language::EbiTraitFiniteLanguage, ebi_trait_queriable_stochastic_language::EbiTraitQueriableStochasticLanguage, ebi_trait_stochastic_semantics::{EbiTraitStochasticSemantics, ToStochasticSemantics}}, follower_semantics::FollowerSemantics, math::fraction::Fraction, techniques::{coverability::Coverability, explain_trace::ExplainTrace}};
 
 
 
@@ -26,13 +26,13 @@ pub const EBI_PROBABILITY_MODEL: EbiCommand = EbiCommand::Command {
     cli_command: None, 
     exact_arithmetic: true, 
     input_types: &[ 
-        &[ &EbiInputType::Trait(EbiTrait::QueriableStochasticLanguage) ], 
+        &[ &EbiInputType::Object(EbiObjectType::StochasticLabelledPetriNet), &EbiInputType::Trait(EbiTrait::QueriableStochasticLanguage) ], 
         &[ &EbiInputType::Trait(EbiTrait::FiniteLanguage) ] 
     ], 
     input_names: &[ "FILE_1", "FILE_2" ], 
     input_helps: &[ "The queriable stochastic language (model).", "The finite language (log)." ], 
     execute: |mut inputs, _| {
-        let model: Box<dyn EbiTraitQueriableStochasticLanguage> = inputs.remove(0).to_type::<dyn EbiTraitQueriableStochasticLanguage>()?;
+        let model: Box<dyn EbiTraitQueriableStochasticLanguage> = get_queriable_stochastic_language(inputs.remove(0))?;
         let log = inputs.remove(0).to_type::<dyn EbiTraitFiniteLanguage>()?;
         
         let mut sum = Fraction::zero();
@@ -61,12 +61,12 @@ pub const EBI_PROBABILITY_TRACE: EbiCommand = EbiCommand::Command {
     }), 
     exact_arithmetic: true, 
     input_types: &[ 
-        &[ &EbiInputType::Trait(EbiTrait::QueriableStochasticLanguage) ] 
+        &[ &EbiInputType::Object(EbiObjectType::StochasticLabelledPetriNet), &EbiInputType::Trait(EbiTrait::QueriableStochasticLanguage) ] 
     ], 
     input_names: &[ "FILE" ], 
     input_helps: &[ "The queriable stochastic language (model)." ], 
     execute: |mut inputs, cli_matches| {
-        let mut model = inputs.remove(0).to_type::<dyn EbiTraitQueriableStochasticLanguage>()?;
+        let mut model = get_queriable_stochastic_language(inputs.remove(0))?;
         if let Some(x) = cli_matches.unwrap().get_many::<String>("trace") {
             let t: Vec<&String> = x.collect();
             let trace = t.into_iter().map(|activity| activity.as_str()).collect::<Vec<_>>();
@@ -100,13 +100,13 @@ pub const EBI_PROBABILITY_EXPLAIN_TRACE: EbiCommand = EbiCommand::Command {
     }), 
     exact_arithmetic: true, 
     input_types: &[ 
-        &[ &EbiInputType::Trait(EbiTrait::StochasticSemantics) ],
+        &[ &EbiInputType::Object(EbiObjectType::StochasticLabelledPetriNet), &EbiInputType::Trait(EbiTrait::StochasticSemantics) ],
         &[ &EbiInputType::Fraction ]
     ], 
     input_names: &[ "FILE", "VALUE" ], 
     input_helps: &[ "The model.", "Balance between 0 (=only consider deviations) to 1 (=only consider weight in the model)" ], 
     execute: |mut inputs, cli_matches| {
-        let mut semantics = inputs.remove(0).to_type::<EbiTraitStochasticSemantics>()?;
+        let mut semantics = get_stochastic_semantics(inputs.remove(0))?;
         let balance = inputs.remove(0).to_type::<Fraction>()?;
         if let Some(x) = cli_matches.unwrap().get_many::<String>("trace") {
             let t: Vec<&String> = x.collect();
@@ -122,4 +122,27 @@ pub const EBI_PROBABILITY_EXPLAIN_TRACE: EbiCommand = EbiCommand::Command {
         }
     }, 
     output_type: &EbiOutputType::ObjectType(EbiObjectType::LanguageOfAlignments),
-};
\ No newline at end of file
+};
+
+/**
+ * Stochastic labelled Petri nets are read as objects first, such that unbounded nets, of which the state space cannot be explored, are rejected before the computation starts.
+ */
+fn get_queriable_stochastic_language(input: EbiInput) -> Result<Box<dyn EbiTraitQueriableStochasticLanguage>> {
+    match input {
+        EbiInput::Object(EbiObject::StochasticLabelledPetriNet(slpn), _) => {
+            slpn.verify_bounded()?;
+            Ok(Box::new(slpn))
+        },
+        input => input.to_type::<dyn EbiTraitQueriableStochasticLanguage>()
+    }
+}
+
+fn get_stochastic_semantics(input: EbiInput) -> Result<Box<EbiTraitStochasticSemantics>> {
+    match input {
+        EbiInput::Object(EbiObject::StochasticLabelledPetriNet(slpn), _) => {
+            slpn.verify_bounded()?;
+            Ok(Box::new(slpn.to_stochastic_semantics()))
+        },
+        input => input.to_type::<EbiTraitStochasticSemantics>()
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{ebi_framework::{ebi_command::EbiCommand, ebi_input::EbiInputType, ebi_object::{EbiObject, EbiObjectType}, ebi_output::{EbiOutput, EbiOutputType}}, ebi_objects::{labelled_petri_net::LabelledPetriNet, stochastic_labelled_petri_net::StochasticLabelledPetriNet}, techniques::reduction::Reduce};
+
+pub const EBI_REDUCE: EbiCommand = EbiCommand::Group {
+    name_short: "red",
+    name_long: Some("reduce"),
+    explanation_short: "Reduce the size of a model while preserving its language.",
+    explanation_long: None,
+    children: &[
+        &EBI_REDUCE_LPN,
+        &EBI_REDUCE_SLPN,
+    ]
+};
+
+pub const EBI_REDUCE_LPN: EbiCommand = EbiCommand::Command {
+    name_short: "lpn",
+    name_long: Some("labelled-Petri-net"),
+    explanation_short: "Reduce a labelled Petri net while preserving its language.",
+    explanation_long: Some("Apply Murata-style reduction rules to a labelled Petri net until none applies anymore: removal of transitions that can never fire and of places that no transition consumes from, removal of implicit places, fusion of parallel transitions, removal of silent self-loops, fusion of series places and transitions over silent transitions, and elimination of silent transitions in state machines."),
+    latex_link: Some("~\\cite{DBLP:journals/pieee/Murata89}"),
+    cli_command: None,
+    exact_arithmetic: true,
+    input_types: &[ &[ &EbiInputType::Object(EbiObjectType::LabelledPetriNet) ] ],
+    input_names: &[ "FILE" ],
+    input_helps: &[ "The labelled Petri net." ],
+    execute: |mut inputs, _| {
+        let lpn = inputs.remove(0).to_type::<LabelledPetriNet>()?;
+        Ok(EbiOutput::Object(EbiObject::LabelledPetriNet(lpn.reduce()?)))
+    },
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::LabelledPetriNet)
+};
+
+pub const EBI_REDUCE_SLPN: EbiCommand = EbiCommand::Command {
+    name_short: "slpn",
+    name_long: Some("stochastic-labelled-Petri-net"),
+    explanation_short: "Reduce a stochastic labelled Petri net while preserving its stochastic language.",
+    explanation_long: Some("Apply Murata-style reduction rules to a stochastic labelled Petri net until none applies anymore, recombining weights such that the probability of each trace is preserved.
+The rules are the ones of the labelled Petri net reduction, where parallel transitions are fused by adding their weights.
+As in a stochastic net silent transitions influence the probabilities of interleavings of concurrent transitions, the rules that remove silent transitions that change the marking are only applied to state machines with a single token.
+Transitions with weight zero are removed."),
+    latex_link: Some("~\\cite{DBLP:journals/pieee/Murata89}"),
+    cli_command: None,
+    exact_arithmetic: true,
+    input_types: &[ &[ &EbiInputType::Object(EbiObjectType::StochasticLabelledPetriNet) ] ],
+    input_names: &[ "FILE" ],
+    input_helps: &[ "The stochastic labelled Petri net." ],
+    execute: |mut inputs, _| {
+        let slpn = inputs.remove(0).to_type::<StochasticLabelledPetriNet>()?;
+        Ok(EbiOutput::Object(EbiObject::StochasticLabelledPetriNet(slpn.reduce()?)))
+    },
+    output_type: &EbiOutputType::ObjectType(EbiObjectType::StochasticLabelledPetriNet)
+};
@@ -2,9 +2,9 @@ use clap::{value_parser, Arg, ArgAction, Command};
 use anyhow::Context;
 use std::io::Write;
 
-use crate::{ebi_framework::{ebi_command::EbiCommand, ebi_input::EbiInputType, ebi_output::{EbiOutput, EbiOutputType}, ebi_trait::EbiTrait}, ebi_info, ebi_traits::ebi_trait_event_log::EbiTraitEventLog, math::fraction::{Fraction, FractionNotParsedYet}, techniques::statistical_test::StatisticalTests};
+use crate::{ebi_framework::{ebi_command::EbiCommand, ebi_input::EbiInputType, ebi_output::{EbiOutput, EbiOutputType}, ebi_trait::EbiTrait}, ebi_info, ebi_traits::ebi_trait_event_log::EbiTraitEventLog, math::{fraction::{Fraction, FractionNotParsedYet}, trace_distance::TraceDistance}, techniques::statistical_test::StatisticalTests};
 
-use super::ebi_command_association::{self, number_of_samples};
+use super::{ebi_command_analyse::{cli_distance, get_distance}, ebi_command_association::{self, number_of_samples}};
 
 macro_rules! p_value {
     () => {Fraction::from((1usize, 20usize))};
@@ -27,7 +27,7 @@ pub const TEST_LOG_ATTRIBUTE: EbiCommand = EbiCommand::Command {
     explanation_short: "Test the hypothesis that the sub-logs defined by the categorical attribute are derived from identical processes.", 
     explanation_long: Some(concat!("Test the hypothesis that the sub-logs defined by the categorical attribute are derived from identical processes.; ", number_of_samples!(), " samples are taken.")), 
     latex_link: Some("\\cite{DBLP:journals/tkde/LeemansMPH23}"), 
-    cli_command: Some(|command| cli_p_value(ebi_command_association::cli_number_of_samples(command))), 
+    cli_command: Some(|command| cli_distance(cli_p_value(ebi_command_association::cli_number_of_samples(command)), "normalised-levenshtein")), 
     exact_arithmetic: true, 
     input_types: &[ 
         &[&EbiInputType::Trait(EbiTrait::EventLog)], 
@@ -40,8 +40,9 @@ pub const TEST_LOG_ATTRIBUTE: EbiCommand = EbiCommand::Command {
         let attribute = inputs.remove(0).to_type::<String>()?;
         let number_of_samples = cli_matches.unwrap().get_one::<usize>("samples").unwrap();
         let p_value = cli_matches.unwrap().get_one::<FractionNotParsedYet>("pvalue").unwrap().try_into().context("Parsing p value")?;
+        let distance = cli_matches.map_or(Ok(TraceDistance::default()), get_distance)?;
 
-        let (value, sustained)= event_log.log_categorical_attribute( *number_of_samples, &attribute, &p_value).with_context(|| format!("attribute {}", attribute))?;
+        let (value, sustained)= event_log.log_categorical_attribute( *number_of_samples, &attribute, &p_value, &distance).with_context(|| format!("attribute {}", attribute))?;
 
         let mut f = vec![];
         writeln!(f, "p-value \t {}", value)?;
@@ -36,11 +36,14 @@ pub const EBI_VISUALISE_TEXT: EbiCommand = EbiCommand::Command {
                 EbiInput::Object(EbiObject::EventLog(log), _) => log.to_string(),
                 EbiInput::Object(EbiObject::FiniteLanguage(language), _) => language.to_string(),
                 EbiInput::Object(EbiObject::DirectlyFollowsModel(d), _) => d.to_string(),
+                EbiInput::Object(EbiObject::StochasticDirectlyFollowsModel(d), _) => d.to_string(),
                 EbiInput::Object(EbiObject::LanguageOfAlignments(a), _) => a.to_string(),
                 EbiInput::Object(EbiObject::StochasticLanguageOfAlignments(a), _) => a.to_string(),
                 EbiInput::Object(EbiObject::DeterministicFiniteAutomaton(s), _) => s.to_string(),
                 EbiInput::Object(EbiObject::ProcessTree(pt), _) => pt.to_string(),
+                EbiInput::Object(EbiObject::StochasticProcessTree(pt), _) => pt.to_string(),
                 EbiInput::Object(EbiObject::Executions(s), _) => s.to_string(),
+                EbiInput::Object(EbiObject::ObjectCentricEventLog(log), _) => log.to_string(),
                 EbiInput::FileHandler(_) => unreachable!(),
                 EbiInput::Trait(_, _) => unreachable!(),
                 EbiInput::String(_) => unreachable!(),
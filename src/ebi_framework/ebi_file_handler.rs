@@ -1,12 +1,15 @@
 use std::{collections::BTreeSet, fmt::Display, hash::Hash, io::BufRead, str::FromStr};
 use anyhow::{anyhow, Result, Error};
 
-use crate::{ebi_commands::ebi_command_validate::EBI_VALIDATE, ebi_objects::{compressed_event_log::EBI_COMPRESSED_EVENT_LOG, deterministic_finite_automaton::EBI_DETERMINISTIC_FINITE_AUTOMATON, directly_follows_model::EBI_DIRCTLY_FOLLOWS_MODEL, event_log::EBI_EVENT_LOG, executions::EBI_EXECUTIONS, finite_language::EBI_FINITE_LANGUAGE, finite_stochastic_language::EBI_FINITE_STOCHASTIC_LANGUAGE, labelled_petri_net::EBI_LABELLED_PETRI_NET, language_of_alignments::EBI_LANGUAGE_OF_ALIGNMENTS, petri_net_markup_language::EBI_PETRI_NET_MARKUP_LANGUAGE, process_tree::EBI_PROCESS_TREE, stochastic_deterministic_finite_automaton::EBI_STOCHASTIC_DETERMINISTIC_FINITE_AUTOMATON, stochastic_labelled_petri_net::EBI_STOCHASTIC_LABELLED_PETRI_NET, stochastic_language_of_alignments::EBI_STOCHASTIC_LANGUAGE_OF_ALIGNMENTS}};
+use crate::{ebi_commands::ebi_command_validate::EBI_VALIDATE, ebi_objects::{business_process_model_and_notation::EBI_BUSINESS_PROCESS_MODEL_AND_NOTATION, compressed_event_log::EBI_COMPRESSED_EVENT_LOG, csv_event_log::EBI_CSV_EVENT_LOG, csv_executions::EBI_CSV_EXECUTIONS, deterministic_finite_automaton::EBI_DETERMINISTIC_FINITE_AUTOMATON, directly_follows_model::EBI_DIRCTLY_FOLLOWS_MODEL, event_log::EBI_EVENT_LOG, executions::EBI_EXECUTIONS, finite_language::EBI_FINITE_LANGUAGE, finite_stochastic_language::EBI_FINITE_STOCHASTIC_LANGUAGE, labelled_petri_net::EBI_LABELLED_PETRI_NET, language_of_alignments::EBI_LANGUAGE_OF_ALIGNMENTS, object_centric_event_log::EBI_OBJECT_CENTRIC_EVENT_LOG, petri_net_markup_language::EBI_PETRI_NET_MARKUP_LANGUAGE, pm4py_process_tree::EBI_PM4PY_PROCESS_TREE, process_tree::EBI_PROCESS_TREE, process_tree_markup_language::EBI_PROCESS_TREE_MARKUP_LANGUAGE, stochastic_deterministic_finite_automaton::EBI_STOCHASTIC_DETERMINISTIC_FINITE_AUTOMATON, stochastic_directly_follows_model::EBI_STOCHASTIC_DIRECTLY_FOLLOWS_MODEL, stochastic_labelled_petri_net::EBI_STOCHASTIC_LABELLED_PETRI_NET, stochastic_language_of_alignments::EBI_STOCHASTIC_LANGUAGE_OF_ALIGNMENTS, stochastic_process_tree::EBI_STOCHASTIC_PROCESS_TREE, xml_object_centric_event_log::EBI_XML_OBJECT_CENTRIC_EVENT_LOG}};
 
 use super::{ebi_command::{EbiCommand, EBI_COMMANDS}, ebi_input::{EbiInput, EbiObjectImporter, EbiTraitImporter}, ebi_output::{EbiObjectExporter, EbiOutputType}, ebi_trait::FromEbiTraitObject, prom_link::JavaObjectHandler};
 
 pub const EBI_FILE_HANDLERS: &'static [EbiFileHandler] = &[
+    EBI_BUSINESS_PROCESS_MODEL_AND_NOTATION,
     EBI_COMPRESSED_EVENT_LOG,
+    EBI_CSV_EVENT_LOG,
+    EBI_CSV_EXECUTIONS,
     EBI_DETERMINISTIC_FINITE_AUTOMATON,
     EBI_DIRCTLY_FOLLOWS_MODEL,
     EBI_EVENT_LOG,
@@ -15,11 +18,17 @@ pub const EBI_FILE_HANDLERS: &'static [EbiFileHandler] = &[
     EBI_FINITE_STOCHASTIC_LANGUAGE,
     EBI_LABELLED_PETRI_NET,
     EBI_LANGUAGE_OF_ALIGNMENTS,
+    EBI_OBJECT_CENTRIC_EVENT_LOG,
     EBI_PETRI_NET_MARKUP_LANGUAGE,
+    EBI_PM4PY_PROCESS_TREE,
     EBI_STOCHASTIC_DETERMINISTIC_FINITE_AUTOMATON,
+    EBI_STOCHASTIC_DIRECTLY_FOLLOWS_MODEL,
     EBI_STOCHASTIC_LABELLED_PETRI_NET,
     EBI_PROCESS_TREE,
+    EBI_PROCESS_TREE_MARKUP_LANGUAGE,
     EBI_STOCHASTIC_LANGUAGE_OF_ALIGNMENTS,
+    EBI_STOCHASTIC_PROCESS_TREE,
+    EBI_XML_OBJECT_CENTRIC_EVENT_LOG,
 ];
 
 #[derive(Clone,Debug)]
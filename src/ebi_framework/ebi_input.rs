@@ -284,6 +284,7 @@ impl Display for EbiTraitImporter {
 pub enum EbiObjectImporter {
     EventLog(fn(&mut dyn BufRead) -> Result<EbiObject>),
     DirectlyFollowsModel(fn(&mut dyn BufRead) -> Result<EbiObject>),
+    StochasticDirectlyFollowsModel(fn(&mut dyn BufRead) -> Result<EbiObject>),
     FiniteLanguage(fn(&mut dyn BufRead) -> Result<EbiObject>),
     FiniteStochasticLanguage(fn(&mut dyn BufRead) -> Result<EbiObject>),
     LabelledPetriNet(fn(&mut dyn BufRead) -> Result<EbiObject>),
@@ -292,7 +293,9 @@ pub enum EbiObjectImporter {
     LanguageOfAlignments(fn(&mut dyn BufRead) -> Result<EbiObject>),
     DeterministicFiniteAutomaton(fn(&mut dyn BufRead) -> Result<EbiObject>),
     ProcessTree(fn(&mut dyn BufRead) -> Result<EbiObject>),
+    StochasticProcessTree(fn(&mut dyn BufRead) -> Result<EbiObject>),
     Executions(fn(&mut dyn BufRead) -> Result<EbiObject>),
+    ObjectCentricEventLog(fn(&mut dyn BufRead) -> Result<EbiObject>),
     StochasticLanguageOfAlignments(fn(&mut dyn BufRead) -> Result<EbiObject>),
 }
 
@@ -301,6 +304,7 @@ impl EbiObjectImporter {
         match self {
             EbiObjectImporter::EventLog(_) => EbiObjectType::EventLog,
             EbiObjectImporter::DirectlyFollowsModel(_) => EbiObjectType::DirectlyFollowsModel,
+            EbiObjectImporter::StochasticDirectlyFollowsModel(_) => EbiObjectType::StochasticDirectlyFollowsModel,
             EbiObjectImporter::FiniteLanguage(_) => EbiObjectType::FiniteLanguage,
             EbiObjectImporter::FiniteStochasticLanguage(_) => EbiObjectType::FiniteStochasticLanguage,
             EbiObjectImporter::LabelledPetriNet(_) => EbiObjectType::LabelledPetriNet,
@@ -310,7 +314,9 @@ impl EbiObjectImporter {
             EbiObjectImporter::StochasticLanguageOfAlignments(_) => EbiObjectType::StochasticLanguageOfAlignments,
             EbiObjectImporter::DeterministicFiniteAutomaton(_) => EbiObjectType::DeterministicFiniteAutomaton,
             EbiObjectImporter::ProcessTree(_) => EbiObjectType::ProcessTree,
+            EbiObjectImporter::StochasticProcessTree(_) => EbiObjectType::StochasticProcessTree,
             EbiObjectImporter::Executions(_) => EbiObjectType::Executions,
+            EbiObjectImporter::ObjectCentricEventLog(_) => EbiObjectType::ObjectCentricEventLog,
         }
     }
     
@@ -318,6 +324,7 @@ impl EbiObjectImporter {
         match self {
             EbiObjectImporter::EventLog(importer) => *importer,
             EbiObjectImporter::DirectlyFollowsModel(importer) => *importer,
+            EbiObjectImporter::StochasticDirectlyFollowsModel(importer) => *importer,
             EbiObjectImporter::FiniteLanguage(importer) => *importer,
             EbiObjectImporter::FiniteStochasticLanguage(importer) => *importer,
             EbiObjectImporter::LabelledPetriNet(importer) => *importer,
@@ -327,7 +334,9 @@ impl EbiObjectImporter {
             EbiObjectImporter::StochasticLanguageOfAlignments(importer) => *importer,
             EbiObjectImporter::DeterministicFiniteAutomaton(importer) => *importer,
             EbiObjectImporter::ProcessTree(importer) => *importer,
+            EbiObjectImporter::StochasticProcessTree(importer) => *importer,
             EbiObjectImporter::Executions(importer) => *importer,
+            EbiObjectImporter::ObjectCentricEventLog(importer) => *importer,
         }
     }
 }
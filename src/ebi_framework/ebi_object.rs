@@ -2,7 +2,7 @@ use std::{collections::{BTreeSet, HashSet}, fmt::Display};
 use anyhow::Result;
 use strum_macros::EnumIter;
 
-use crate::{ebi_objects::{deterministic_finite_automaton::DeterministicFiniteAutomaton, directly_follows_model::DirectlyFollowsModel, event_log::EventLog, executions::Executions, finite_language::FiniteLanguage, finite_stochastic_language::FiniteStochasticLanguage, labelled_petri_net::LabelledPetriNet, language_of_alignments::LanguageOfAlignments, process_tree::ProcessTree, stochastic_deterministic_finite_automaton::StochasticDeterministicFiniteAutomaton, stochastic_labelled_petri_net::StochasticLabelledPetriNet, stochastic_language_of_alignments::StochasticLanguageOfAlignments}, ebi_traits::{ebi_trait_event_log::EbiTraitEventLog, ebi_trait_finite_language::EbiTraitFiniteLanguage, ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_graphable::EbiTraitGraphable, ebi_trait_iterable_language::EbiTraitIterableLanguage, ebi_trait_iterable_stochastic_language::EbiTraitIterableStochasticLanguage, ebi_trait_queriable_stochastic_language::EbiTraitQueriableStochasticLanguage, ebi_trait_semantics::EbiTraitSemantics, ebi_trait_stochastic_deterministic_semantics::EbiTraitStochasticDeterministicSemantics, ebi_trait_stochastic_semantics::EbiTraitStochasticSemantics}};
+use crate::{ebi_objects::{deterministic_finite_automaton::DeterministicFiniteAutomaton, directly_follows_model::DirectlyFollowsModel, event_log::EventLog, executions::Executions, finite_language::FiniteLanguage, finite_stochastic_language::FiniteStochasticLanguage, labelled_petri_net::LabelledPetriNet, language_of_alignments::LanguageOfAlignments, object_centric_event_log::ObjectCentricEventLog, process_tree::ProcessTree, stochastic_deterministic_finite_automaton::StochasticDeterministicFiniteAutomaton, stochastic_directly_follows_model::StochasticDirectlyFollowsModel, stochastic_labelled_petri_net::StochasticLabelledPetriNet, stochastic_language_of_alignments::StochasticLanguageOfAlignments, stochastic_process_tree::StochasticProcessTree}, ebi_traits::{ebi_trait_event_log::EbiTraitEventLog, ebi_trait_finite_language::EbiTraitFiniteLanguage, ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_graphable::EbiTraitGraphable, ebi_trait_iterable_language::EbiTraitIterableLanguage, ebi_trait_iterable_stochastic_language::EbiTraitIterableStochasticLanguage, ebi_trait_queriable_stochastic_language::EbiTraitQueriableStochasticLanguage, ebi_trait_semantics::EbiTraitSemantics, ebi_trait_stochastic_deterministic_semantics::EbiTraitStochasticDeterministicSemantics, ebi_trait_stochastic_semantics::EbiTraitStochasticSemantics}};
 
 use super::{ebi_command::{EbiCommand, EBI_COMMANDS}, ebi_file_handler::{EbiFileHandler, EBI_FILE_HANDLERS}, ebi_input::EbiInputType, ebi_output::EbiOutputType, ebi_trait::EbiTrait, infoable::Infoable, prom_link::JavaObjectHandler};
 
@@ -14,13 +14,16 @@ pub enum EbiObjectType {
     StochasticDeterministicFiniteAutomaton,
     DeterministicFiniteAutomaton,
     DirectlyFollowsModel,
+    StochasticDirectlyFollowsModel,
     EventLog,
     FiniteLanguage,
     FiniteStochasticLanguage,
     LabelledPetriNet,
     StochasticLabelledPetriNet,
     ProcessTree,
+    StochasticProcessTree,
     Executions,
+    ObjectCentricEventLog,
 }
 
 impl EbiObjectType {
@@ -34,11 +37,14 @@ impl EbiObjectType {
             EbiObjectType::EventLog => "an",
             EbiObjectType::FiniteLanguage => "a",
             EbiObjectType::DirectlyFollowsModel => "a",
+            EbiObjectType::StochasticDirectlyFollowsModel => "a",
             EbiObjectType::LanguageOfAlignments => "",
             EbiObjectType::StochasticLanguageOfAlignments => "an",
             EbiObjectType::DeterministicFiniteAutomaton => "a",
             EbiObjectType::ProcessTree => "a",
+            EbiObjectType::StochasticProcessTree => "a",
             EbiObjectType::Executions => "",
+            EbiObjectType::ObjectCentricEventLog => "an",
         }
     }
     
@@ -94,11 +100,14 @@ impl Display for EbiObjectType {
             EbiObjectType::EventLog => "event log",
             EbiObjectType::FiniteLanguage => "finite language",
             EbiObjectType::DirectlyFollowsModel => "directly follows model",
+            EbiObjectType::StochasticDirectlyFollowsModel => "stochastic directly follows model",
             EbiObjectType::LanguageOfAlignments => "alignments",
             EbiObjectType::StochasticLanguageOfAlignments => "stochastic language of alignments",
             EbiObjectType::DeterministicFiniteAutomaton => "deterministic finite automaton",
             EbiObjectType::ProcessTree => "process tree",
+            EbiObjectType::StochasticProcessTree => "stochastic process tree",
             EbiObjectType::Executions => "executions",
+            EbiObjectType::ObjectCentricEventLog => "object-centric event log",
         })
     }
 }
@@ -111,11 +120,14 @@ pub enum EbiObject {
     EventLog(EventLog),
     FiniteLanguage(FiniteLanguage),
     DirectlyFollowsModel(DirectlyFollowsModel),
+    StochasticDirectlyFollowsModel(StochasticDirectlyFollowsModel),
     LanguageOfAlignments(LanguageOfAlignments),
     StochasticLanguageOfAlignments(StochasticLanguageOfAlignments),
     DeterministicFiniteAutomaton(DeterministicFiniteAutomaton),
     ProcessTree(ProcessTree),
+    StochasticProcessTree(StochasticProcessTree),
     Executions(Executions),
+    ObjectCentricEventLog(ObjectCentricEventLog),
 }
 
 impl EbiObject {
@@ -128,11 +140,14 @@ impl EbiObject {
             EbiObject::EventLog(_) => EbiObjectType::EventLog,
             EbiObject::FiniteLanguage(_) => EbiObjectType::FiniteLanguage,
             EbiObject::DirectlyFollowsModel(_) => EbiObjectType::DirectlyFollowsModel,
+            EbiObject::StochasticDirectlyFollowsModel(_) => EbiObjectType::StochasticDirectlyFollowsModel,
             EbiObject::LanguageOfAlignments(_) => EbiObjectType::LanguageOfAlignments,
             EbiObject::StochasticLanguageOfAlignments(_) => EbiObjectType::StochasticLanguageOfAlignments,
             EbiObject::DeterministicFiniteAutomaton(_) => EbiObjectType::DeterministicFiniteAutomaton,
             EbiObject::ProcessTree(_) => EbiObjectType::ProcessTree,
+            EbiObject::StochasticProcessTree(_) => EbiObjectType::StochasticProcessTree,
             EbiObject::Executions(_) => EbiObjectType::Executions,
+            EbiObject::ObjectCentricEventLog(_) => EbiObjectType::ObjectCentricEventLog,
         }
     }
 }
@@ -147,11 +162,14 @@ impl Display for EbiObject {
             EbiObject::EventLog(o) => write!(f, "{}", o),
             EbiObject::FiniteLanguage(o) => write!(f, "{}", o),
             EbiObject::DirectlyFollowsModel(o) => write!(f, "{}", o),
+            EbiObject::StochasticDirectlyFollowsModel(o) => write!(f, "{}", o),
             EbiObject::LanguageOfAlignments(o) => write!(f, "{}", o),
             EbiObject::StochasticLanguageOfAlignments(o) => write!(f, "{}", o),
             EbiObject::DeterministicFiniteAutomaton(o) => write!(f, "{}", o),
             EbiObject::ProcessTree(o) => write!(f, "{}", o),
+            EbiObject::StochasticProcessTree(o) => write!(f, "{}", o),
             EbiObject::Executions(o) => write!(f, "{}", o),
+            EbiObject::ObjectCentricEventLog(o) => write!(f, "{}", o),
         }
     }
 }
@@ -166,11 +184,14 @@ impl Infoable for EbiObject {
             EbiObject::EventLog(o) => o.info(f),
             EbiObject::FiniteLanguage(o) => o.info(f),
             EbiObject::DirectlyFollowsModel(o) => o.info(f),
+            EbiObject::StochasticDirectlyFollowsModel(o) => o.info(f),
             EbiObject::LanguageOfAlignments(o) => o.info(f),
             EbiObject::StochasticLanguageOfAlignments(o) => o.info(f),
             EbiObject::DeterministicFiniteAutomaton(o) => o.info(f),
             EbiObject::ProcessTree(o) => o.info(f),
+            EbiObject::StochasticProcessTree(o) => o.info(f),
             EbiObject::Executions(o) => o.info(f),
+            EbiObject::ObjectCentricEventLog(o) => o.info(f),
         }
     }
 }
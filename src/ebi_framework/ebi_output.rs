@@ -2,7 +2,7 @@ use std::{collections::{BTreeSet, HashSet}, fmt::{self, Display}, fs::File, io::
 use anyhow::{Context, Result};
 use strum_macros::{Display, EnumIter};
 
-use crate::{ebi_objects::{compressed_event_log::{CompressedEventLog, EBI_COMPRESSED_EVENT_LOG}, deterministic_finite_automaton::{DeterministicFiniteAutomaton, EBI_DETERMINISTIC_FINITE_AUTOMATON}, directly_follows_model::{DirectlyFollowsModel, EBI_DIRCTLY_FOLLOWS_MODEL}, executions::{Executions, EBI_EXECUTIONS}, finite_language::{FiniteLanguage, EBI_FINITE_LANGUAGE}, finite_stochastic_language::{FiniteStochasticLanguage, EBI_FINITE_STOCHASTIC_LANGUAGE}, labelled_petri_net::{LabelledPetriNet, EBI_LABELLED_PETRI_NET}, language_of_alignments::{LanguageOfAlignments, EBI_LANGUAGE_OF_ALIGNMENTS}, process_tree::{ProcessTree, EBI_PROCESS_TREE}, stochastic_deterministic_finite_automaton::{StochasticDeterministicFiniteAutomaton, EBI_STOCHASTIC_DETERMINISTIC_FINITE_AUTOMATON}, stochastic_labelled_petri_net::{StochasticLabelledPetriNet, EBI_STOCHASTIC_LABELLED_PETRI_NET}, stochastic_language_of_alignments::{StochasticLanguageOfAlignments, EBI_STOCHASTIC_LANGUAGE_OF_ALIGNMENTS}}, math::{fraction::Fraction, log_div::LogDiv, root::ContainsRoot, root_log_div::RootLogDiv}};
+use crate::{ebi_objects::{compressed_event_log::{CompressedEventLog, EBI_COMPRESSED_EVENT_LOG}, deterministic_finite_automaton::{DeterministicFiniteAutomaton, EBI_DETERMINISTIC_FINITE_AUTOMATON}, directly_follows_model::{DirectlyFollowsModel, EBI_DIRCTLY_FOLLOWS_MODEL}, executions::{Executions, EBI_EXECUTIONS}, finite_language::{FiniteLanguage, EBI_FINITE_LANGUAGE}, finite_stochastic_language::{FiniteStochasticLanguage, EBI_FINITE_STOCHASTIC_LANGUAGE}, labelled_petri_net::{LabelledPetriNet, EBI_LABELLED_PETRI_NET}, language_of_alignments::{LanguageOfAlignments, EBI_LANGUAGE_OF_ALIGNMENTS}, object_centric_event_log::{ObjectCentricEventLog, EBI_OBJECT_CENTRIC_EVENT_LOG}, process_tree::{ProcessTree, EBI_PROCESS_TREE}, stochastic_deterministic_finite_automaton::{StochasticDeterministicFiniteAutomaton, EBI_STOCHASTIC_DETERMINISTIC_FINITE_AUTOMATON}, stochastic_directly_follows_model::{StochasticDirectlyFollowsModel, EBI_STOCHASTIC_DIRECTLY_FOLLOWS_MODEL}, stochastic_labelled_petri_net::{StochasticLabelledPetriNet, EBI_STOCHASTIC_LABELLED_PETRI_NET}, stochastic_language_of_alignments::{StochasticLanguageOfAlignments, EBI_STOCHASTIC_LANGUAGE_OF_ALIGNMENTS}, stochastic_process_tree::{StochasticProcessTree, EBI_STOCHASTIC_PROCESS_TREE}}, math::{fraction::Fraction, log_div::LogDiv, root::ContainsRoot, root_log_div::RootLogDiv}};
 
 use super::{ebi_command::{EbiCommand, EBI_COMMANDS}, ebi_file_handler::{EbiFileHandler, EBI_FILE_HANDLERS}, ebi_object::{EbiObject, EbiObjectType}, exportable::Exportable, prom_link::{JavaObjectHandler, JAVA_OBJECT_HANDLERS_CONTAINSROOT, JAVA_OBJECT_HANDLERS_FRACTION, JAVA_OBJECT_HANDLERS_LOGDIV, JAVA_OBJECT_HANDLERS_PDF, JAVA_OBJECT_HANDLERS_ROOTLOGDIV, JAVA_OBJECT_HANDLERS_STRING, JAVA_OBJECT_HANDLERS_SVG, JAVA_OBJECT_HANDLERS_USIZE}};
 
@@ -99,6 +99,7 @@ impl EbiOutputType {
             EbiOutputType::ObjectType(EbiObjectType::StochasticLanguageOfAlignments) => EbiExporter::Object(&EbiObjectExporter::StochasticLanguageOfAlignments(StochasticLanguageOfAlignments::export_from_object), &EBI_STOCHASTIC_LANGUAGE_OF_ALIGNMENTS),
             EbiOutputType::ObjectType(EbiObjectType::DeterministicFiniteAutomaton) => EbiExporter::Object(&EbiObjectExporter::DeterministicFiniteAutomaton(DeterministicFiniteAutomaton::export_from_object), &EBI_DETERMINISTIC_FINITE_AUTOMATON),
             EbiOutputType::ObjectType(EbiObjectType::DirectlyFollowsModel) => EbiExporter::Object(&EbiObjectExporter::DirectlyFollowsModel(DirectlyFollowsModel::export_from_object), &EBI_DIRCTLY_FOLLOWS_MODEL),
+            EbiOutputType::ObjectType(EbiObjectType::StochasticDirectlyFollowsModel) => EbiExporter::Object(&EbiObjectExporter::StochasticDirectlyFollowsModel(StochasticDirectlyFollowsModel::export_from_object), &EBI_STOCHASTIC_DIRECTLY_FOLLOWS_MODEL),
             EbiOutputType::ObjectType(EbiObjectType::EventLog) => EbiExporter::Object(&EbiObjectExporter::EventLog(CompressedEventLog::export_from_object), &EBI_COMPRESSED_EVENT_LOG),
             EbiOutputType::ObjectType(EbiObjectType::Executions) => EbiExporter::Object(&EbiObjectExporter::Executions(Executions::export_from_object), &EBI_EXECUTIONS),
             EbiOutputType::ObjectType(EbiObjectType::FiniteLanguage) => EbiExporter::Object(&EbiObjectExporter::FiniteLanguage(FiniteLanguage::export_from_object), &EBI_FINITE_LANGUAGE),
@@ -107,6 +108,8 @@ impl EbiOutputType {
             EbiOutputType::ObjectType(EbiObjectType::StochasticDeterministicFiniteAutomaton) => EbiExporter::Object(&&EbiObjectExporter::StochasticDeterministicFiniteAutomaton(StochasticDeterministicFiniteAutomaton::export_from_object), &EBI_STOCHASTIC_DETERMINISTIC_FINITE_AUTOMATON),
             EbiOutputType::ObjectType(EbiObjectType::StochasticLabelledPetriNet) => EbiExporter::Object(&&EbiObjectExporter::StochasticLabelledPetriNet(StochasticLabelledPetriNet::export_from_object), &EBI_STOCHASTIC_LABELLED_PETRI_NET),
             EbiOutputType::ObjectType(EbiObjectType::ProcessTree) => EbiExporter::Object(&&EbiObjectExporter::ProcessTree(ProcessTree::export_from_object), &EBI_PROCESS_TREE),
+            EbiOutputType::ObjectType(EbiObjectType::StochasticProcessTree) => EbiExporter::Object(&&EbiObjectExporter::StochasticProcessTree(StochasticProcessTree::export_from_object), &EBI_STOCHASTIC_PROCESS_TREE),
+            EbiOutputType::ObjectType(EbiObjectType::ObjectCentricEventLog) => EbiExporter::Object(&EbiObjectExporter::ObjectCentricEventLog(ObjectCentricEventLog::export_from_object), &EBI_OBJECT_CENTRIC_EVENT_LOG),
             EbiOutputType::String => EbiExporter::String,
             EbiOutputType::SVG => EbiExporter::SVG,
             EbiOutputType::PDF => EbiExporter::PDF,
@@ -275,6 +278,7 @@ impl Display for EbiExporter {
 pub enum EbiObjectExporter {
     EventLog(fn(object: EbiOutput, &mut dyn std::io::Write) -> Result<()>),
     DirectlyFollowsModel(fn(object: EbiOutput, &mut dyn std::io::Write) -> Result<()>),
+    StochasticDirectlyFollowsModel(fn(object: EbiOutput, &mut dyn std::io::Write) -> Result<()>),
     FiniteLanguage(fn(object: EbiOutput, &mut dyn std::io::Write) -> Result<()>),
     FiniteStochasticLanguage(fn(object: EbiOutput, &mut dyn std::io::Write) -> Result<()>),
     LabelledPetriNet(fn(object: EbiOutput, &mut dyn std::io::Write) -> Result<()>),
@@ -284,7 +288,9 @@ pub enum EbiObjectExporter {
     StochasticLanguageOfAlignments(fn(object: EbiOutput, &mut dyn std::io::Write) -> Result<()>),
     DeterministicFiniteAutomaton(fn(object: EbiOutput, &mut dyn std::io::Write) -> Result<()>),
     ProcessTree(fn(object: EbiOutput, &mut dyn std::io::Write) -> Result<()>),
+    StochasticProcessTree(fn(object: EbiOutput, &mut dyn std::io::Write) -> Result<()>),
     Executions(fn(object: EbiOutput, &mut dyn std::io::Write) -> Result<()>),
+    ObjectCentricEventLog(fn(object: EbiOutput, &mut dyn std::io::Write) -> Result<()>),
 }
 
 impl EbiObjectExporter {
@@ -292,6 +298,7 @@ impl EbiObjectExporter {
         match self {
             EbiObjectExporter::EventLog(_) => EbiObjectType::EventLog,
             EbiObjectExporter::DirectlyFollowsModel(_) => EbiObjectType::DirectlyFollowsModel,
+            EbiObjectExporter::StochasticDirectlyFollowsModel(_) => EbiObjectType::StochasticDirectlyFollowsModel,
             EbiObjectExporter::FiniteLanguage(_) => EbiObjectType::FiniteLanguage,
             EbiObjectExporter::FiniteStochasticLanguage(_) => EbiObjectType::FiniteStochasticLanguage,
             EbiObjectExporter::LabelledPetriNet(_) => EbiObjectType::LabelledPetriNet,
@@ -301,7 +308,9 @@ impl EbiObjectExporter {
             EbiObjectExporter::StochasticLanguageOfAlignments(_) => EbiObjectType::StochasticLanguageOfAlignments,
             EbiObjectExporter::DeterministicFiniteAutomaton(_) => EbiObjectType::DeterministicFiniteAutomaton,
             EbiObjectExporter::ProcessTree(_) => EbiObjectType::ProcessTree,
+            EbiObjectExporter::StochasticProcessTree(_) => EbiObjectType::StochasticProcessTree,
             EbiObjectExporter::Executions(_) => EbiObjectType::Executions,
+            EbiObjectExporter::ObjectCentricEventLog(_) => EbiObjectType::ObjectCentricEventLog,
         }
     }
 
@@ -309,6 +318,7 @@ impl EbiObjectExporter {
         match self {
             EbiObjectExporter::EventLog(exporter) => (exporter)(object, f),
             EbiObjectExporter::DirectlyFollowsModel(exporter) => (exporter)(object, f),
+            EbiObjectExporter::StochasticDirectlyFollowsModel(exporter) => (exporter)(object, f),
             EbiObjectExporter::FiniteLanguage(exporter) => (exporter)(object, f),
             EbiObjectExporter::FiniteStochasticLanguage(exporter) => (exporter)(object, f),
             EbiObjectExporter::LabelledPetriNet(exporter) => (exporter)(object, f),
@@ -318,7 +328,9 @@ impl EbiObjectExporter {
             EbiObjectExporter::StochasticLanguageOfAlignments(exporter) => (exporter)(object, f),
             EbiObjectExporter::DeterministicFiniteAutomaton(exporter) => (exporter)(object, f),
             EbiObjectExporter::ProcessTree(exporter) => (exporter)(object, f),
+            EbiObjectExporter::StochasticProcessTree(exporter) => (exporter)(object, f),
             EbiObjectExporter::Executions(exporter) => (exporter)(object, f),
+            EbiObjectExporter::ObjectCentricEventLog(exporter) => (exporter)(object, f),
         }
     }
 }
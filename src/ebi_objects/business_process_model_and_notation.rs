@@ -0,0 +1,322 @@
+use std::{collections::HashMap, io::BufRead};
+
+use anyhow::{anyhow, Result};
+use roxmltree::Node;
+
+use crate::{ebi_framework::{activity_key::{Activity, HasActivityKey}, ebi_file_handler::EbiFileHandler, ebi_input::{self, EbiObjectImporter, EbiTraitImporter}, ebi_object::EbiObject, importable::Importable}, ebi_traits::{ebi_trait_graphable::EbiTraitGraphable, ebi_trait_semantics::EbiTraitSemantics}, techniques::coverability::Coverability};
+
+use super::labelled_petri_net::LabelledPetriNet;
+
+pub const FORMAT_SPECIFICATION: &str = "A business process model and notation file follows the BPMN 2.0 XML format~\\cite{bpmn}.
+Ebi translates the processes in the file into a labelled Petri net, following~\\cite{DBLP:journals/infsof/DijkmanDO08}.
+Supported are tasks (including user, service, manual, script, business rule, send and receive tasks), exclusive, parallel and inclusive gateways, start and end events, sequence flows and embedded sub-processes.
+Tasks are labelled with their name, and all other elements become silent transitions.
+Conditions on sequence flows are ignored, that is, each choice is non-deterministic.
+The synchronisation of an inclusive gateway is approximated by allowing any non-empty subset of its incoming sequence flows to synchronise.
+A sub-process completes when one of its end events has been reached; a sub-process in which an end event can be reached while another part of it is still active, for instance when parallel branches end in separate end events, is not supported and causes an error.
+Lanes, data objects, annotations and diagram information are ignored.
+Any other element, such as a boundary event, an intermediate event or a message flow, is not supported and causes an error.";
+
+pub const EBI_BUSINESS_PROCESS_MODEL_AND_NOTATION: EbiFileHandler = EbiFileHandler {
+    name: "business process model and notation",
+    article: "a",
+    file_extension: "bpmn",
+    format_specification: &FORMAT_SPECIFICATION,
+    validator: ebi_input::validate::<BusinessProcessModelAndNotation>,
+    trait_importers: &[
+        EbiTraitImporter::Semantics(BusinessProcessModelAndNotation::import_as_semantics),
+        EbiTraitImporter::Graphable(BusinessProcessModelAndNotation::import_as_graphable),
+    ],
+    object_importers: &[
+        EbiObjectImporter::LabelledPetriNet(BusinessProcessModelAndNotation::import_as_object),
+    ],
+    object_exporters: &[],
+    java_object_handlers: &[],
+};
+
+const TASKS: [&str; 8] = ["task", "userTask", "serviceTask", "manualTask", "scriptTask", "businessRuleTask", "sendTask", "receiveTask"];
+const GATEWAYS: [&str; 3] = ["exclusiveGateway", "parallelGateway", "inclusiveGateway"];
+
+/**
+ * Elements that do not influence the control flow.
+ */
+const IGNORED_ELEMENTS: [&str; 16] = ["documentation", "extensionElements", "laneSet", "textAnnotation", "association", "group", "dataObject", "dataObjectReference", "dataStoreReference", "ioSpecification", "property", "dataInputAssociation", "dataOutputAssociation", "dataInput", "dataOutput", "conditionExpression"];
+
+/**
+ * Event definitions that merely trigger a start event or are thrown by an end event, without influencing the control flow.
+ */
+const IGNORED_EVENT_DEFINITIONS: [&str; 4] = ["messageEventDefinition", "timerEventDefinition", "signalEventDefinition", "conditionalEventDefinition"];
+
+/**
+ * The maximum number of sequence flows of an inclusive gateway, as each subset of them becomes a transition.
+ */
+const MAX_INCLUSIVE_GATEWAY_FLOWS: usize = 10;
+
+pub struct BusinessProcessModelAndNotation {
+    net: LabelledPetriNet
+}
+
+impl BusinessProcessModelAndNotation {
+    pub fn import_as_semantics(reader: &mut dyn BufRead) -> Result<EbiTraitSemantics> {
+        Ok(EbiTraitSemantics::Marking(Box::new(Self::import(reader)?.net)))
+    }
+
+    pub fn import_as_graphable(reader: &mut dyn BufRead) -> Result<Box<dyn EbiTraitGraphable>> {
+        Ok(Box::new(Self::import(reader)?.net))
+    }
+}
+
+impl Importable for BusinessProcessModelAndNotation {
+    fn import_as_object(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::LabelledPetriNet(Self::import(reader)?.net))
+    }
+
+    fn import(reader: &mut dyn BufRead) -> Result<Self> where Self: Sized {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        let document = roxmltree::Document::parse(&text)?;
+        let definitions = document.root_element();
+        if !definitions.has_tag_name("definitions") {
+            return Err(anyhow!("file is not a BPMN file"));
+        }
+
+        let mut translator = Translator::new();
+        let mut processes = 0;
+        for child in definitions.children().filter(|node| node.is_element()) {
+            match child.tag_name().name() {
+                "process" => {
+                    //each process starts with a token in each of its start events
+                    for place in translator.translate_scope(child, None)? {
+                        translator.net.get_initial_marking_mut().increase(place, 1)?;
+                    }
+                    processes += 1;
+                },
+                "collaboration" => {
+                    if let Some(message_flow) = child.descendants().find(|node| node.has_tag_name("messageFlow")) {
+                        return Err(unsupported(message_flow));
+                    }
+                },
+                _ => {}, //messages, signals, diagrams, etc.
+            }
+        }
+
+        if processes == 0 {
+            return Err(anyhow!("BPMN file does not contain a process."));
+        }
+
+        Ok(Self {
+            net: translator.net
+        })
+    }
+}
+
+impl From<BusinessProcessModelAndNotation> for LabelledPetriNet {
+    fn from(value: BusinessProcessModelAndNotation) -> Self {
+        value.net
+    }
+}
+
+struct Translator {
+    net: LabelledPetriNet,
+}
+
+impl Translator {
+    fn new() -> Self {
+        Self {
+            net: LabelledPetriNet::new(),
+        }
+    }
+
+    /**
+     * Translates a process or sub-process. Each sequence flow becomes a place; flows that enter an element that merges them (all but parallel and inclusive gateways) share their place.
+     * End events put a token in the end place if given.
+     * Returns the input places of the start events.
+     */
+    fn translate_scope(&mut self, scope: Node, end_place: Option<usize>) -> Result<Vec<usize>> {
+        //gather the elements and sequence flows of this scope
+        let mut nodes = vec![];
+        let mut flows = vec![];
+        for child in scope.children().filter(|node| node.is_element()) {
+            let tag = child.tag_name().name();
+            if tag == "sequenceFlow" {
+                flows.push(child);
+            } else if TASKS.contains(&tag) || GATEWAYS.contains(&tag) || tag == "startEvent" || tag == "endEvent" || tag == "subProcess" {
+                if tag == "subProcess" && child.attribute("triggeredByEvent") == Some("true") {
+                    return Err(unsupported(child));
+                }
+                if tag != "subProcess" {
+                    check_children(child)?;
+                }
+                nodes.push(child);
+            } else if !IGNORED_ELEMENTS.contains(&tag) && tag != "incoming" && tag != "outgoing" {
+                //incoming and outgoing are the references of a sub-process to its own sequence flows
+                return Err(unsupported(child));
+            }
+        }
+        let node2index = nodes.iter().enumerate().map(|(index, node)| Ok((get_id(*node)?, index))).collect::<Result<HashMap<_, _>>>()?;
+
+        //connect the sequence flows
+        let mut node2incoming = vec![vec![]; nodes.len()];
+        let mut node2outgoing = vec![vec![]; nodes.len()];
+        for flow in &flows {
+            let id = get_id(*flow)?;
+            let source = flow.attribute("sourceRef").ok_or_else(|| anyhow!("Sequence flow `{}` has no source.", id))?;
+            let target = flow.attribute("targetRef").ok_or_else(|| anyhow!("Sequence flow `{}` has no target.", id))?;
+            let source = node2index.get(source).ok_or_else(|| anyhow!("Sequence flow `{}` has source `{}`, which is not a supported element in the same (sub-)process.", id, source))?;
+            let target = node2index.get(target).ok_or_else(|| anyhow!("Sequence flow `{}` has target `{}`, which is not a supported element in the same (sub-)process.", id, target))?;
+            node2outgoing[*source].push(id);
+            node2incoming[*target].push(id);
+        }
+
+        //create the places
+        let mut node2input_place = vec![None; nodes.len()];
+        let mut flow2place = HashMap::new();
+        for (index, node) in nodes.iter().enumerate() {
+            if node2incoming[index].is_empty() && !node.has_tag_name("startEvent") {
+                return Err(anyhow!("Element `{}` at line {} has no incoming sequence flow.", get_id(*node)?, get_line(*node)));
+            }
+
+            if node.has_tag_name("parallelGateway") || node.has_tag_name("inclusiveGateway") {
+                for flow in &node2incoming[index] {
+                    flow2place.insert(*flow, self.net.add_place());
+                }
+            } else {
+                let place = self.net.add_place();
+                node2input_place[index] = Some(place);
+                for flow in &node2incoming[index] {
+                    flow2place.insert(*flow, place);
+                }
+            }
+        }
+
+        //create the transitions
+        let mut start_places = vec![];
+        for (index, node) in nodes.iter().enumerate() {
+            let input_places = match node2input_place[index] {
+                Some(place) => vec![place],
+                None => node2incoming[index].iter().map(|flow| flow2place[flow]).collect(),
+            };
+            let output_places = node2outgoing[index].iter().map(|flow| flow2place[flow]).collect::<Vec<_>>();
+
+            match node.tag_name().name() {
+                "startEvent" => {
+                    start_places.extend(input_places.iter());
+                    self.add_transition(None, &input_places, &output_places)?;
+                },
+                "endEvent" => {
+                    self.add_transition(None, &input_places, &end_place.into_iter().collect())?;
+                },
+                "exclusiveGateway" => {
+                    if output_places.is_empty() {
+                        self.add_transition(None, &input_places, &vec![])?;
+                    }
+                    for output_place in output_places {
+                        self.add_transition(None, &input_places, &vec![output_place])?;
+                    }
+                },
+                "parallelGateway" => {
+                    self.add_transition(None, &input_places, &output_places)?;
+                },
+                "inclusiveGateway" => {
+                    if input_places.len() + output_places.len() > MAX_INCLUSIVE_GATEWAY_FLOWS {
+                        return Err(anyhow!("Inclusive gateway `{}` at line {} has more than {} sequence flows, which is not supported.", get_id(*node)?, get_line(*node), MAX_INCLUSIVE_GATEWAY_FLOWS));
+                    }
+                    for inputs in non_empty_subsets(&input_places) {
+                        if output_places.is_empty() {
+                            self.add_transition(None, &inputs, &vec![])?;
+                        }
+                        for outputs in non_empty_subsets(&output_places) {
+                            self.add_transition(None, &inputs, &outputs)?;
+                        }
+                    }
+                },
+                "subProcess" => {
+                    verify_completion(*node)?;
+                    let done_place = self.net.add_place();
+                    let inner_start_places = self.translate_scope(*node, Some(done_place))?;
+                    if inner_start_places.is_empty() {
+                        return Err(anyhow!("Sub-process `{}` at line {} has no start event.", get_id(*node)?, get_line(*node)));
+                    }
+                    self.add_transition(None, &input_places, &inner_start_places)?;
+                    self.add_transition(None, &vec![done_place], &output_places)?;
+                },
+                _ => {
+                    //task
+                    let name = match node.attribute("name") {
+                        Some(name) if !name.trim().is_empty() => name.split_whitespace().collect::<Vec<_>>().join(" "),
+                        _ => get_id(*node)?.to_string(),
+                    };
+                    let activity = self.net.get_activity_key_mut().process_activity(&name);
+                    self.add_transition(Some(activity), &input_places, &output_places)?;
+                }
+            }
+        }
+
+        Ok(start_places)
+    }
+
+    fn add_transition(&mut self, activity: Option<Activity>, input_places: &Vec<usize>, output_places: &Vec<usize>) -> Result<()> {
+        let transition = self.net.add_transition(activity);
+        for place in input_places {
+            self.net.add_place_transition_arc(*place, transition, 1)?;
+        }
+        for place in output_places {
+            self.net.add_transition_place_arc(transition, *place, 1)?;
+        }
+        Ok(())
+    }
+}
+
+/**
+ * Verifies that once an end event of the sub-process has been reached, no other part of the sub-process is still active.
+ * Otherwise, the sub-process would complete once for each end event, and its remaining tokens would keep running.
+ */
+fn verify_completion(sub_process: Node) -> Result<()> {
+    let mut translator = Translator::new();
+    let done_place = translator.net.add_place();
+    for place in translator.translate_scope(sub_process, Some(done_place))? {
+        translator.net.get_initial_marking_mut().increase(place, 1)?;
+    }
+
+    let graph = translator.net.get_coverability_graph()?;
+    if graph.markings.iter().any(|marking| marking[done_place] > 0 && marking.iter().enumerate().any(|(place, tokens)| place != done_place && *tokens > 0)) {
+        return Err(anyhow!("Sub-process `{}` at line {} can reach an end event while another part of it is still active, which is not supported.", get_id(sub_process)?, get_line(sub_process)));
+    }
+    Ok(())
+}
+
+/**
+ * Verifies that an element has no children that influence its behaviour, such as loop characteristics or terminating event definitions.
+ */
+fn check_children(node: Node) -> Result<()> {
+    for child in node.children().filter(|node| node.is_element()) {
+        let tag = child.tag_name().name();
+        if tag == "incoming" || tag == "outgoing" || IGNORED_ELEMENTS.contains(&tag) {
+            continue;
+        }
+        if (node.has_tag_name("startEvent") || node.has_tag_name("endEvent")) && IGNORED_EVENT_DEFINITIONS.contains(&tag) {
+            continue;
+        }
+        return Err(unsupported(child));
+    }
+    Ok(())
+}
+
+fn non_empty_subsets(places: &Vec<usize>) -> Vec<Vec<usize>> {
+    (1..1usize << places.len()).map(|mask| places.iter().enumerate().filter(|(index, _)| mask & (1 << index) != 0).map(|(_, place)| *place).collect()).collect()
+}
+
+fn get_id<'a>(node: Node<'a, '_>) -> Result<&'a str> {
+    node.attribute("id").ok_or_else(|| anyhow!("Element `{}` at line {} has no id.", node.tag_name().name(), get_line(node)))
+}
+
+fn get_line(node: Node) -> u32 {
+    node.document().text_pos_at(node.range().start).row
+}
+
+fn unsupported(node: Node) -> anyhow::Error {
+    match node.attribute("id") {
+        Some(id) => anyhow!("BPMN element `{}` with id `{}` at line {} is not supported.", node.tag_name().name(), id, get_line(node)),
+        None => anyhow!("BPMN element `{}` at line {} is not supported.", node.tag_name().name(), get_line(node)),
+    }
+}
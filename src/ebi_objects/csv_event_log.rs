@@ -0,0 +1,399 @@
+use std::{collections::HashMap, io::BufRead};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+use process_mining::event_log::event_log_struct::{Attribute, AttributeValue, Event, EventLogClassifier, Trace};
+
+use crate::{ebi_framework::{ebi_file_handler::EbiFileHandler, ebi_input::{self, EbiObjectImporter, EbiTraitImporter}, ebi_object::EbiObject, importable::Importable}, ebi_traits::{ebi_trait_event_log::{EbiTraitEventLog, ATTRIBUTE_TIME}, ebi_trait_finite_language::EbiTraitFiniteLanguage, ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_iterable_language::EbiTraitIterableLanguage, ebi_trait_iterable_stochastic_language::EbiTraitIterableStochasticLanguage, ebi_trait_queriable_stochastic_language::EbiTraitQueriableStochasticLanguage, ebi_trait_semantics::{EbiTraitSemantics, ToSemantics}, ebi_trait_stochastic_deterministic_semantics::{EbiTraitStochasticDeterministicSemantics, ToStochasticDeterministicSemantics}, ebi_trait_stochastic_semantics::{EbiTraitStochasticSemantics, ToStochasticSemantics}}};
+
+use super::{event_log::EventLog, finite_language::FiniteLanguage, finite_stochastic_language::FiniteStochasticLanguage};
+
+pub const FORMAT_SPECIFICATION: &str = "A CSV event log is a table of comma-, semicolon-, tab- or pipe-separated values, of which the first line is a header and each further line is an event.
+Values may be quoted with double quotes; a double quote inside a quoted value is written as two double quotes.
+The case, activity and timestamp columns are recognised by their names (such as \\texttt{case:concept:name}, \\texttt{case}, \\texttt{concept:name}, \\texttt{activity}, \\texttt{time:timestamp} and \\texttt{timestamp}); the timestamp column is optional.
+To choose these columns explicitly, use \\texttt{Ebi convert event-log}.
+Within each case, events are ordered by their timestamps, and otherwise kept in the order of the file.
+Every other column becomes a trace attribute if it has the same value for all events of each case, and an event attribute otherwise.
+The type of an attribute is inferred from all values in its column: integer, floating point number, time or text.
+For instance:
+    \\lstinputlisting[style=boxed]{../testfiles/a-b.csv}";
+
+pub const EBI_CSV_EVENT_LOG: EbiFileHandler = EbiFileHandler {
+    name: "CSV event log",
+    article: "a",
+    file_extension: "csv",
+    format_specification: &FORMAT_SPECIFICATION,
+    validator: ebi_input::validate::<CsvEventLog>,
+    trait_importers: &[
+        EbiTraitImporter::IterableLanguage(CsvEventLog::read_as_iterable_language),
+        EbiTraitImporter::FiniteLanguage(CsvEventLog::read_as_finite_language),
+        EbiTraitImporter::FiniteStochasticLanguage(CsvEventLog::read_as_finite_stochastic_language),
+        EbiTraitImporter::QueriableStochasticLanguage(CsvEventLog::read_as_queriable_stochastic_language),
+        EbiTraitImporter::IterableStochasticLanguage(CsvEventLog::read_as_iterable_stochastic_language),
+        EbiTraitImporter::EventLog(CsvEventLog::read_as_event_log),
+        EbiTraitImporter::StochasticDeterministicSemantics(CsvEventLog::import_as_stochastic_deterministic_semantics),
+        EbiTraitImporter::StochasticSemantics(CsvEventLog::import_as_stochastic_semantics),
+        EbiTraitImporter::Semantics(CsvEventLog::import_as_semantics),
+    ],
+    object_importers: &[
+        EbiObjectImporter::EventLog(CsvEventLog::import_as_object)
+    ],
+    object_exporters: &[],
+    java_object_handlers: &[],
+};
+
+/**
+ * Names (lowercase, without non-alphanumeric characters) by which columns are recognised, in order of preference.
+ */
+const CASE_COLUMNS: &[&str] = &["caseconceptname", "caseid", "case", "casename", "traceid", "trace"];
+const ACTIVITY_COLUMNS: &[&str] = &["conceptname", "activity", "activityname", "activityid", "eventname", "event", "task", "action"];
+const TIMESTAMP_COLUMNS: &[&str] = &["timetimestamp", "timestamp", "completetimestamp", "endtimestamp", "time", "endtime", "completetime", "datetime", "starttimestamp", "starttime", "date"];
+
+const SEPARATORS: &[char] = &[',', ';', '\t', '|'];
+
+/**
+ * The columns to use as case, activity and timestamp. Columns that are None are detected by their names.
+ */
+#[derive(Default,Clone,Debug)]
+pub struct CsvImportOptions {
+    pub case_column: Option<String>,
+    pub activity_column: Option<String>,
+    pub timestamp_column: Option<String>,
+}
+
+pub struct CsvEventLog {
+    log: EventLog
+}
+
+impl CsvEventLog {
+
+    pub fn read_as_finite_language(reader: &mut dyn BufRead) -> Result<Box<dyn EbiTraitFiniteLanguage>> {
+        let event_log = Self::import(reader)?;
+        Ok(Box::new(event_log.log.get_finite_language()))
+    }
+
+    pub fn read_as_finite_stochastic_language(reader: &mut dyn BufRead) -> Result<Box<dyn EbiTraitFiniteStochasticLanguage>> {
+        let event_log = Self::import(reader)?;
+        Ok(Box::new(Into::<FiniteStochasticLanguage>::into(event_log.log.get_finite_stochastic_language())))
+    }
+
+    pub fn read_as_queriable_stochastic_language(reader: &mut dyn BufRead) -> Result<Box<dyn EbiTraitQueriableStochasticLanguage>> {
+        let event_log = Self::import(reader)?;
+        Ok(Box::new(event_log.log.get_finite_stochastic_language()))
+    }
+
+    pub fn read_as_iterable_language(reader: &mut dyn BufRead) -> Result<Box<dyn EbiTraitIterableLanguage>> {
+        let event_log = Self::import(reader)?;
+        Ok(Box::new(Into::<FiniteLanguage>::into(event_log.log.get_finite_language())))
+    }
+
+    pub fn read_as_iterable_stochastic_language(reader: &mut dyn BufRead) -> Result<Box<dyn EbiTraitIterableStochasticLanguage>> {
+        let event_log = Self::import(reader)?;
+        Ok(Box::new(Into::<FiniteStochasticLanguage>::into(event_log.log.get_finite_stochastic_language())))
+    }
+
+    pub fn read_as_event_log(reader: &mut dyn BufRead) -> Result<Box<dyn EbiTraitEventLog>> {
+        let event_log = Self::import(reader)?;
+        Ok(Box::new(event_log.log))
+    }
+
+    pub fn import_with_options(reader: &mut dyn BufRead, options: &CsvImportOptions) -> Result<EventLog> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        let text = text.strip_prefix('\u{feff}').unwrap_or(&text);
+
+        let mut rows = parse_csv(text)?;
+        if rows.len() < 2 {
+            return Err(anyhow!("a CSV event log needs a header and at least one event"));
+        }
+        let header = rows.remove(0);
+        if header.len() < 2 {
+            return Err(anyhow!("a CSV event log needs at least a case and an activity column"));
+        }
+        for (row_index, row) in rows.iter().enumerate() {
+            if row.len() != header.len() {
+                return Err(anyhow!("row {} has {} values, whereas the header has {}", row_index + 1, row.len(), header.len()));
+            }
+        }
+
+        //find the columns
+        let case_column = find_column(&header, &options.case_column, CASE_COLUMNS, "case")?;
+        let activity_column = find_column(&header, &options.activity_column, ACTIVITY_COLUMNS, "activity")?;
+        let timestamp_column = match &options.timestamp_column {
+            Some(_) => Some(find_column(&header, &options.timestamp_column, TIMESTAMP_COLUMNS, "timestamp")?),
+            None => find_column(&header, &None, TIMESTAMP_COLUMNS, "timestamp").ok(),
+        };
+        if case_column == activity_column || timestamp_column.is_some_and(|column| column == case_column || column == activity_column) {
+            return Err(anyhow!("the case, activity and timestamp columns must be different"));
+        }
+
+        //infer the type of each column
+        let mut column_types = (0..header.len()).map(|column| ColumnType::infer(rows.iter().map(|row| row[column].as_str()))).collect::<Vec<_>>();
+        if let Some(column) = timestamp_column {
+            if let Some(value) = rows.iter().map(|row| &row[column]).find(|value| !value.is_empty() && parse_time(value).is_none()) {
+                return Err(anyhow!("timestamp column `{}` has value `{}`, which is not a time", header[column], value));
+            }
+            column_types[column] = ColumnType::Time;
+        }
+
+        //group the rows into cases, in order of first appearance
+        let mut case2index = HashMap::new();
+        let mut cases: Vec<Vec<usize>> = vec![];
+        for (row_index, row) in rows.iter().enumerate() {
+            let case_index = *case2index.entry(row[case_column].as_str()).or_insert_with(|| {
+                cases.push(vec![]);
+                cases.len() - 1
+            });
+            cases[case_index].push(row_index);
+        }
+
+        //order the events of each case by timestamp; events without a timestamp come first, and the sort is stable, so the order of the file breaks ties
+        if let Some(column) = timestamp_column {
+            for case in cases.iter_mut() {
+                case.sort_by_key(|row_index| parse_time(&rows[*row_index][column]));
+            }
+        }
+
+        //a column that is constant within each case is a trace attribute
+        let is_trace_column = (0..header.len()).map(|column| {
+            column != activity_column && Some(column) != timestamp_column && cases.iter().all(|case| case.iter().all(|row_index| rows[*row_index][column] == rows[case[0]][column]))
+        }).collect::<Vec<_>>();
+
+        let mut traces = vec![];
+        for case in cases {
+            let mut attributes = vec![];
+            for column in 0..header.len() {
+                if column == case_column {
+                    attributes.push(Attribute::new("concept:name".to_string(), AttributeValue::String(rows[case[0]][column].clone())));
+                } else if is_trace_column[column] {
+                    if let Some(value) = column_types[column].to_attribute_value(&rows[case[0]][column]) {
+                        attributes.push(Attribute::new(header[column].clone(), value));
+                    }
+                }
+            }
+
+            let mut events = vec![];
+            for row_index in case {
+                let row = &rows[row_index];
+                let mut event_attributes = vec![];
+                for column in 0..header.len() {
+                    if column == activity_column {
+                        event_attributes.push(Attribute::new("concept:name".to_string(), AttributeValue::String(row[column].clone())));
+                    } else if Some(column) == timestamp_column {
+                        if let Some(value) = column_types[column].to_attribute_value(&row[column]) {
+                            event_attributes.push(Attribute::new(ATTRIBUTE_TIME.to_string(), value));
+                        }
+                    } else if column != case_column && !is_trace_column[column] {
+                        if let Some(value) = column_types[column].to_attribute_value(&row[column]) {
+                            event_attributes.push(Attribute::new(header[column].clone(), value));
+                        }
+                    }
+                }
+                events.push(Event {
+                    attributes: event_attributes
+                });
+            }
+
+            traces.push(Trace {
+                attributes: attributes,
+                events: events,
+            });
+        }
+
+        let log = process_mining::EventLog {
+            attributes: vec![],
+            traces: traces,
+            extensions: None,
+            classifiers: None,
+            global_trace_attrs: None,
+            global_event_attrs: None,
+        };
+        let classifier = EventLogClassifier{name: "concept:name".to_string(), keys: vec!["concept:name".to_string()]};
+        Ok(EventLog::new(log, classifier))
+    }
+}
+
+impl Importable for CsvEventLog {
+    fn import_as_object(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::EventLog(Self::import(reader)?.log))
+    }
+
+    fn import(reader: &mut dyn BufRead) -> Result<Self> where Self: Sized {
+        Ok(Self {
+            log: Self::import_with_options(reader, &CsvImportOptions::default())?
+        })
+    }
+}
+
+impl ToSemantics for CsvEventLog {
+    fn to_semantics(self) -> EbiTraitSemantics {
+        self.log.to_semantics()
+    }
+}
+
+impl ToStochasticSemantics for CsvEventLog {
+    fn to_stochastic_semantics(self) -> EbiTraitStochasticSemantics {
+        self.log.to_stochastic_semantics()
+    }
+}
+
+impl ToStochasticDeterministicSemantics for CsvEventLog {
+    fn to_stochastic_deterministic_semantics(self) -> EbiTraitStochasticDeterministicSemantics {
+        self.log.to_stochastic_deterministic_semantics()
+    }
+}
+
+/**
+ * The most specific type that all non-empty values of a column have.
+ */
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+enum ColumnType {
+    Integer,
+    Float,
+    Time,
+    Text,
+}
+
+impl ColumnType {
+    fn infer<'a>(values: impl Iterator<Item = &'a str>) -> Self {
+        let values = values.filter(|value| !value.is_empty()).collect::<Vec<_>>();
+        [ColumnType::Integer, ColumnType::Float, ColumnType::Time].into_iter().find(|column_type| values.iter().all(|value| column_type.accepts(value))).unwrap_or(ColumnType::Text)
+    }
+
+    fn accepts(&self, value: &str) -> bool {
+        match self {
+            ColumnType::Integer => value.parse::<i64>().is_ok(),
+            ColumnType::Float => value.parse::<f64>().is_ok_and(|x| x.is_finite()),
+            ColumnType::Time => parse_time(value).is_some(),
+            ColumnType::Text => true,
+        }
+    }
+
+    /**
+     * Returns None for empty values, which are left out of the log.
+     */
+    fn to_attribute_value(&self, value: &str) -> Option<AttributeValue> {
+        if value.is_empty() {
+            return None;
+        }
+        Some(match self {
+            ColumnType::Integer => AttributeValue::Int(value.parse().ok()?),
+            ColumnType::Float => AttributeValue::Float(value.parse().ok()?),
+            ColumnType::Time => AttributeValue::Date(parse_time(value)?),
+            ColumnType::Text => AttributeValue::String(value.to_string()),
+        })
+    }
+}
+
+/**
+ * Parses a time with a time zone (RFC 3339 or RFC 2822), or a time or date without time zone, which is taken as UTC.
+ */
+fn parse_time(value: &str) -> Option<DateTime<FixedOffset>> {
+    if let Ok(time) = value.parse::<DateTime<FixedOffset>>() {
+        return Some(time);
+    }
+    if let Ok(time) = DateTime::parse_from_rfc2822(value) {
+        return Some(time);
+    }
+    for format in ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M", "%Y/%m/%d %H:%M:%S%.f", "%Y/%m/%d %H:%M", "%d-%m-%Y %H:%M:%S%.f", "%d-%m-%Y %H:%M", "%d/%m/%Y %H:%M:%S%.f", "%d/%m/%Y %H:%M"] {
+        if let Ok(time) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(time.and_utc().fixed_offset());
+        }
+    }
+    for format in ["%Y-%m-%d", "%Y/%m/%d", "%d-%m-%Y", "%d/%m/%Y"] {
+        if let Ok(date) = NaiveDate::parse_from_str(value, format) {
+            return Some(DateTime::<Utc>::from_naive_utc_and_offset(date.and_hms_opt(0, 0, 0)?, Utc).fixed_offset());
+        }
+    }
+    None
+}
+
+/**
+ * Returns the index of the column with the given name, or if no name is given, of the first column of which the name is recognised.
+ */
+fn find_column(header: &Vec<String>, name: &Option<String>, recognised_names: &[&str], column_kind: &str) -> Result<usize> {
+    match name {
+        Some(name) => match header.iter().position(|column| column == name) {
+            Some(column) => Ok(column),
+            None => Err(anyhow!("there is no column `{}`; the columns are {}", name, header.join(", "))),
+        },
+        None => {
+            let normalised = header.iter().map(|column| column.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase()).collect::<Vec<_>>();
+            for recognised_name in recognised_names {
+                if let Some(column) = normalised.iter().position(|column| column == recognised_name) {
+                    return Ok(column);
+                }
+            }
+            Err(anyhow!("could not recognise the {} column among the columns {}", column_kind, header.join(", ")))
+        }
+    }
+}
+
+/**
+ * Splits the text into rows of values. The separator is the first of the SEPARATORS that occurs in the header outside of quotes.
+ * Empty lines are skipped.
+ */
+pub(crate) fn parse_csv(text: &str) -> Result<Vec<Vec<String>>> {
+    let header_line = text.lines().next().unwrap_or("");
+    let mut in_quotes = false;
+    let mut separator = None;
+    for c in header_line.chars() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        } else if !in_quotes && SEPARATORS.contains(&c) {
+            separator = Some(c);
+            break;
+        }
+    }
+    let separator = separator.ok_or_else(|| anyhow!("the header of the CSV file has no separator"))?;
+
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut value = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+    let mut line = 1;
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    value.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                if c == '\n' {
+                    line += 1;
+                }
+                value.push(c);
+            }
+        } else if c == '"' && value.is_empty() {
+            in_quotes = true;
+        } else if c == '"' {
+            return Err(anyhow!("line {}: unexpected double quote in an unquoted value", line));
+        } else if c == separator {
+            row.push(std::mem::take(&mut value));
+        } else if c == '\n' || c == '\r' {
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                chars.next();
+            }
+            row.push(std::mem::take(&mut value));
+            if !(row.len() == 1 && row[0].is_empty()) {
+                rows.push(std::mem::take(&mut row));
+            }
+            row.clear();
+            line += 1;
+        } else {
+            value.push(c);
+        }
+    }
+    if in_quotes {
+        return Err(anyhow!("line {}: a quoted value is not closed", line));
+    }
+    row.push(value);
+    if !(row.len() == 1 && row[0].is_empty()) {
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
@@ -0,0 +1,100 @@
+use std::{collections::BTreeSet, io::BufRead};
+use anyhow::{anyhow, Result};
+
+use crate::ebi_framework::{ebi_file_handler::EbiFileHandler, ebi_object::EbiObject, ebi_output::{EbiObjectExporter, EbiOutput}};
+
+use super::{csv_event_log::parse_csv, executions::Executions};
+
+pub const FORMAT_SPECIFICATION: &str = "A CSV executions file is a table of comma-separated values, which Ebi can write and validate but not read; to read executions, use the executions format (.exs).
+The first line is a header and each further line is an execution.
+The columns are the index of the trace, the executed transition, the transitions that were enabled at enablement (separated by spaces), the time of enablement, the time of execution, and a column for each feature that occurs in any of the executions, as in the executions format.
+Missing values are left empty.
+Values that contain a comma, a double quote or a line break are quoted with double quotes; a double quote inside a quoted value is written as two double quotes.";
+
+pub const EBI_CSV_EXECUTIONS: EbiFileHandler = EbiFileHandler {
+    name: "CSV executions",
+    article: "a",
+    file_extension: "executions.csv",
+    format_specification: &FORMAT_SPECIFICATION,
+    validator: CsvExecutions::validate,
+    trait_importers: &[],
+    object_importers: &[],
+    object_exporters: &[
+        EbiObjectExporter::Executions(CsvExecutions::export_from_object)
+    ],
+    java_object_handlers: &[],
+};
+
+const HEADER: &[&str] = &["trace", "transition", "enabled transitions at enablement", "time of enablement", "time of execution"];
+
+pub struct CsvExecutions {}
+
+impl CsvExecutions {
+    /**
+     * Checks the header, the number of values on each line, and the trace and transition columns.
+     */
+    pub fn validate(reader: &mut dyn BufRead) -> Result<()> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        let rows = parse_csv(&text)?;
+
+        let header = rows.first().ok_or_else(|| anyhow!("the file has no header"))?;
+        if header.len() < HEADER.len() || header.iter().zip(HEADER.iter()).any(|(column, expected)| column != expected) {
+            return Err(anyhow!("the header should start with the columns {}", HEADER.join(", ")));
+        }
+
+        for (line, row) in rows.iter().enumerate().skip(1) {
+            if row.len() != header.len() {
+                return Err(anyhow!("line {}: expected {} values, but found {}", line + 1, header.len(), row.len()));
+            }
+            row[0].parse::<usize>().map_err(|_| anyhow!("line {}: could not read trace `{}`", line + 1, row[0]))?;
+            row[1].parse::<usize>().map_err(|_| anyhow!("line {}: could not read transition `{}`", line + 1, row[1]))?;
+        }
+
+        Ok(())
+    }
+
+    pub fn export_from_object(object: EbiOutput, f: &mut dyn std::io::Write) -> Result<()> {
+        match object {
+            EbiOutput::Object(EbiObject::Executions(executions)) => Self::export(&executions, f),
+            _ => unreachable!()
+        }
+    }
+
+    pub fn export(executions: &Executions, f: &mut dyn std::io::Write) -> Result<()> {
+        let features = executions.executions.iter()
+            .filter_map(|execution| execution.features_at_enablement.as_ref())
+            .flat_map(|features| features.keys())
+            .collect::<BTreeSet<_>>();
+
+        //header
+        let mut header = HEADER.to_vec();
+        header.extend(features.iter().map(|feature| feature.as_str()));
+        writeln!(f, "{}", header.into_iter().map(Self::quote).collect::<Vec<_>>().join(","))?;
+
+        //executions
+        for execution in &executions.executions {
+            let mut line = vec![
+                execution.trace.to_string(),
+                execution.transition.to_string(),
+                execution.enabled_transitions_at_enablement.as_ref().map_or(String::new(), |transitions| transitions.iter().map(|transition| transition.to_string()).collect::<Vec<_>>().join(" ")),
+                execution.time_of_enablement.map_or(String::new(), |time| time.to_rfc3339()),
+                execution.time_of_execution.map_or(String::new(), |time| time.to_rfc3339()),
+            ];
+            for feature in &features {
+                line.push(execution.features_at_enablement.as_ref().and_then(|features| features.get(*feature)).map_or(String::new(), |value| value.to_string()));
+            }
+            writeln!(f, "{}", line.iter().map(|value| Self::quote(value)).collect::<Vec<_>>().join(","))?;
+        }
+
+        Ok(())
+    }
+
+    fn quote(value: &str) -> String {
+        if value.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+}
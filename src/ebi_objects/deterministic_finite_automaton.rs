@@ -3,7 +3,7 @@ use anyhow::{anyhow, Result, Error,Context};
 use layout::topo::layout::VisualGraph;
 use serde_json::Value;
 
-use crate::{ebi_framework::{activity_key::{Activity, ActivityKey}, ebi_file_handler::EbiFileHandler, ebi_input::{self, EbiObjectImporter, EbiTraitImporter}, ebi_object::EbiObject, ebi_output::{EbiObjectExporter, EbiOutput}, exportable::Exportable, importable::Importable, infoable::Infoable}, ebi_traits::{ebi_trait_graphable::{self, EbiTraitGraphable}, ebi_trait_semantics::{EbiTraitSemantics, Semantics, ToSemantics}}, json};
+use crate::{ebi_framework::{activity_key::{Activity, ActivityKey}, ebi_file_handler::EbiFileHandler, ebi_input::{self, EbiInput, EbiObjectImporter, EbiTraitImporter}, ebi_object::EbiObject, ebi_output::{EbiObjectExporter, EbiOutput}, ebi_trait::FromEbiTraitObject, exportable::Exportable, importable::Importable, infoable::Infoable}, ebi_traits::{ebi_trait_graphable::{self, EbiTraitGraphable}, ebi_trait_semantics::{EbiTraitSemantics, Semantics, ToSemantics}}, json};
 
 use super::stochastic_deterministic_finite_automaton::StochasticDeterministicFiniteAutomaton;
 
@@ -203,6 +203,15 @@ impl DeterministicFiniteAutomaton {
     }
 }
 
+impl FromEbiTraitObject for DeterministicFiniteAutomaton {
+    fn from_trait_object(object: EbiInput) -> Result<Box<Self>> {
+        match object {
+            EbiInput::Object(EbiObject::DeterministicFiniteAutomaton(e), _) => Ok(Box::new(e)),
+            _ => Err(anyhow!("cannot read {} {} as a deterministic finite automaton", object.get_type().get_article(), object.get_type()))
+        }
+    }
+}
+
 impl FromStr for DeterministicFiniteAutomaton {
     type Err = Error;
 
@@ -52,6 +52,17 @@ pub struct DirectlyFollowsModel {
 
 impl DirectlyFollowsModel {
 
+    pub fn new(activity_key: ActivityKey, empty_traces: bool, edges: Vec<Vec<bool>>, node_2_activity: Vec<Activity>, start_nodes: HashSet<usize>, end_nodes: HashSet<usize>) -> Self {
+        Self {
+            activity_key,
+            empty_traces,
+            edges,
+            node_2_activity,
+            start_nodes,
+            end_nodes
+        }
+    }
+
     pub fn import_as_labelled_petri_net(reader: &mut dyn BufRead) -> Result<EbiObject> {
         let dfm = Self::import(reader)?;
         Ok(EbiObject::LabelledPetriNet(dfm.get_labelled_petri_net()))
@@ -1,13 +1,32 @@
-use std::{fmt::{self, Display}, io::BufRead, str::FromStr};
-use anyhow::{Result, Error};
+use std::{collections::BTreeMap, fmt::{self, Display}, io::BufRead, str::FromStr};
+use anyhow::{anyhow, Result, Error};
 use chrono::{DateTime, FixedOffset};
+use process_mining::event_log::AttributeValue;
 use serde::{Deserialize, Serialize};
 
-use crate::ebi_framework::{ebi_file_handler::EbiFileHandler, ebi_input::{self, EbiObjectImporter}, ebi_object::EbiObject, ebi_output::{EbiObjectExporter, EbiOutput}, exportable::Exportable, importable::Importable, infoable::Infoable};
+use crate::ebi_framework::{ebi_file_handler::EbiFileHandler, ebi_input::{self, EbiInput, EbiObjectImporter}, ebi_object::EbiObject, ebi_output::{EbiObjectExporter, EbiOutput}, ebi_trait::FromEbiTraitObject, exportable::Exportable, importable::Importable, infoable::Infoable};
 
 pub const HEADER: &str = "executions";
 
-pub const FORMAT_SPECIFICATION: &str = "not yet finalised.";
+pub const FORMAT_SPECIFICATION: &str = "An executions file is a JSON object with two fields.
+The field \\texttt{version} is the version of the format, which is currently 1.
+The field \\texttt{executions} is a list of executions, each of which is an object with the following fields:
+\\begin{itemize}
+    \\item \\texttt{trace}: the index of the trace in the event log;
+    \\item \\texttt{transition}: the index of the executed transition in the model;
+    \\item \\texttt{enabled\\_transitions\\_at\\_enablement}: the list of transitions that were enabled when the executed transition became enabled, which includes the executed transition, or \\texttt{null};
+    \\item \\texttt{time\\_of\\_enablement}: the time (RFC 3339) of the last event before the executed transition became enabled, or \\texttt{null};
+    \\item \\texttt{time\\_of\\_execution}: the time (RFC 3339) of the event of the executed transition, or \\texttt{null}, for instance for silent transitions;
+    \\item \\texttt{features\\_at\\_enablement}: an object that maps each trace attribute, prefixed with \\texttt{trace:}, and each event attribute of the last event before enablement that has it, prefixed with \\texttt{event:}, to its boolean, integer, floating point or text value; times are written as text. This field may be \\texttt{null}.
+\\end{itemize}
+The time of enablement cannot be after the time of execution.
+For instance:
+    \\lstinputlisting[language=json, style=boxed]{../testfiles/a-b.exs}";
+
+/**
+ * The version of the format that is written, and the only version that is read.
+ */
+pub const EXECUTIONS_VERSION: u64 = 1;
 
 pub const EBI_EXECUTIONS: EbiFileHandler = EbiFileHandler {
     name: "executions",
@@ -27,9 +46,12 @@ pub const EBI_EXECUTIONS: EbiFileHandler = EbiFileHandler {
     java_object_handlers: &[],
 };
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
 pub struct Executions {
-    executions: Vec<Execution>
+    #[serde(default)]
+    version: u64,
+    pub(crate) executions: Vec<Execution>
 }
 
 impl Importable for Executions {
@@ -38,7 +60,34 @@ impl Importable for Executions {
     }
 
     fn import(reader: &mut dyn BufRead) -> Result<Self> where Self: Sized {
-        Ok(serde_json::from_reader(reader)?)
+        let result: Self = serde_json::from_reader(reader)?;
+
+        if result.version != EXECUTIONS_VERSION {
+            return Err(anyhow!("executions of version {} are not supported; expected version {}", result.version, EXECUTIONS_VERSION));
+        }
+        for (index, execution) in result.executions.iter().enumerate() {
+            if let Some(enabled_transitions) = &execution.enabled_transitions_at_enablement {
+                if !enabled_transitions.contains(&execution.transition) {
+                    return Err(anyhow!("execution {} is of transition {}, which is not among its enabled transitions", index, execution.transition));
+                }
+            }
+            if let (Some(enablement), Some(execution_time)) = (&execution.time_of_enablement, &execution.time_of_execution) {
+                if enablement > execution_time {
+                    return Err(anyhow!("execution {} is enabled after it is executed", index));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl FromEbiTraitObject for Executions {
+    fn from_trait_object(object: EbiInput) -> Result<Box<Self>> {
+        match object {
+            EbiInput::Object(EbiObject::Executions(e), _) => Ok(Box::new(e)),
+            _ => Err(anyhow!("cannot read {} {} as executions", object.get_type().get_article(), object.get_type()))
+        }
     }
 }
 
@@ -81,18 +130,21 @@ impl Exportable for Executions {
 impl From<Vec<Execution>> for Executions {
     fn from(value: Vec<Execution>) -> Self {
         Self {
+            version: EXECUTIONS_VERSION,
             executions: value
         }
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
 pub struct Execution {
+    pub trace: usize,
     pub transition: usize,
     pub enabled_transitions_at_enablement: Option<Vec<usize>>,
     pub time_of_enablement: Option<DateTime<FixedOffset>>,
     pub time_of_execution: Option<DateTime<FixedOffset>>,
-    pub features_at_enablement: Option<Vec<usize>>
+    pub features_at_enablement: Option<BTreeMap<String, Feature>>
 }
 
 impl Display for Execution {
@@ -100,4 +152,43 @@ impl Display for Execution {
         let x = serde_json::to_string(self).unwrap();
         write!(f, "{}", x)
     }
-}
\ No newline at end of file
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Feature {
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+}
+
+impl Feature {
+    /**
+     * Times become text; lists, containers and identifiers are not features.
+     */
+    pub fn from_attribute_value(value: &AttributeValue) -> Option<Self> {
+        match value {
+            AttributeValue::String(x) => Some(Self::String(x.to_owned())),
+            AttributeValue::Date(x) => Some(Self::String(x.to_rfc3339())),
+            AttributeValue::Int(x) => Some(Self::Integer(*x)),
+            AttributeValue::Float(x) => Some(Self::Float(*x)),
+            AttributeValue::Boolean(x) => Some(Self::Boolean(*x)),
+            AttributeValue::ID(_) => None,
+            AttributeValue::List(_) => None,
+            AttributeValue::Container(_) => None,
+            AttributeValue::None() => None,
+        }
+    }
+}
+
+impl Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Feature::Boolean(x) => write!(f, "{}", x),
+            Feature::Integer(x) => write!(f, "{}", x),
+            Feature::Float(x) => write!(f, "{}", x),
+            Feature::String(x) => write!(f, "{}", x),
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use std::{collections::{HashMap, HashSet}, fmt, io::{self, BufRead, Write}, str::FromStr};
+use anyhow::{anyhow, Result, Error};
+use process_mining::{event_log::event_log_struct::{Attribute, AttributeValue, Event, EventLogClassifier, Trace}, ocel::ocel_struct::OCELObject, OCEL};
+
+use crate::{ebi_framework::{ebi_file_handler::EbiFileHandler, ebi_input::{self, EbiInput, EbiObjectImporter}, ebi_object::EbiObject, ebi_output::{EbiObjectExporter, EbiOutput}, ebi_trait::FromEbiTraitObject, exportable::Exportable, importable::Importable, infoable::Infoable}, ebi_traits::ebi_trait_event_log::{EbiTraitEventLog, ATTRIBUTE_TIME}};
+
+use super::event_log::EventLog;
+
+pub const FORMAT_SPECIFICATION: &str = "An object-centric event log file follows the JSON variant of the OCEL 2.0 format~\\cite{DBLP:journals/corr/abs-2403-01975}.
+Parsing is performed by the Rust4PM crate~\\cite{DBLP:conf/bpm/KustersA24}.
+An object-centric event log cannot be used as an event log or as a language directly, as that requires a choice of object type.
+To flatten the log on an object type, such that each object of that type becomes a trace that consists of the events that refer to the object, use \\texttt{Ebi convert flatten}.
+For instance:
+    \\lstinputlisting[language=json, style=boxed]{../testfiles/a-b.jsonocel}";
+
+pub const EBI_OBJECT_CENTRIC_EVENT_LOG: EbiFileHandler = EbiFileHandler {
+    name: "object-centric event log",
+    article: "an",
+    file_extension: "jsonocel",
+    format_specification: &FORMAT_SPECIFICATION,
+    validator: ebi_input::validate::<ObjectCentricEventLog>,
+    trait_importers: &[],
+    object_importers: &[
+        EbiObjectImporter::ObjectCentricEventLog(ObjectCentricEventLog::import_as_object),
+    ],
+    object_exporters: &[
+        EbiObjectExporter::ObjectCentricEventLog(ObjectCentricEventLog::export_from_object),
+    ],
+    java_object_handlers: &[],
+};
+
+pub struct ObjectCentricEventLog {
+    ocel: OCEL,
+}
+
+impl ObjectCentricEventLog {
+    pub fn new(ocel: OCEL) -> Result<Self> {
+        if ocel.events.is_empty() {
+            return Err(anyhow!("object-centric event log has no events"));
+        }
+        let object_ids = ocel.objects.iter().map(|object| object.id.as_str()).collect::<HashSet<_>>();
+        if !ocel.events.iter().any(|event| event.relationships.iter().any(|relationship| object_ids.contains(relationship.object_id.as_str()))) {
+            return Err(anyhow!("no event of the object-centric event log refers to an object"));
+        }
+        Ok(Self {
+            ocel: ocel
+        })
+    }
+
+    /**
+     * Returns the object types in the order in which they are declared, followed by undeclared types that objects have.
+     */
+    pub fn get_object_types(&self) -> Vec<String> {
+        let mut result = self.ocel.object_types.iter().map(|object_type| object_type.name.clone()).collect::<Vec<_>>();
+        for object in &self.ocel.objects {
+            if !result.contains(&object.object_type) {
+                result.push(object.object_type.clone());
+            }
+        }
+        result
+    }
+
+    /**
+     * Flattens the log on the objects of the given type: every such object becomes a trace of the events that refer to it.
+     */
+    pub fn flatten_on(&self, object_type: &str) -> Result<EventLog> {
+        if !self.get_object_types().iter().any(|t| t == object_type) {
+            return Err(anyhow!("the object-centric event log has no object type `{}`; the available object types are {}", object_type, self.get_object_types().join(", ")));
+        }
+        let log = self.flatten_objects(|object| object.object_type == object_type);
+        if log.get_log().traces.is_empty() {
+            return Err(anyhow!("no event of the object-centric event log refers to an object of type `{}`", object_type));
+        }
+        Ok(log)
+    }
+
+    fn flatten_objects(&self, filter: impl Fn(&OCELObject) -> bool) -> EventLog {
+        log::info!("flatten object-centric event log");
+
+        let mut object2trace = HashMap::new();
+        let mut traces = vec![];
+        for object in self.ocel.objects.iter().filter(|object| filter(object)) {
+            object2trace.insert(object.id.as_str(), traces.len());
+            traces.push(Trace {
+                attributes: vec![
+                    Attribute::new("concept:name".to_string(), AttributeValue::String(object.id.clone())),
+                    Attribute::new("ocel:type".to_string(), AttributeValue::String(object.object_type.clone())),
+                ],
+                events: vec![],
+            });
+        }
+
+        //the sort is stable, so events with equal times keep their order in the file
+        let mut events = self.ocel.events.iter().collect::<Vec<_>>();
+        events.sort_by_key(|event| event.time);
+
+        for event in events {
+            //an event may refer to the same object multiple times, with different qualifiers
+            let mut trace_indices = event.relationships.iter().filter_map(|relationship| object2trace.get(relationship.object_id.as_str())).collect::<Vec<_>>();
+            trace_indices.sort();
+            trace_indices.dedup();
+
+            for trace_index in trace_indices {
+                traces[*trace_index].events.push(Event {
+                    attributes: vec![
+                        Attribute::new("concept:name".to_string(), AttributeValue::String(event.event_type.clone())),
+                        Attribute::new(ATTRIBUTE_TIME.to_string(), AttributeValue::Date(event.time)),
+                    ],
+                });
+            }
+        }
+        traces.retain(|trace| !trace.events.is_empty());
+
+        let log = process_mining::EventLog {
+            attributes: vec![],
+            traces: traces,
+            extensions: None,
+            classifiers: None,
+            global_trace_attrs: None,
+            global_event_attrs: None,
+        };
+        let classifier = EventLogClassifier{name: "concept:name".to_string(), keys: vec!["concept:name".to_string()]};
+        EventLog::new(log, classifier)
+    }
+}
+
+impl FromEbiTraitObject for ObjectCentricEventLog {
+    fn from_trait_object(object: EbiInput) -> Result<Box<Self>> {
+        match object {
+            EbiInput::Object(EbiObject::ObjectCentricEventLog(e), _) => Ok(Box::new(e)),
+            _ => Err(anyhow!("cannot read {} {} as an object-centric event log", object.get_type().get_article(), object.get_type()))
+        }
+    }
+}
+
+impl Importable for ObjectCentricEventLog {
+    fn import_as_object(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::ObjectCentricEventLog(Self::import(reader)?))
+    }
+
+    fn import(reader: &mut dyn BufRead) -> Result<Self> where Self: Sized {
+        let ocel: OCEL = serde_json::from_reader(reader)?;
+        Self::new(ocel)
+    }
+}
+
+impl FromStr for ObjectCentricEventLog {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut reader = io::Cursor::new(s);
+        Self::import(&mut reader)
+    }
+}
+
+impl Exportable for ObjectCentricEventLog {
+    fn export_from_object(object: EbiOutput, f: &mut dyn Write) -> Result<()> {
+        match object {
+            EbiOutput::Object(EbiObject::ObjectCentricEventLog(log)) => log.export(f),
+            _ => unreachable!()
+        }
+    }
+
+    fn export(&self, f: &mut dyn Write) -> Result<()> {
+        Ok(serde_json::to_writer_pretty(f, &self.ocel)?)
+    }
+}
+
+impl fmt::Display for ObjectCentricEventLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "object-centric event log with {} events and {} objects", self.ocel.events.len(), self.ocel.objects.len())
+    }
+}
+
+impl Infoable for ObjectCentricEventLog {
+    fn info(&self, f: &mut impl std::io::Write) -> Result<()> {
+        writeln!(f, "Number of events\t{}", self.ocel.events.len())?;
+        writeln!(f, "Number of objects\t{}", self.ocel.objects.len())?;
+
+        //count the objects per type, and the events that refer to an object of each type
+        let object2type = self.ocel.objects.iter().map(|object| (object.id.as_str(), object.object_type.as_str())).collect::<HashMap<_, _>>();
+        let mut type2objects: HashMap<&str, usize> = HashMap::new();
+        for object in &self.ocel.objects {
+            *type2objects.entry(&object.object_type).or_default() += 1;
+        }
+        let mut type2events: HashMap<&str, usize> = HashMap::new();
+        for event in &self.ocel.events {
+            let mut types = event.relationships.iter().filter_map(|relationship| object2type.get(relationship.object_id.as_str())).collect::<Vec<_>>();
+            types.sort();
+            types.dedup();
+            for object_type in types {
+                *type2events.entry(object_type).or_default() += 1;
+            }
+        }
+
+        writeln!(f, "Object types:")?;
+        for object_type in self.get_object_types() {
+            writeln!(f, "\t{}\t{} objects\t{} events", object_type, type2objects.get(object_type.as_str()).unwrap_or(&0), type2events.get(object_type.as_str()).unwrap_or(&0))?;
+        }
+
+        let mut event_types = self.ocel.event_types.iter().map(|event_type| event_type.name.as_str()).collect::<Vec<_>>();
+        let mut type2count: HashMap<&str, usize> = HashMap::new();
+        for event in &self.ocel.events {
+            if !event_types.contains(&event.event_type.as_str()) {
+                event_types.push(&event.event_type);
+            }
+            *type2count.entry(&event.event_type).or_default() += 1;
+        }
+
+        writeln!(f, "Event types:")?;
+        for event_type in event_types {
+            writeln!(f, "\t{}\t{} events", event_type, type2count.get(event_type).unwrap_or(&0))?;
+        }
+
+        Ok(write!(f, "")?)
+    }
+}
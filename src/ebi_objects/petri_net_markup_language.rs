@@ -1,16 +1,20 @@
 use std::{collections::HashMap, io::{BufRead, Write}};
 
-use anyhow::{anyhow, Error, Result};
+use anyhow::{anyhow, Context, Error, Result};
 use bitvec::bitvec;
 use fraction::ToPrimitive;
 use process_mining::{petri_net::petri_net_struct::{self, ArcType}, PetriNet};
+use roxmltree::Node;
 
-use crate::{ebi_framework::{activity_key::HasActivityKey, ebi_file_handler::EbiFileHandler, ebi_input::{EbiObjectImporter, EbiTraitImporter}, ebi_object::EbiObject, ebi_output::{EbiObjectExporter, EbiOutput}, exportable::Exportable, importable::Importable}, ebi_objects::labelled_petri_net::LPNMarking, ebi_traits::ebi_trait_semantics::{EbiTraitSemantics, Semantics}, marking::Marking};
+use crate::{ebi_framework::{activity_key::HasActivityKey, ebi_file_handler::EbiFileHandler, ebi_input::{EbiObjectImporter, EbiTraitImporter}, ebi_object::EbiObject, ebi_output::{EbiObjectExporter, EbiOutput}, exportable::Exportable, importable::Importable}, ebi_objects::labelled_petri_net::LPNMarking, ebi_traits::{ebi_trait_queriable_stochastic_language::EbiTraitQueriableStochasticLanguage, ebi_trait_semantics::{EbiTraitSemantics, Semantics}, ebi_trait_stochastic_deterministic_semantics::{EbiTraitStochasticDeterministicSemantics, ToStochasticDeterministicSemantics}, ebi_trait_stochastic_semantics::{EbiTraitStochasticSemantics, ToStochasticSemantics}}, marking::Marking, math::fraction::Fraction, xml};
 
 use super::{labelled_petri_net::LabelledPetriNet, stochastic_labelled_petri_net::StochasticLabelledPetriNet};
 
 pub const FORMAT_SPECIFICATION: &str = "A Petri net markup language file follows the ISO 15909-2:2011 format~\\cite{pnml}. 
 Parsing is performed by the Rust4PM crate~\\cite{DBLP:conf/bpm/KustersA24}.
+When a stochastic labelled Petri net is read from or written to PNML, the weight of each transition is stored in a \\texttt{toolspecific} element of the tool \\texttt{StochasticPetriNet}, as a \\texttt{property} with key \\texttt{weight}.
+As that weight is a decimal number, Ebi additionally writes the exact weight in a \\texttt{toolspecific} element of the tool \\texttt{Ebi}, which takes precedence over the decimal weight when reading.
+A PNML file can only be read as a stochastic labelled Petri net if at least one of its transitions has a weight; transitions without a weight get weight 1.
 For instance:
     \\lstinputlisting[language=xml, style=boxed]{../testfiles/a.pnml}";
 
@@ -22,10 +26,14 @@ pub const EBI_PETRI_NET_MARKUP_LANGUAGE: EbiFileHandler = EbiFileHandler {
     format_specification: &FORMAT_SPECIFICATION,
     validator: PetriNetMarkupLanguage::validate,
     trait_importers: &[
+        EbiTraitImporter::QueriableStochasticLanguage(PetriNetMarkupLanguage::import_as_queriable_stochastic_language),
+        EbiTraitImporter::StochasticDeterministicSemantics(PetriNetMarkupLanguage::import_as_stochastic_deterministic_semantics),
+        EbiTraitImporter::StochasticSemantics(PetriNetMarkupLanguage::import_as_stochastic_semantics),
         EbiTraitImporter::Semantics(PetriNetMarkupLanguage::import_as_semantics),
     ],
     object_importers: &[
         EbiObjectImporter::LabelledPetriNet(PetriNetMarkupLanguage::import_as_object),
+        EbiObjectImporter::StochasticLabelledPetriNet(PetriNetMarkupLanguage::import_as_stochastic_labelled_petri_net_object),
     ],
     object_exporters: &[
         EbiObjectExporter::LabelledPetriNet(PetriNetMarkupLanguage::export_from_object),
@@ -36,6 +44,16 @@ pub const EBI_PETRI_NET_MARKUP_LANGUAGE: EbiFileHandler = EbiFileHandler {
     java_object_handlers: &[], //java translations covered by LabelledPetrinet
 };
 
+/**
+ * The tool of the toolspecific elements that hold the (decimal) weights of transitions, as used by ProM.
+ */
+pub const TOOL_STOCHASTIC_PETRI_NET: &str = "StochasticPetriNet";
+
+/**
+ * The tool of the toolspecific elements that hold the exact weights of transitions.
+ */
+pub const TOOL_EBI: &str = "Ebi";
+
 pub struct PetriNetMarkupLanguage {
     net: process_mining::PetriNet
 }
@@ -63,12 +81,194 @@ impl PetriNetMarkupLanguage {
         Ok(EbiTraitSemantics::Marking(Box::new(lpn)))
     }
 
+    pub fn import_as_stochastic_labelled_petri_net_object(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::StochasticLabelledPetriNet(Self::import_as_stochastic_labelled_petri_net(reader)?))
+    }
+
+    pub fn import_as_stochastic_semantics(reader: &mut dyn BufRead) -> Result<EbiTraitStochasticSemantics> {
+        Ok(Self::import_as_stochastic_labelled_petri_net(reader)?.to_stochastic_semantics())
+    }
+
+    pub fn import_as_stochastic_deterministic_semantics(reader: &mut dyn BufRead) -> Result<EbiTraitStochasticDeterministicSemantics> {
+        Ok(Self::import_as_stochastic_labelled_petri_net(reader)?.to_stochastic_deterministic_semantics())
+    }
+
+    pub fn import_as_queriable_stochastic_language(reader: &mut dyn BufRead) -> Result<Box<dyn EbiTraitQueriableStochasticLanguage>> {
+        Ok(Box::new(Self::import_as_stochastic_labelled_petri_net(reader)?))
+    }
+
+    /**
+     * Reads a PNML file as a stochastic labelled Petri net, including the weights of the transitions.
+     * Rust4PM does not keep toolspecific elements, thus the file is read directly.
+     */
+    pub fn import_as_stochastic_labelled_petri_net(reader: &mut dyn BufRead) -> Result<StochasticLabelledPetriNet> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        let document = roxmltree::Document::parse(&text)?;
+        if !document.root_element().has_tag_name("pnml") {
+            return Err(anyhow!("file is not a PNML file"));
+        }
+        let net = document.root_element().children().find(|node| node.has_tag_name("net")).ok_or_else(|| anyhow!("PNML file does not contain a net"))?;
+
+        let mut lpn = LabelledPetriNet::new();
+        let mut weights = vec![];
+
+        //places; pages may be nested
+        let mut place2index = HashMap::new();
+        for place_node in net.descendants().filter(|node| node.has_tag_name("place")) {
+            let id = Self::get_id(place_node)?;
+            let place = lpn.add_place();
+            if place2index.insert(id, place).is_some() {
+                return Err(anyhow!("Place `{}` is declared twice.", id));
+            }
+
+            if let Some(tokens) = Self::get_text_of_child(place_node, "initialMarking") {
+                let tokens = tokens.parse::<u64>().with_context(|| format!("could not read initial marking of place `{}`", id))?;
+                lpn.get_initial_marking_mut().increase(place, tokens)?;
+            }
+        }
+
+        //transitions
+        let mut transition2index = HashMap::new();
+        for transition_node in net.descendants().filter(|node| node.has_tag_name("transition")) {
+            let id = Self::get_id(transition_node)?;
+            let label = match Self::get_text_of_child(transition_node, "name") {
+                Some(name) if !Self::is_invisible(transition_node) => Some(lpn.get_activity_key_mut().process_activity(name)),
+                _ => None
+            };
+            let transition = lpn.add_transition(label);
+            if transition2index.insert(id, transition).is_some() {
+                return Err(anyhow!("Transition `{}` is declared twice.", id));
+            }
+
+            weights.push(Self::get_weight(transition_node).with_context(|| format!("could not read weight of transition `{}`", id))?);
+        }
+
+        //a net without weights is not stochastic
+        if weights.iter().all(|weight| weight.is_none()) {
+            return Err(anyhow!("PNML file has no transition weights in `toolspecific` elements of the tools `{}` or `{}`, so it is not a stochastic labelled Petri net", TOOL_STOCHASTIC_PETRI_NET, TOOL_EBI));
+        }
+        let weights = weights.into_iter().map(|weight| weight.unwrap_or_else(Fraction::one)).collect::<Vec<_>>();
+
+        //arcs
+        for arc_node in net.descendants().filter(|node| node.has_tag_name("arc")) {
+            let source = arc_node.attribute("source").ok_or_else(|| anyhow!("Arc without source found."))?;
+            let target = arc_node.attribute("target").ok_or_else(|| anyhow!("Arc without target found."))?;
+            let cardinality = match Self::get_text_of_child(arc_node, "inscription") {
+                Some(inscription) => inscription.parse::<u64>().with_context(|| format!("could not read inscription of arc from `{}` to `{}`", source, target))?,
+                None => 1
+            };
+
+            match (place2index.get(source), transition2index.get(target), transition2index.get(source), place2index.get(target)) {
+                (Some(place), Some(transition), _, _) => lpn.add_place_transition_arc(*place, *transition, cardinality)?,
+                (_, _, Some(transition), Some(place)) => lpn.add_transition_place_arc(*transition, *place, cardinality)?,
+                _ => return Err(anyhow!("Arc from `{}` to `{}` does not connect a declared place and a declared transition.", source, target))
+            }
+        }
+
+        Ok((lpn, weights).into())
+    }
+
+    fn get_id<'a>(node: Node<'a, '_>) -> Result<&'a str> {
+        node.attribute("id").ok_or_else(|| anyhow!("Element `{}` without id found.", node.tag_name().name()))
+    }
+
+    /**
+     * Returns the text of constructs such as <name><text>a</text></name>.
+     */
+    fn get_text_of_child<'a>(node: Node<'a, '_>, child: &str) -> Option<&'a str> {
+        node.children().find(|node| node.has_tag_name(child))?.children().find(|node| node.has_tag_name("text"))?.text().map(|text| text.trim())
+    }
+
+    fn get_property<'a>(toolspecific: Node<'a, '_>, key: &str) -> Option<&'a str> {
+        toolspecific.children().find(|node| node.has_tag_name("property") && node.attribute("key") == Some(key))?.text().map(|text| text.trim())
+    }
+
+    /**
+     * A transition is silent if ProM marked it as invisible, either in its own toolspecific element or in the stochastic one.
+     */
+    fn is_invisible(transition_node: Node) -> bool {
+        transition_node.children().filter(|node| node.has_tag_name("toolspecific")).any(|toolspecific| {
+            toolspecific.attribute("activity") == Some("$invisible$") || Self::get_property(toolspecific, "invisible") == Some("true")
+        })
+    }
+
+    /**
+     * Returns the weight of the transition, or None if it has no weight.
+     */
+    fn get_weight(transition_node: Node) -> Result<Option<Fraction>> {
+        let mut result = None;
+        for toolspecific in transition_node.children().filter(|node| node.has_tag_name("toolspecific")) {
+            match (toolspecific.attribute("tool"), Self::get_property(toolspecific, "weight")) {
+                (Some(TOOL_EBI), Some(weight)) => return Ok(Some(weight.parse::<Fraction>()?)),
+                (Some(TOOL_STOCHASTIC_PETRI_NET), Some(weight)) => result = Some(weight.parse::<Fraction>()?),
+                _ => {}
+            }
+        }
+        Ok(result)
+    }
+
+    /**
+     * Writes a stochastic labelled Petri net as PNML, including the weights of the transitions.
+     * Rust4PM cannot write toolspecific elements, thus the file is written directly.
+     */
+    pub fn export_stochastic_labelled_petri_net(slpn: &StochasticLabelledPetriNet, f: &mut dyn std::io::Write) -> Result<()> {
+        writeln!(f, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(f, "<pnml>")?;
+        writeln!(f, "\t<net id=\"net\" type=\"http://www.pnml.org/version-2009/grammar/pnmlcoremodel\">")?;
+        writeln!(f, "\t\t<page id=\"n0\">")?;
+
+        for (place, tokens) in slpn.get_initial_marking().get_place2token().iter().enumerate() {
+            writeln!(f, "\t\t\t<place id=\"p{}\">", place)?;
+            if *tokens > 0 {
+                writeln!(f, "\t\t\t\t<initialMarking><text>{}</text></initialMarking>", tokens)?;
+            }
+            writeln!(f, "\t\t\t</place>")?;
+        }
+
+        for transition in 0..slpn.get_number_of_transitions() {
+            let weight = slpn.get_transition_weight(transition);
+            let decimal_weight = match weight {
+                Fraction::Exact(fraction) => fraction.to_f64(),
+                Fraction::Approx(value) => Some(*value),
+                Fraction::CannotCombineExactAndApprox => None,
+            }.ok_or_else(|| anyhow!("Weight {} of transition {} cannot be written as a decimal number.", weight, transition))?;
+
+            writeln!(f, "\t\t\t<transition id=\"t{}\">", transition)?;
+            match slpn.get_transition_label(transition) {
+                Some(activity) => writeln!(f, "\t\t\t\t<name><text>{}</text></name>", xml::escape(slpn.get_activity_key().get_activity_label(&activity)))?,
+                None => writeln!(f, "\t\t\t\t<toolspecific tool=\"ProM\" version=\"6.4\" activity=\"$invisible$\"/>")?,
+            }
+            writeln!(f, "\t\t\t\t<toolspecific tool=\"{}\" version=\"0.2\">", TOOL_STOCHASTIC_PETRI_NET)?;
+            writeln!(f, "\t\t\t\t\t<property key=\"distributionType\">IMMEDIATE</property>")?;
+            writeln!(f, "\t\t\t\t\t<property key=\"weight\">{}</property>", decimal_weight)?;
+            writeln!(f, "\t\t\t\t\t<property key=\"priority\">1</property>")?;
+            writeln!(f, "\t\t\t\t\t<property key=\"invisible\">{}</property>", slpn.is_transition_silent(transition))?;
+            writeln!(f, "\t\t\t\t</toolspecific>")?;
+            writeln!(f, "\t\t\t\t<toolspecific tool=\"{}\" version=\"1.0\">", TOOL_EBI)?;
+            writeln!(f, "\t\t\t\t\t<property key=\"weight\">{}</property>", weight)?;
+            writeln!(f, "\t\t\t\t</toolspecific>")?;
+            writeln!(f, "\t\t\t</transition>")?;
+        }
+
+        for transition in 0..slpn.get_number_of_transitions() {
+            for (place, cardinality) in slpn.transition2input_places[transition].iter().zip(slpn.transition2input_places_cardinality[transition].iter()) {
+                write_arc(f, &format!("p{}", place), &format!("t{}", transition), *cardinality)?;
+            }
+            for (place, cardinality) in slpn.transition2output_places[transition].iter().zip(slpn.transition2output_places_cardinality[transition].iter()) {
+                write_arc(f, &format!("t{}", transition), &format!("p{}", place), *cardinality)?;
+            }
+        }
+
+        writeln!(f, "\t\t</page>")?;
+        writeln!(f, "\t</net>")?;
+        writeln!(f, "</pnml>")?;
+        Ok(())
+    }
+
     fn export_from_stochastic_labelled_petri_net(object: EbiOutput, f: &mut dyn std::io::Write) -> Result<()> {
         match object {
-            EbiOutput::Object(EbiObject::StochasticLabelledPetriNet(slpn)) => {
-                let lpn = <StochasticLabelledPetriNet as Into<LabelledPetriNet>>::into(slpn);
-                PetriNetMarkupLanguage::try_from(lpn)?.export(f)
-            },
+            EbiOutput::Object(EbiObject::StochasticLabelledPetriNet(slpn)) => Self::export_stochastic_labelled_petri_net(&slpn, f),
             _ => unreachable!()
         }
     }
@@ -265,4 +465,11 @@ impl TryFrom<LabelledPetriNet> for PetriNetMarkupLanguage {
 
         Ok(Self{net: result})
     }
-}
\ No newline at end of file
+}
+
+fn write_arc(f: &mut dyn std::io::Write, source: &str, target: &str, cardinality: u64) -> Result<()> {
+    writeln!(f, "\t\t\t<arc id=\"{}-{}\" source=\"{}\" target=\"{}\">", source, target, source, target)?;
+    writeln!(f, "\t\t\t\t<inscription><text>{}</text></inscription>", cardinality)?;
+    writeln!(f, "\t\t\t</arc>")?;
+    Ok(())
+}
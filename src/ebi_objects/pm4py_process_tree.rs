@@ -0,0 +1,297 @@
+use std::io::{BufRead, Write};
+
+use anyhow::{anyhow, Result};
+
+use crate::{ebi_framework::{activity_key::{ActivityKey, HasActivityKey}, ebi_file_handler::EbiFileHandler, ebi_input::{self, EbiObjectImporter, EbiTraitImporter}, ebi_object::EbiObject, ebi_output::{EbiObjectExporter, EbiOutput}, exportable::Exportable, importable::Importable}, ebi_traits::{ebi_trait_graphable::EbiTraitGraphable, ebi_trait_semantics::{EbiTraitSemantics, ToSemantics}}};
+
+use super::process_tree::{Node, Operator, ProcessTree};
+
+pub const FORMAT_SPECIFICATION: &str = "A pm4py process tree is a process tree in the textual notation of pm4py~\\cite{DBLP:journals/simpa/BertiZS23}.
+Each node is either \\texttt{tau}, an activity label between single (or double) quotes, or an operator followed by its children between parentheses, separated by commas.
+The operators are \\texttt{->} (sequence), \\texttt{X} (xor), \\texttt{+} (concurrent), \\texttt{<>} (interleaved), \\texttt{O} (or) and \\texttt{*} (loop).
+A quote or backslash in a label is preceded by a backslash.
+As pm4py loops have two children, a loop with multiple redo children is written with an \\texttt{X} of these children as its redo child, and a loop with one child gets \\texttt{tau} as its redo child.
+Whitespace between the elements is ignored.
+As a pm4py process tree takes up a single line, it can be given to Ebi directly on the standard input, using \\texttt{-} as file name.
+
+For instance:
+    \\lstinputlisting[style=boxed]{../testfiles/all_operators.pm4py}";
+
+pub const EBI_PM4PY_PROCESS_TREE: EbiFileHandler = EbiFileHandler {
+    name: "pm4py process tree",
+    article: "a",
+    file_extension: "pm4py",
+    format_specification: &FORMAT_SPECIFICATION,
+    validator: ebi_input::validate::<Pm4pyProcessTree>,
+    trait_importers: &[
+        EbiTraitImporter::Semantics(Pm4pyProcessTree::import_as_semantics),
+        EbiTraitImporter::Graphable(Pm4pyProcessTree::import_as_graphable),
+    ],
+    object_importers: &[
+        EbiObjectImporter::ProcessTree(Pm4pyProcessTree::import_as_object),
+        EbiObjectImporter::LabelledPetriNet(Pm4pyProcessTree::import_as_labelled_petri_net),
+    ],
+    object_exporters: &[
+        EbiObjectExporter::ProcessTree(Pm4pyProcessTree::export_from_object),
+    ],
+    java_object_handlers: &[], //java translations covered by ProcessTree
+};
+
+pub struct Pm4pyProcessTree {
+    tree: ProcessTree
+}
+
+impl Pm4pyProcessTree {
+    pub fn import_as_semantics(reader: &mut dyn BufRead) -> Result<EbiTraitSemantics> {
+        Ok(Self::import(reader)?.tree.to_semantics())
+    }
+
+    pub fn import_as_graphable(reader: &mut dyn BufRead) -> Result<Box<dyn EbiTraitGraphable>> {
+        Ok(Box::new(Self::import(reader)?.tree))
+    }
+
+    pub fn import_as_labelled_petri_net(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::LabelledPetriNet(Self::import(reader)?.tree.get_labelled_petri_net()))
+    }
+}
+
+impl ProcessTree {
+    /**
+     * Parses a process tree in the notation of pm4py, such as ->('a', X('b', tau), *('c', tau)).
+     */
+    pub fn from_pm4py_notation(s: &str) -> Result<Self> {
+        let mut parser = Parser {
+            chars: s.chars().collect(),
+            position: 0,
+        };
+        let mut activity_key = ActivityKey::new();
+        let mut tree = vec![];
+        parser.parse_node(&mut tree, &mut activity_key)?;
+
+        parser.skip_whitespace();
+        if parser.position < parser.chars.len() {
+            return Err(anyhow!("Unexpected `{}` at position {} after the end of the process tree.", parser.chars[parser.position], parser.position));
+        }
+
+        Ok(ProcessTree::new(activity_key, tree))
+    }
+
+    /**
+     * Writes the process tree in the notation of pm4py.
+     */
+    pub fn to_pm4py_notation(&self) -> String {
+        let mut result = String::new();
+        self.node_to_pm4py_notation(self.get_root(), &mut result);
+        result
+    }
+
+    /**
+     * Returns the next node in the tree.
+     */
+    fn node_to_pm4py_notation(&self, node: usize, result: &mut String) -> usize {
+        match &self.tree[node] {
+            Node::Tau => {
+                result.push_str("tau");
+                node + 1
+            },
+            Node::Activity(activity) => {
+                let label = self.get_activity_key().get_activity_label(activity);
+                result.push('\'');
+                result.push_str(&label.replace('\\', "\\\\").replace('\'', "\\'"));
+                result.push('\'');
+                node + 1
+            },
+            Node::Operator(Operator::Loop, number_of_children) => {
+                result.push_str("*(");
+                let mut child = self.node_to_pm4py_notation(node + 1, result);
+                result.push_str(", ");
+                match number_of_children {
+                    1 => result.push_str("tau"),
+                    2 => child = self.node_to_pm4py_notation(child, result),
+                    _ => {
+                        result.push_str("X(");
+                        for i in 1..*number_of_children {
+                            if i > 1 {
+                                result.push_str(", ");
+                            }
+                            child = self.node_to_pm4py_notation(child, result);
+                        }
+                        result.push(')');
+                    }
+                }
+                result.push(')');
+                child
+            },
+            Node::Operator(operator, number_of_children) => {
+                result.push_str(match operator {
+                    Operator::Xor => "X",
+                    Operator::Sequence => "->",
+                    Operator::Interleaved => "<>",
+                    Operator::Concurrent => "+",
+                    Operator::Or => "O",
+                    Operator::Loop => unreachable!(),
+                });
+                result.push('(');
+                let mut child = node + 1;
+                for i in 0..*number_of_children {
+                    if i > 0 {
+                        result.push_str(", ");
+                    }
+                    child = self.node_to_pm4py_notation(child, result);
+                }
+                result.push(')');
+                child
+            },
+        }
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn skip_whitespace(&mut self) {
+        while self.position < self.chars.len() && self.chars[self.position].is_whitespace() {
+            self.position += 1;
+        }
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        s.chars().enumerate().all(|(i, c)| self.chars.get(self.position + i) == Some(&c))
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        self.skip_whitespace();
+        match self.chars.get(self.position) {
+            Some(found) if *found == c => {
+                self.position += 1;
+                Ok(())
+            },
+            Some(found) => Err(anyhow!("Expected `{}` at position {}, but found `{}`.", c, self.position, found)),
+            None => Err(anyhow!("Expected `{}` at position {}, but the process tree ended.", c, self.position)),
+        }
+    }
+
+    ///read one node, recursively
+    fn parse_node(&mut self, tree: &mut Vec<Node>, activity_key: &mut ActivityKey) -> Result<()> {
+        self.skip_whitespace();
+
+        //leaves
+        if self.starts_with("tau") {
+            self.position += 3;
+            tree.push(Node::Tau);
+            return Ok(());
+        }
+        if let Some(quote) = self.chars.get(self.position).filter(|c| **c == '\'' || **c == '"').cloned() {
+            let label = self.parse_label(quote)?;
+            tree.push(Node::Activity(activity_key.process_activity(&label)));
+            return Ok(());
+        }
+
+        //operators
+        let operator = if self.starts_with("->") {
+            (Operator::Sequence, 2)
+        } else if self.starts_with("<>") {
+            (Operator::Interleaved, 2)
+        } else if self.starts_with("X") {
+            (Operator::Xor, 1)
+        } else if self.starts_with("+") {
+            (Operator::Concurrent, 1)
+        } else if self.starts_with("O") {
+            (Operator::Or, 1)
+        } else if self.starts_with("*") {
+            (Operator::Loop, 1)
+        } else {
+            return match self.chars.get(self.position) {
+                Some(c) => Err(anyhow!("Expected `tau`, a quoted activity or an operator at position {}, but found `{}`.", self.position, c)),
+                None => Err(anyhow!("Expected `tau`, a quoted activity or an operator at position {}, but the process tree ended.", self.position)),
+            };
+        };
+        self.position += operator.1;
+        self.expect('(')?;
+
+        let node_index = tree.len();
+        tree.push(Node::Operator(operator.0, 0));
+        let mut number_of_children = 0;
+        loop {
+            self.parse_node(tree, activity_key)?;
+            number_of_children += 1;
+
+            self.skip_whitespace();
+            match self.chars.get(self.position) {
+                Some(',') => self.position += 1,
+                Some(')') => {
+                    self.position += 1;
+                    break;
+                },
+                Some(c) => return Err(anyhow!("Expected `,` or `)` at position {}, but found `{}`.", self.position, c)),
+                None => return Err(anyhow!("Expected `,` or `)` at position {}, but the process tree ended.", self.position)),
+            }
+        }
+        tree[node_index] = Node::Operator(operator.0, number_of_children);
+
+        Ok(())
+    }
+
+    fn parse_label(&mut self, quote: char) -> Result<String> {
+        let start = self.position;
+        self.position += 1;
+        let mut label = String::new();
+        while let Some(c) = self.chars.get(self.position) {
+            self.position += 1;
+            match c {
+                '\\' => {
+                    let escaped = self.chars.get(self.position).ok_or_else(|| anyhow!("Activity label starting at position {} is not closed.", start))?;
+                    label.push(*escaped);
+                    self.position += 1;
+                },
+                c if *c == quote => return Ok(label),
+                c => label.push(*c),
+            }
+        }
+        Err(anyhow!("Activity label starting at position {} is not closed.", start))
+    }
+}
+
+impl Importable for Pm4pyProcessTree {
+    fn import_as_object(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::ProcessTree(Self::import(reader)?.tree))
+    }
+
+    fn import(reader: &mut dyn BufRead) -> Result<Self> where Self: Sized {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        Ok(Self {
+            tree: ProcessTree::from_pm4py_notation(&text)?
+        })
+    }
+}
+
+impl Exportable for Pm4pyProcessTree {
+    fn export_from_object(object: EbiOutput, f: &mut dyn Write) -> Result<()> {
+        match object {
+            EbiOutput::Object(EbiObject::ProcessTree(tree)) => Self::from(tree).export(f),
+            _ => unreachable!()
+        }
+    }
+
+    fn export(&self, f: &mut dyn Write) -> Result<()> {
+        Ok(writeln!(f, "{}", self.tree.to_pm4py_notation())?)
+    }
+}
+
+impl From<ProcessTree> for Pm4pyProcessTree {
+    fn from(value: ProcessTree) -> Self {
+        Self {
+            tree: value
+        }
+    }
+}
+
+impl From<Pm4pyProcessTree> for ProcessTree {
+    fn from(value: Pm4pyProcessTree) -> Self {
+        value.tree
+    }
+}
@@ -55,7 +55,7 @@ pub const EBI_PROCESS_TREE: EbiFileHandler = EbiFileHandler {
     ],
 };
 
-#[derive(Debug,ActivityKey)]
+#[derive(Clone,Debug,ActivityKey)]
 pub struct ProcessTree {
     activity_key: ActivityKey,
     pub(crate) tree: Vec<Node>,
@@ -625,7 +625,7 @@ pub enum Operator {
 }
 
 impl Operator {
-    fn to_string(&self) -> &str {
+    pub(crate) fn to_string(&self) -> &str {
         match self {
             Operator::Xor => "xor",
             Operator::Sequence => "sequence",
@@ -0,0 +1,281 @@
+use std::{collections::{HashMap, HashSet}, io::{BufRead, Write}};
+
+use anyhow::{anyhow, Result};
+
+use crate::{ebi_framework::{activity_key::{ActivityKey, HasActivityKey}, ebi_file_handler::EbiFileHandler, ebi_input::{self, EbiObjectImporter, EbiTraitImporter}, ebi_object::EbiObject, ebi_output::{EbiObjectExporter, EbiOutput}, exportable::Exportable, importable::Importable}, ebi_traits::{ebi_trait_graphable::EbiTraitGraphable, ebi_trait_semantics::{EbiTraitSemantics, ToSemantics}}, xml};
+
+use super::process_tree::{Node, Operator, ProcessTree};
+
+pub const FORMAT_SPECIFICATION: &str = "A process tree markup language file is an XML file as written by ProM and pm4py.
+Its \\texttt{processTree} element contains the nodes of the tree and \\texttt{parentsNode} elements that link each node to its parent, in the order of the children.
+Activities are \\texttt{manualTask} elements with the activity as name, and silent steps are \\texttt{automaticTask} elements.
+The operators are \\texttt{sequence}, \\texttt{xor}, \\texttt{and} (concurrent), \\texttt{interleaved}, \\texttt{or} and \\texttt{xorLoop}; \\texttt{def} and \\texttt{defLoop} are read as \\texttt{xor} and \\texttt{xorLoop}.
+A \\texttt{xorLoop} has a body, a redo and an exit child.
+When reading, a silent exit child is dropped, and otherwise the loop is followed by its exit child in a sequence.
+When writing, a loop with multiple redo children gets an \\texttt{xor} of these children as its redo child.
+
+For instance:
+    \\lstinputlisting[language=xml, style=boxed]{../testfiles/all_operators.ptml}";
+
+pub const EBI_PROCESS_TREE_MARKUP_LANGUAGE: EbiFileHandler = EbiFileHandler {
+    name: "process tree markup language",
+    article: "a",
+    file_extension: "ptml",
+    format_specification: &FORMAT_SPECIFICATION,
+    validator: ebi_input::validate::<ProcessTreeMarkupLanguage>,
+    trait_importers: &[
+        EbiTraitImporter::Semantics(ProcessTreeMarkupLanguage::import_as_semantics),
+        EbiTraitImporter::Graphable(ProcessTreeMarkupLanguage::import_as_graphable),
+    ],
+    object_importers: &[
+        EbiObjectImporter::ProcessTree(ProcessTreeMarkupLanguage::import_as_object),
+        EbiObjectImporter::LabelledPetriNet(ProcessTreeMarkupLanguage::import_as_labelled_petri_net),
+    ],
+    object_exporters: &[
+        EbiObjectExporter::ProcessTree(ProcessTreeMarkupLanguage::export_from_object),
+    ],
+    java_object_handlers: &[], //java translations covered by ProcessTree
+};
+
+pub struct ProcessTreeMarkupLanguage {
+    tree: ProcessTree
+}
+
+impl ProcessTreeMarkupLanguage {
+    pub fn import_as_semantics(reader: &mut dyn BufRead) -> Result<EbiTraitSemantics> {
+        Ok(Self::import(reader)?.tree.to_semantics())
+    }
+
+    pub fn import_as_graphable(reader: &mut dyn BufRead) -> Result<Box<dyn EbiTraitGraphable>> {
+        Ok(Box::new(Self::import(reader)?.tree))
+    }
+
+    pub fn import_as_labelled_petri_net(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::LabelledPetriNet(Self::import(reader)?.tree.get_labelled_petri_net()))
+    }
+
+    /**
+     * Reads one node and its descendants, recursively.
+     */
+    fn xml_to_tree<'a>(id: &'a str, elements: &HashMap<&'a str, roxmltree::Node<'a, '_>>, children: &HashMap<&'a str, Vec<&'a str>>, visited: &mut HashSet<&'a str>, tree: &mut Vec<Node>, activity_key: &mut ActivityKey) -> Result<()> {
+        if !visited.insert(id) {
+            return Err(anyhow!("Node `{}` has multiple parents.", id));
+        }
+        let element = elements.get(id).ok_or_else(|| anyhow!("Node `{}` is referenced but not declared.", id))?;
+        let node_children = children.get(id).map(|children| children.as_slice()).unwrap_or(&[]);
+
+        let operator = match element.tag_name().name() {
+            "manualTask" => {
+                let label = element.attribute("name").ok_or_else(|| anyhow!("Task `{}` has no name.", id))?;
+                tree.push(Node::Activity(activity_key.process_activity(label)));
+                return Ok(());
+            },
+            "automaticTask" => {
+                tree.push(Node::Tau);
+                return Ok(());
+            },
+            "xorLoop" | "defLoop" => {
+                return Self::xml_loop_to_tree(id, node_children, elements, children, visited, tree, activity_key);
+            },
+            "sequence" => Operator::Sequence,
+            "xor" | "def" => Operator::Xor,
+            "and" => Operator::Concurrent,
+            "interleaved" => Operator::Interleaved,
+            "or" => Operator::Or,
+            tag => return Err(anyhow!("Node `{}` has the unsupported type `{}`.", id, tag)),
+        };
+
+        if node_children.is_empty() {
+            return Err(anyhow!("Operator node `{}` has no children.", id));
+        }
+        tree.push(Node::Operator(operator, node_children.len()));
+        for child in node_children {
+            Self::xml_to_tree(child, elements, children, visited, tree, activity_key)?;
+        }
+        Ok(())
+    }
+
+    fn xml_loop_to_tree<'a>(id: &'a str, node_children: &[&'a str], elements: &HashMap<&'a str, roxmltree::Node<'a, '_>>, children: &HashMap<&'a str, Vec<&'a str>>, visited: &mut HashSet<&'a str>, tree: &mut Vec<Node>, activity_key: &mut ActivityKey) -> Result<()> {
+        let (body, redo, exit) = match node_children {
+            [body, redo] => (body, redo, None),
+            [body, redo, exit] => (body, redo, Some(exit)),
+            _ => return Err(anyhow!("Loop node `{}` has {} children, whereas 2 or 3 are expected.", id, node_children.len()))
+        };
+        let exit = exit.filter(|exit| !elements.get(*exit).is_some_and(|element| element.has_tag_name("automaticTask")));
+
+        if let Some(exit) = exit {
+            tree.push(Node::Operator(Operator::Sequence, 2));
+            tree.push(Node::Operator(Operator::Loop, 2));
+            Self::xml_to_tree(body, elements, children, visited, tree, activity_key)?;
+            Self::xml_to_tree(redo, elements, children, visited, tree, activity_key)?;
+            Self::xml_to_tree(exit, elements, children, visited, tree, activity_key)
+        } else {
+            tree.push(Node::Operator(Operator::Loop, 2));
+            Self::xml_to_tree(body, elements, children, visited, tree, activity_key)?;
+            Self::xml_to_tree(redo, elements, children, visited, tree, activity_key)
+        }
+    }
+
+    /**
+     * Writes the node with the given identifier and its descendants, recursively. Returns the next node in the tree.
+     */
+    fn tree_to_xml(&self, node: usize, id: String, next_id: &mut usize, elements: &mut Vec<String>, edges: &mut Vec<(String, String)>) -> Result<usize> {
+        match &self.tree.tree[node] {
+            Node::Tau => {
+                elements.push(format!("<automaticTask id=\"{}\" name=\"\"/>", id));
+                Ok(node + 1)
+            },
+            Node::Activity(activity) => {
+                elements.push(format!("<manualTask id=\"{}\" name=\"{}\"/>", id, xml::escape(self.tree.get_activity_key().get_activity_label(activity))));
+                Ok(node + 1)
+            },
+            Node::Operator(Operator::Loop, number_of_children) => {
+                elements.push(format!("<xorLoop id=\"{}\" name=\"\"/>", id));
+                let mut child = self.child_to_xml(node + 1, &id, next_id, elements, edges)?;
+
+                //redo
+                match number_of_children {
+                    1 => {
+                        let redo = Self::create_id(next_id);
+                        elements.push(format!("<automaticTask id=\"{}\" name=\"\"/>", redo));
+                        edges.push((id.clone(), redo));
+                    },
+                    2 => {
+                        child = self.child_to_xml(child, &id, next_id, elements, edges)?;
+                    },
+                    _ => {
+                        let redo = Self::create_id(next_id);
+                        elements.push(format!("<xor id=\"{}\" name=\"\"/>", redo));
+                        edges.push((id.clone(), redo.clone()));
+                        for _ in 1..*number_of_children {
+                            child = self.child_to_xml(child, &redo, next_id, elements, edges)?;
+                        }
+                    }
+                }
+
+                //exit
+                let exit = Self::create_id(next_id);
+                elements.push(format!("<automaticTask id=\"{}\" name=\"\"/>", exit));
+                edges.push((id, exit));
+
+                Ok(child)
+            },
+            Node::Operator(operator, number_of_children) => {
+                let tag = match operator {
+                    Operator::Xor => "xor",
+                    Operator::Sequence => "sequence",
+                    Operator::Interleaved => "interleaved",
+                    Operator::Concurrent => "and",
+                    Operator::Or => "or",
+                    Operator::Loop => unreachable!(),
+                };
+                elements.push(format!("<{} id=\"{}\" name=\"\"/>", tag, id));
+                let mut child = node + 1;
+                for _ in 0..*number_of_children {
+                    child = self.child_to_xml(child, &id, next_id, elements, edges)?;
+                }
+                Ok(child)
+            },
+        }
+    }
+
+    fn child_to_xml(&self, child: usize, parent: &String, next_id: &mut usize, elements: &mut Vec<String>, edges: &mut Vec<(String, String)>) -> Result<usize> {
+        let id = Self::create_id(next_id);
+        edges.push((parent.clone(), id.clone()));
+        self.tree_to_xml(child, id, next_id, elements, edges)
+    }
+
+    /**
+     * ProM requires the identifiers to be UUIDs.
+     */
+    fn create_id(next_id: &mut usize) -> String {
+        *next_id += 1;
+        format!("00000000-0000-0000-0000-{:012x}", *next_id - 1)
+    }
+}
+
+impl Importable for ProcessTreeMarkupLanguage {
+    fn import_as_object(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::ProcessTree(Self::import(reader)?.tree))
+    }
+
+    fn import(reader: &mut dyn BufRead) -> Result<Self> where Self: Sized {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        let document = roxmltree::Document::parse(&text)?;
+        if !document.root_element().has_tag_name("ptml") {
+            return Err(anyhow!("file is not a PTML file"));
+        }
+        let process_tree = document.root_element().children().find(|node| node.has_tag_name("processTree")).ok_or_else(|| anyhow!("PTML file does not contain a process tree."))?;
+        let root = process_tree.attribute("root").ok_or_else(|| anyhow!("Process tree has no root."))?;
+
+        //gather the nodes and the edges
+        let mut elements = HashMap::new();
+        let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+        for element in process_tree.children().filter(|node| node.is_element()) {
+            if element.has_tag_name("parentsNode") {
+                let source = element.attribute("sourceId").ok_or_else(|| anyhow!("Edge without source found."))?;
+                let target = element.attribute("targetId").ok_or_else(|| anyhow!("Edge without target found."))?;
+                children.entry(source).or_default().push(target);
+            } else {
+                let id = element.attribute("id").ok_or_else(|| anyhow!("Node `{}` without id found.", element.tag_name().name()))?;
+                if elements.insert(id, element).is_some() {
+                    return Err(anyhow!("Node `{}` is declared twice.", id));
+                }
+            }
+        }
+
+        let mut activity_key = ActivityKey::new();
+        let mut tree = vec![];
+        Self::xml_to_tree(root, &elements, &children, &mut HashSet::new(), &mut tree, &mut activity_key)?;
+
+        Ok(Self {
+            tree: ProcessTree::new(activity_key, tree)
+        })
+    }
+}
+
+impl Exportable for ProcessTreeMarkupLanguage {
+    fn export_from_object(object: EbiOutput, f: &mut dyn Write) -> Result<()> {
+        match object {
+            EbiOutput::Object(EbiObject::ProcessTree(tree)) => Self::from(tree).export(f),
+            _ => unreachable!()
+        }
+    }
+
+    fn export(&self, f: &mut dyn Write) -> Result<()> {
+        let mut next_id = 0;
+        let tree_id = Self::create_id(&mut next_id);
+        let root_id = Self::create_id(&mut next_id);
+        let mut elements = vec![];
+        let mut edges = vec![];
+        self.tree_to_xml(self.tree.get_root(), root_id.clone(), &mut next_id, &mut elements, &mut edges)?;
+
+        writeln!(f, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(f, "<ptml>")?;
+        writeln!(f, "\t<processTree id=\"{}\" name=\"process tree\" root=\"{}\">", tree_id, root_id)?;
+        for element in elements {
+            writeln!(f, "\t\t{}", element)?;
+        }
+        for (source, target) in edges {
+            writeln!(f, "\t\t<parentsNode id=\"{}\" sourceId=\"{}\" targetId=\"{}\"/>", Self::create_id(&mut next_id), source, target)?;
+        }
+        writeln!(f, "\t</processTree>")?;
+        writeln!(f, "</ptml>")?;
+        Ok(())
+    }
+}
+
+impl From<ProcessTree> for ProcessTreeMarkupLanguage {
+    fn from(value: ProcessTree) -> Self {
+        Self {
+            tree: value
+        }
+    }
+}
+
+impl From<ProcessTreeMarkupLanguage> for ProcessTree {
+    fn from(value: ProcessTreeMarkupLanguage) -> Self {
+        value.tree
+    }
+}
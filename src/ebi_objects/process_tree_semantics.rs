@@ -23,7 +23,6 @@ impl Semantics for ProcessTree {
         } else {
             let node = self.transition2node.get(transition).ok_or_else(|| anyhow!("Transition does not exist."))?;
             self.start_node(state, *node, None);
-            println!("execute node {}", node);
             self.close_node(state, *node);
         }
         Ok(())
@@ -40,7 +39,8 @@ impl Semantics for ProcessTree {
                 _ => false,
             }
         } else {
-            false
+            //the termination transition has no label
+            transition == self.transition2node.len()
         }
     }
 
@@ -79,9 +79,8 @@ impl ProcessTree {
     /**
      * Start executing a node.
      */
-    fn start_node(&self, state: &mut <Self as Semantics>::SemState, node: usize, child: Option<usize>) {
+    pub(crate) fn start_node(&self, state: &mut <Self as Semantics>::SemState, node: usize, child: Option<usize>) {
         if state[node] != NodeState::Started {
-            println!("start node {} from child {:?}", node, child);
             state[node] = NodeState::Started;
 
            match self.tree[node] {
@@ -111,17 +110,13 @@ impl ProcessTree {
         }
     }
 
-    fn withdraw_enablement(&self, state: &mut <Self as Semantics>::SemState, node: usize) {
-        println!("withdraw enablement of node {}", node);
+    pub(crate) fn withdraw_enablement(&self, state: &mut <Self as Semantics>::SemState, node: usize) {
         for grandchild in node..self.traverse(node) {
             state[grandchild] = NodeState::Closed;
         }
     }
 
-    fn close_node(&self, state: &mut <Self as Semantics>::SemState, node: usize) {
-
-        println!("close node {}", node);
-        
+    pub(crate) fn close_node(&self, state: &mut <Self as Semantics>::SemState, node: usize) {
         //close this node and all of its children
         for grandchild in node..self.traverse(node) {
             state[grandchild] = NodeState::Closed;
@@ -134,7 +129,6 @@ impl ProcessTree {
                 Node::Activity(_) => unreachable!(),
                 Node::Operator(Operator::Sequence, number_of_children) => {
                     //for a sequence parent, we enable the next child
-                    println!("close node {}, parent is sequence node {}", node, parent);
                     if child_rank < number_of_children - 1 {
                         let next_child = self.get_child(parent, child_rank + 1);
                         self.enable_node(state, next_child);
@@ -170,7 +164,7 @@ impl ProcessTree {
         }
     }
 
-    fn enable_node(&self, state: &mut <Self as Semantics>::SemState, node: usize) {
+    pub(crate) fn enable_node(&self, state: &mut <Self as Semantics>::SemState, node: usize) {
         state[node] = NodeState::Enabled;
 
         match self.tree[node] {
@@ -189,7 +183,7 @@ impl ProcessTree {
         }
     }
 
-    fn can_execute(&self, state: &<Self as Semantics>::SemState, node: usize) -> bool {
+    pub(crate) fn can_execute(&self, state: &<Self as Semantics>::SemState, node: usize) -> bool {
         if let Some(NodeState::Closed) = state.get(node) {
             return false;
         }
@@ -0,0 +1,473 @@
+use std::{collections::{hash_map::Entry, HashMap}, fmt::Display, io::{self, BufRead, Write}, str::FromStr};
+use anyhow::{anyhow, Context, Result, Error};
+use layout::topo::layout::VisualGraph;
+
+use crate::{ebi_framework::{activity_key::{Activity, ActivityKey, ActivityKeyTranslator}, ebi_file_handler::EbiFileHandler, ebi_input::{self, EbiInput, EbiObjectImporter, EbiTraitImporter}, ebi_object::EbiObject, ebi_output::{EbiObjectExporter, EbiOutput}, ebi_trait::FromEbiTraitObject, exportable::Exportable, importable::Importable, infoable::Infoable}, ebi_traits::{ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_graphable::{self, EbiTraitGraphable}, ebi_trait_queriable_stochastic_language, ebi_trait_semantics::{EbiTraitSemantics, ToSemantics}, ebi_trait_stochastic_deterministic_semantics::{EbiTraitStochasticDeterministicSemantics, ToStochasticDeterministicSemantics}, ebi_trait_stochastic_semantics::{EbiTraitStochasticSemantics, ToStochasticSemantics}}, line_reader::LineReader, math::fraction::Fraction};
+
+use super::{directly_follows_model::DirectlyFollowsModel, labelled_petri_net::LabelledPetriNet, stochastic_deterministic_finite_automaton::StochasticDeterministicFiniteAutomaton, stochastic_labelled_petri_net::StochasticLabelledPetriNet};
+
+pub const HEADER: &str = "stochastic directly follows model";
+
+pub const FORMAT_SPECIFICATION: &str = "A stochastic directly follows model is a line-based structure. Lines starting with a \\# are ignored.
+    This first line is exactly `stochastic directly follows model'.
+    The second line is the weight of the empty trace.
+    The third line is the number of activities in the model. Each activity label may appear only once.
+    The following lines each contain an activity.
+    The next line contains the number of start activities, followed by, for each start activity, a line with the index of the start activity and a line with its weight.
+    The next line contains the number of end activities, followed by, for each end activity, a line with the index of the end activity and a line with its weight.
+    The next line contains the number of edges, followed by, for each edge, a line with first the index of the source activity, then the `>` symbol, then the index of the target activity, and a line with the weight of the edge.
+    Weights may be any non-negative fraction or decimal number, and are typically frequencies.
+
+    Before the first activity, the model starts with an activity or ends with the empty trace, with a probability proportional to their weights.
+    After an activity, the model continues with the target of an outgoing edge or ends, with a probability proportional to the weights of the edges and the end weight of the activity.
+
+    For instance:
+    \\lstinputlisting[language=ebilines, style=boxed]{../testfiles/a-b_star.sdfm}";
+
+pub const EBI_STOCHASTIC_DIRECTLY_FOLLOWS_MODEL: EbiFileHandler = EbiFileHandler {
+    name: "stochastic directly follows model",
+    article: "a",
+    file_extension: "sdfm",
+    format_specification: &FORMAT_SPECIFICATION,
+    validator: ebi_input::validate::<StochasticDirectlyFollowsModel>,
+    trait_importers: &[
+        EbiTraitImporter::QueriableStochasticLanguage(ebi_trait_queriable_stochastic_language::import::<StochasticDirectlyFollowsModel>),
+        EbiTraitImporter::StochasticDeterministicSemantics(StochasticDirectlyFollowsModel::import_as_stochastic_deterministic_semantics),
+        EbiTraitImporter::StochasticSemantics(StochasticDirectlyFollowsModel::import_as_stochastic_semantics),
+        EbiTraitImporter::Semantics(StochasticDirectlyFollowsModel::import_as_semantics),
+        EbiTraitImporter::Graphable(ebi_trait_graphable::import::<StochasticDirectlyFollowsModel>),
+    ],
+    object_importers: &[
+        EbiObjectImporter::StochasticDirectlyFollowsModel(StochasticDirectlyFollowsModel::import_as_object),
+        EbiObjectImporter::DirectlyFollowsModel(StochasticDirectlyFollowsModel::import_as_directly_follows_model),
+        EbiObjectImporter::StochasticDeterministicFiniteAutomaton(StochasticDirectlyFollowsModel::import_as_stochastic_deterministic_finite_automaton),
+        EbiObjectImporter::StochasticLabelledPetriNet(StochasticDirectlyFollowsModel::import_as_stochastic_labelled_petri_net),
+        EbiObjectImporter::LabelledPetriNet(StochasticDirectlyFollowsModel::import_as_labelled_petri_net),
+    ],
+    object_exporters: &[
+        EbiObjectExporter::StochasticDirectlyFollowsModel(StochasticDirectlyFollowsModel::export_from_object),
+    ],
+    java_object_handlers: &[],
+};
+
+/**
+ * A directly follows model with a weight on each start activity, end activity, edge and on the empty trace.
+ * Each activity has exactly one node.
+ */
+#[derive(ActivityKey,Debug,Clone)]
+pub struct StochasticDirectlyFollowsModel {
+    activity_key: ActivityKey,
+    pub(crate) node_2_activity: Vec<Activity>,
+    pub(crate) activity_2_node: HashMap<Activity, usize>,
+    pub(crate) empty_traces_weight: Fraction,
+    pub(crate) edges: Vec<Vec<Fraction>>, //matrix of edge weights
+    pub(crate) start_node_weights: Vec<Fraction>,
+    pub(crate) end_node_weights: Vec<Fraction>,
+}
+
+impl StochasticDirectlyFollowsModel {
+
+    pub fn new(activity_key: ActivityKey) -> Self {
+        Self {
+            activity_key: activity_key,
+            node_2_activity: vec![],
+            activity_2_node: HashMap::new(),
+            empty_traces_weight: Fraction::zero(),
+            edges: vec![],
+            start_node_weights: vec![],
+            end_node_weights: vec![],
+        }
+    }
+
+    /**
+     * Returns the node of the activity, which is added if it did not exist yet.
+     */
+    pub fn get_or_add_node(&mut self, activity: Activity) -> usize {
+        match self.activity_2_node.entry(activity) {
+            Entry::Occupied(e) => *e.get(),
+            Entry::Vacant(e) => {
+                let node = self.node_2_activity.len();
+                e.insert(node);
+                self.node_2_activity.push(activity);
+                for row in self.edges.iter_mut() {
+                    row.push(Fraction::zero());
+                }
+                self.edges.push(vec![Fraction::zero(); node + 1]);
+                self.start_node_weights.push(Fraction::zero());
+                self.end_node_weights.push(Fraction::zero());
+                node
+            },
+        }
+    }
+
+    pub fn add_empty_trace(&mut self, weight: &Fraction) {
+        self.empty_traces_weight += weight;
+    }
+
+    pub fn add_start_node(&mut self, node: usize, weight: &Fraction) {
+        self.start_node_weights[node] += weight;
+    }
+
+    pub fn add_end_node(&mut self, node: usize, weight: &Fraction) {
+        self.end_node_weights[node] += weight;
+    }
+
+    pub fn add_edge(&mut self, source: usize, target: usize, weight: &Fraction) {
+        self.edges[source][target] += weight;
+    }
+
+    pub fn get_node(&self, activity: Activity) -> Option<usize> {
+        self.activity_2_node.get(&activity).copied()
+    }
+
+    pub fn get_number_of_nodes(&self) -> usize {
+        self.node_2_activity.len()
+    }
+
+    pub fn get_number_of_edges(&self) -> usize {
+        self.edges.iter().fold(0usize, |a, b| {
+            a + b.iter().filter(|c| c.is_positive()).count()
+        })
+    }
+
+    pub fn get_number_of_start_nodes(&self) -> usize {
+        self.start_node_weights.iter().filter(|weight| weight.is_positive()).count()
+    }
+
+    pub fn get_number_of_end_nodes(&self) -> usize {
+        self.end_node_weights.iter().filter(|weight| weight.is_positive()).count()
+    }
+
+    /**
+     * Summarises a finite stochastic language: each start, end and directly-follows pair gets the sum probability of the traces in which it occurs, counted per occurrence.
+     */
+    pub fn from_finite_stochastic_language(language: &dyn EbiTraitFiniteStochasticLanguage) -> Self {
+        log::info!("convert finite stochastic language to stochastic directly follows model");
+        let mut result = Self::new(ActivityKey::new());
+        let translator = ActivityKeyTranslator::new(language.get_activity_key(), &mut result.activity_key);
+
+        for (trace, probability) in language.iter_trace_probability() {
+            let mut previous = None;
+            for activity in trace {
+                let node = result.get_or_add_node(translator.translate_activity(activity));
+                match previous {
+                    None => result.add_start_node(node, probability),
+                    Some(previous_node) => result.add_edge(previous_node, node, probability),
+                }
+                previous = Some(node);
+            }
+            match previous {
+                None => result.add_empty_trace(probability),
+                Some(node) => result.add_end_node(node, probability),
+            }
+        }
+
+        result
+    }
+
+    pub fn import_as_directly_follows_model(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::DirectlyFollowsModel(Self::import(reader)?.into()))
+    }
+
+    pub fn import_as_stochastic_deterministic_finite_automaton(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::StochasticDeterministicFiniteAutomaton(Self::import(reader)?.get_stochastic_deterministic_finite_automaton()?))
+    }
+
+    pub fn import_as_stochastic_labelled_petri_net(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::StochasticLabelledPetriNet(Self::import(reader)?.get_stochastic_deterministic_finite_automaton()?.get_stochastic_labelled_petri_net()))
+    }
+
+    pub fn import_as_labelled_petri_net(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        let slpn: StochasticLabelledPetriNet = Self::import(reader)?.get_stochastic_deterministic_finite_automaton()?.get_stochastic_labelled_petri_net();
+        Ok(EbiObject::LabelledPetriNet(LabelledPetriNet::from(slpn)))
+    }
+
+    /**
+     * Returns the sum weight of the ways to continue in the state of the semantics: before the first activity (nodes + 1) or after a node.
+     */
+    pub(crate) fn get_total_weight(&self, state: usize) -> Fraction {
+        if state == self.get_number_of_nodes() + 1 {
+            //initial state
+            let mut sum = self.empty_traces_weight.clone();
+            self.start_node_weights.iter().for_each(|weight| sum += weight);
+            sum
+        } else if state < self.get_number_of_nodes() {
+            let mut sum = self.end_node_weights[state].clone();
+            self.edges[state].iter().for_each(|weight| sum += weight);
+            sum
+        } else {
+            //final state
+            Fraction::zero()
+        }
+    }
+
+    /**
+     * State 0 of the automaton is the initial state; node n becomes state n + 1.
+     */
+    pub fn get_stochastic_deterministic_finite_automaton(&self) -> Result<StochasticDeterministicFiniteAutomaton> {
+        log::info!("convert stochastic directly follows model to sdfa");
+        let mut result = StochasticDeterministicFiniteAutomaton::new();
+        result.set_activity_key(&self.activity_key);
+
+        //starts
+        let total = self.get_total_weight(self.get_number_of_nodes() + 1);
+        for (node, weight) in self.start_node_weights.iter().enumerate() {
+            if weight.is_positive() {
+                result.add_transition(0, self.node_2_activity[node], node + 1, weight / &total)?;
+            }
+        }
+
+        //edges
+        for source in 0..self.get_number_of_nodes() {
+            let total = self.get_total_weight(source);
+            for (target, weight) in self.edges[source].iter().enumerate() {
+                if weight.is_positive() {
+                    result.add_transition(source + 1, self.node_2_activity[target], target + 1, weight / &total)?;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn read_weight(lreader: &mut LineReader<'_>, number_of_activities: usize, node: usize, element: impl Fn() -> String) -> Result<Fraction> {
+        if node >= number_of_activities {
+            return Err(anyhow!("{} at line {} is not an activity", element(), lreader.get_last_line_number()));
+        }
+        let weight = lreader.next_line_weight().with_context(|| format!("could not read the weight of {}", element()))?;
+        if weight.is_negative() {
+            return Err(anyhow!("the weight of {} at line {} is negative", element(), lreader.get_last_line_number()));
+        }
+        Ok(weight)
+    }
+}
+
+impl FromEbiTraitObject for StochasticDirectlyFollowsModel {
+    fn from_trait_object(object: EbiInput) -> Result<Box<Self>> {
+        match object {
+            EbiInput::Object(EbiObject::StochasticDirectlyFollowsModel(e), _) => Ok(Box::new(e)),
+            _ => Err(anyhow!("cannot read {} {} as a stochastic directly follows model", object.get_type().get_article(), object.get_type()))
+        }
+    }
+}
+
+impl Importable for StochasticDirectlyFollowsModel {
+    fn import_as_object(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::StochasticDirectlyFollowsModel(Self::import(reader)?))
+    }
+
+    fn import(reader: &mut dyn BufRead) -> Result<Self> where Self: Sized {
+        let mut lreader = LineReader::new(reader);
+
+        let head = lreader.next_line_string().with_context(|| format!("failed to read header, which should be {}", HEADER))?;
+        if head != HEADER {
+            return Err(anyhow!("first line should be exactly `{}`, but found `{}`", HEADER, lreader.get_last_line()));
+        }
+
+        let mut result = Self::new(ActivityKey::new());
+
+        //read empty traces
+        let empty_traces_weight = lreader.next_line_weight().context("could not read the weight of the empty trace")?;
+        if empty_traces_weight.is_negative() {
+            return Err(anyhow!("the weight of the empty trace is negative"));
+        }
+        result.add_empty_trace(&empty_traces_weight);
+
+        //read activities
+        let number_of_activities = lreader.next_line_index().context("could not read the number of activities")?;
+        for activity in 0 .. number_of_activities {
+            let label = lreader.next_line_string().with_context(|| format!("could not read activity {}", activity))?;
+            let activity = result.activity_key.process_activity(&label);
+            if result.get_node(activity).is_some() {
+                return Err(anyhow!("activity `{}` at line {} was already declared; each activity can appear only once", label, lreader.get_last_line_number()));
+            }
+            result.get_or_add_node(activity);
+        }
+
+        //read start activities
+        let number_of_start_activities = lreader.next_line_index().context("could not read the number of start activities")?;
+        for i in 0..number_of_start_activities {
+            let start_activity = lreader.next_line_index().with_context(|| format!("could not read start activity {}", i))?;
+            let weight = Self::read_weight(&mut lreader, number_of_activities, start_activity, || format!("start activity {}", i))?;
+            result.add_start_node(start_activity, &weight);
+        }
+
+        //read end activities
+        let number_of_end_activities = lreader.next_line_index().context("could not read the number of end activities")?;
+        for i in 0..number_of_end_activities {
+            let end_activity = lreader.next_line_index().with_context(|| format!("could not read end activity {}", i))?;
+            let weight = Self::read_weight(&mut lreader, number_of_activities, end_activity, || format!("end activity {}", i))?;
+            result.add_end_node(end_activity, &weight);
+        }
+
+        //read edges
+        let number_of_edges = lreader.next_line_index().context("could not read number of edges")?;
+        for e in 0..number_of_edges {
+            let edge_line = lreader.next_line_string().with_context(|| format!("could not read edge {}", e))?;
+
+            let mut arr = edge_line.split('>');
+            let source = match arr.next() {
+                Some(s) => s.parse::<usize>().with_context(|| format!("could not read source of edge {}", e))?,
+                None => return Err(anyhow!("could not read source of edge {}", e)),
+            };
+            let target = match arr.next() {
+                Some(t) => t.parse::<usize>().with_context(|| format!("could not read target of edge {}", e))?,
+                None => return Err(anyhow!("could not read target of edge {}", e)),
+            };
+            if source >= number_of_activities {
+                return Err(anyhow!("source of edge {} is not an activity", e));
+            }
+
+            let weight = Self::read_weight(&mut lreader, number_of_activities, target, || format!("edge {}", e))?;
+            result.add_edge(source, target, &weight);
+        }
+
+        Ok(result)
+    }
+}
+
+impl FromStr for StochasticDirectlyFollowsModel {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
+        let mut reader = io::Cursor::new(s);
+        Self::import(&mut reader)
+    }
+}
+
+impl ToSemantics for StochasticDirectlyFollowsModel {
+    fn to_semantics(self) -> EbiTraitSemantics {
+        EbiTraitSemantics::Usize(Box::new(self))
+    }
+}
+
+impl ToStochasticSemantics for StochasticDirectlyFollowsModel {
+    fn to_stochastic_semantics(self) -> EbiTraitStochasticSemantics {
+        EbiTraitStochasticSemantics::Usize(Box::new(self))
+    }
+}
+
+impl ToStochasticDeterministicSemantics for StochasticDirectlyFollowsModel {
+    fn to_stochastic_deterministic_semantics(self) -> EbiTraitStochasticDeterministicSemantics {
+        EbiTraitStochasticDeterministicSemantics::Usize(Box::new(self))
+    }
+}
+
+impl Display for StochasticDirectlyFollowsModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+
+        writeln!(f, "# empty trace weight\n{}", self.empty_traces_weight)?;
+
+        //activities
+        writeln!(f, "# number of activites\n{}", self.node_2_activity.len())?;
+        for (a, activity) in self.node_2_activity.iter().enumerate() {
+            writeln!(f, "# activity {}\n{}", a, self.activity_key.get_activity_label(activity))?;
+        }
+
+        //start activities
+        writeln!(f, "# number of start activites\n{}", self.get_number_of_start_nodes())?;
+        for (i, (node, weight)) in self.start_node_weights.iter().enumerate().filter(|(_, weight)| weight.is_positive()).enumerate() {
+            writeln!(f, "# start activity {}\n{}\n# weight\n{}", i, node, weight)?;
+        }
+
+        //end activities
+        writeln!(f, "# number of end activites\n{}", self.get_number_of_end_nodes())?;
+        for (i, (node, weight)) in self.end_node_weights.iter().enumerate().filter(|(_, weight)| weight.is_positive()).enumerate() {
+            writeln!(f, "# end activity {}\n{}\n# weight\n{}", i, node, weight)?;
+        }
+
+        //edges
+        writeln!(f, "# number of edges\n{}", self.get_number_of_edges())?;
+        let mut e = 0;
+        for source in 0..self.edges.len() {
+            for target in 0..self.edges.len() {
+                if self.edges[source][target].is_positive() {
+                    writeln!(f, "# edge {}\n{}>{}\n# weight\n{}", e, source, target, self.edges[source][target])?;
+                    e += 1;
+                }
+            }
+        }
+
+        Ok(write!(f, "")?)
+    }
+}
+
+impl EbiTraitGraphable for StochasticDirectlyFollowsModel {
+    fn to_dot(&self) -> layout::topo::layout::VisualGraph {
+        let mut graph = VisualGraph::new(layout::core::base::Orientation::LeftToRight);
+
+        //source + sink
+        let source = <dyn EbiTraitGraphable>::create_place(&mut graph, "");
+        let sink = <dyn EbiTraitGraphable>::create_place(&mut graph, "");
+
+        //empty traces
+        if self.empty_traces_weight.is_positive() {
+            <dyn EbiTraitGraphable>::create_edge(&mut graph, &source, &sink, &self.empty_traces_weight.to_string());
+        }
+
+        //nodes
+        let mut nodes = vec![];
+        for n in &self.node_2_activity {
+            nodes.push(<dyn EbiTraitGraphable>::create_transition(&mut graph, self.activity_key.get_activity_label(n), ""));
+        }
+
+        //start activities
+        for (node, weight) in self.start_node_weights.iter().enumerate() {
+            if weight.is_positive() {
+                <dyn EbiTraitGraphable>::create_edge(&mut graph, &source, &nodes[node], &weight.to_string());
+            }
+        }
+
+        //end activities
+        for (node, weight) in self.end_node_weights.iter().enumerate() {
+            if weight.is_positive() {
+                <dyn EbiTraitGraphable>::create_edge(&mut graph, &nodes[node], &sink, &weight.to_string());
+            }
+        }
+
+        //edges
+        for source in 0..self.edges.len() {
+            for target in 0..self.edges.len() {
+                if self.edges[source][target].is_positive() {
+                    <dyn EbiTraitGraphable>::create_edge(&mut graph, &nodes[source], &nodes[target], &self.edges[source][target].to_string());
+                }
+            }
+        }
+
+        return graph;
+    }
+}
+
+impl Exportable for StochasticDirectlyFollowsModel {
+    fn export_from_object(object: EbiOutput, f: &mut dyn Write) -> Result<()> {
+        match object {
+            EbiOutput::Object(EbiObject::StochasticDirectlyFollowsModel(sdfm)) => Self::export(&sdfm, f),
+            _ => unreachable!()
+        }
+    }
+
+    fn export(&self, f: &mut dyn std::io::Write) -> Result<()> {
+        Ok(write!(f, "{}", self.to_string())?)
+    }
+}
+
+impl Infoable for StochasticDirectlyFollowsModel {
+    fn info(&self, f: &mut impl std::io::Write) -> Result<()> {
+        writeln!(f, "Number of activities\t{}", self.node_2_activity.len())?;
+        writeln!(f, "Number of start activities\t{}", self.get_number_of_start_nodes())?;
+        writeln!(f, "Number of end activities\t{}", self.get_number_of_end_nodes())?;
+        writeln!(f, "Number of edges\t\t{}", self.get_number_of_edges())?;
+
+        Ok(write!(f, "")?)
+    }
+}
+
+impl From<StochasticDirectlyFollowsModel> for DirectlyFollowsModel {
+    fn from(value: StochasticDirectlyFollowsModel) -> Self {
+        let edges = value.edges.iter().map(|row| row.iter().map(|weight| weight.is_positive()).collect()).collect();
+        let start_nodes = value.start_node_weights.iter().enumerate().filter_map(|(node, weight)| if weight.is_positive() {Some(node)} else {None}).collect();
+        let end_nodes = value.end_node_weights.iter().enumerate().filter_map(|(node, weight)| if weight.is_positive() {Some(node)} else {None}).collect();
+        DirectlyFollowsModel::new(value.activity_key, value.empty_traces_weight.is_positive(), edges, value.node_2_activity, start_nodes, end_nodes)
+    }
+}
@@ -0,0 +1,126 @@
+use anyhow::{anyhow, Result};
+
+use crate::{ebi_framework::activity_key::Activity, ebi_traits::{ebi_trait_semantics::Semantics, ebi_trait_stochastic_deterministic_semantics::StochasticDeterministicSemantics, ebi_trait_stochastic_semantics::{StochasticSemantics, TransitionIndex}}, math::fraction::Fraction};
+
+use super::stochastic_directly_follows_model::StochasticDirectlyFollowsModel;
+
+impl Semantics for StochasticDirectlyFollowsModel {
+    /**
+     * Map of states:
+     * 0..nodes:        after executing the activity, we end up in this state.
+     * nodes:           end
+     * nodes + 1:       start
+     *
+     * Map of transitions:
+     * 0..nodes:        the activity
+     * nodes:           terminate
+     */
+    type SemState = usize;
+
+    fn get_initial_state(&self) -> usize {
+        self.get_number_of_nodes() + 1
+    }
+
+    fn execute_transition(&self, state: &mut usize, transition: TransitionIndex) -> Result<()> {
+        *state = transition;
+        Ok(())
+    }
+
+    fn is_final_state(&self, state: &usize) -> bool {
+        state == &self.get_number_of_nodes()
+    }
+
+    fn is_transition_silent(&self, transition: TransitionIndex) -> bool {
+        transition == self.get_number_of_nodes()
+    }
+
+    fn get_transition_activity(&self, transition: TransitionIndex) -> Option<Activity> {
+        self.node_2_activity.get(transition).copied()
+    }
+
+    fn get_enabled_transitions(&self, state: &usize) -> Vec<TransitionIndex> {
+        if self.is_final_state(state) {
+            return vec![];
+        }
+
+        //only transitions with a positive weight are enabled
+        let mut result = (0..self.get_number_of_nodes()).filter(|transition| self.get_transition_weight(state, *transition).is_positive()).collect::<Vec<_>>();
+        if self.get_transition_weight(state, self.get_number_of_nodes()).is_positive() {
+            result.push(self.get_number_of_nodes());
+        }
+        result
+    }
+
+    fn get_number_of_transitions(&self) -> usize {
+        self.get_number_of_nodes() + 1
+    }
+}
+
+impl StochasticSemantics for StochasticDirectlyFollowsModel {
+    type StoSemState = usize;
+
+    fn get_transition_weight(&self, state: &usize, transition: TransitionIndex) -> &Fraction {
+        if *state == self.get_initial_state() {
+            //start or empty trace
+            self.start_node_weights.get(transition).unwrap_or(&self.empty_traces_weight)
+        } else {
+            //edge or end
+            self.edges[*state].get(transition).unwrap_or(&self.end_node_weights[*state])
+        }
+    }
+
+    fn get_total_weight_of_enabled_transitions(&self, state: &usize) -> Result<Fraction> {
+        let sum = self.get_total_weight(*state);
+        if sum.is_zero() {
+            return Err(anyhow!("total enabled weight is 0"));
+        }
+        Ok(sum)
+    }
+}
+
+impl StochasticDeterministicSemantics for StochasticDirectlyFollowsModel {
+    type DetState = usize;
+
+    fn get_deterministic_initial_state(&self) -> Result<usize> {
+        Ok(self.get_initial_state())
+    }
+
+    fn execute_deterministic_activity(&self, state: &usize, activity: Activity) -> Result<usize> {
+        match self.get_node(activity) {
+            Some(node) if !self.is_final_state(state) && self.get_transition_weight(state, node).is_positive() => Ok(node),
+            _ => Err(anyhow!("activity not enabled"))
+        }
+    }
+
+    fn get_deterministic_termination_probability(&self, state: &usize) -> Fraction {
+        if self.is_final_state(state) {
+            return Fraction::zero();
+        }
+        let sum = self.get_total_weight(*state);
+        if sum.is_zero() {
+            return Fraction::zero();
+        }
+        self.get_transition_weight(state, self.get_number_of_nodes()) / &sum
+    }
+
+    fn get_deterministic_silent_livelock_probability(&self, _state: &usize) -> Fraction {
+        Fraction::zero()
+    }
+
+    fn get_deterministic_activity_probability(&self, state: &usize, activity: Activity) -> Fraction {
+        match self.get_node(activity) {
+            Some(node) if !self.is_final_state(state) => {
+                let sum = self.get_total_weight(*state);
+                if sum.is_zero() {
+                    return Fraction::zero();
+                }
+                self.get_transition_weight(state, node) / &sum
+            },
+            _ => Fraction::zero()
+        }
+    }
+
+    fn get_deterministic_enabled_activities(&self, state: &usize) -> Vec<Activity> {
+        self.get_enabled_transitions(state).into_iter().filter_map(|transition| self.get_transition_activity(transition)).collect()
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::io;
 use std::str::FromStr;
 use std::{fmt, io::BufRead};
@@ -6,9 +7,10 @@ use layout::topo::layout::VisualGraph;
 
 use crate::ebi_framework::activity_key::{Activity, ActivityKey, HasActivityKey};
 use crate::ebi_framework::ebi_file_handler::EbiFileHandler;
-use crate::ebi_framework::ebi_input::{self, EbiObjectImporter, EbiTraitImporter};
+use crate::ebi_framework::ebi_input::{self, EbiInput, EbiObjectImporter, EbiTraitImporter};
 use crate::ebi_framework::ebi_object::EbiObject;
 use crate::ebi_framework::ebi_output::{EbiObjectExporter, EbiOutput};
+use crate::ebi_framework::ebi_trait::FromEbiTraitObject;
 use crate::ebi_framework::exportable::Exportable;
 use crate::ebi_framework::importable::Importable;
 use crate::ebi_framework::infoable::Infoable;
@@ -16,16 +18,23 @@ use crate::ebi_framework::prom_link::JavaObjectHandler;
 use crate::ebi_traits::ebi_trait_graphable::{self, EbiTraitGraphable};
 use crate::ebi_traits::ebi_trait_queriable_stochastic_language;
 use crate::ebi_traits::ebi_trait_semantics::{EbiTraitSemantics, Semantics, ToSemantics};
-use crate::ebi_traits::ebi_trait_stochastic_deterministic_semantics::{EbiTraitStochasticDeterministicSemantics, ToStochasticDeterministicSemantics};
+use crate::ebi_traits::ebi_trait_stochastic_deterministic_semantics::{EbiTraitStochasticDeterministicSemantics, StochasticDeterministicSemantics, ToStochasticDeterministicSemantics};
 use crate::ebi_traits::ebi_trait_stochastic_semantics::{EbiTraitStochasticSemantics, ToStochasticSemantics, TransitionIndex};
 use crate::line_reader::LineReader;
 use crate::marking::Marking;
 use crate::math::fraction::Fraction;
+use crate::techniques::coverability::Coverability;
 
 use super::labelled_petri_net::LabelledPetriNet;
+use super::stochastic_deterministic_finite_automaton::StochasticDeterministicFiniteAutomaton;
 
 pub const HEADER: &str = "stochastic labelled Petri net";
 
+/**
+ * The default maximum number of states when converting a stochastic labelled Petri net into a stochastic deterministic finite automaton.
+ */
+pub const DEFAULT_MAX_SDFA_STATES: usize = 100000;
+
 pub const FORMAT_SPECIFICATION: &str = "A stochastic labelled Petri net is a line-based structure. Lines starting with a \\# are ignored.
     This first line is exactly `stochastic labelled Petri net'.
     The second line is the number of places in the net.
@@ -129,6 +138,65 @@ impl StochasticLabelledPetriNet {
             0
         }
     }
+
+    /**
+     * Explores the deterministic stochastic semantics of this net into a finite automaton.
+     * The net must be bounded, the automaton may have at most max_states states, and the net may not have silent livelocks, as these cannot be represented in an SDFA.
+     */
+    pub fn get_stochastic_deterministic_finite_automaton(&self, max_states: usize) -> Result<StochasticDeterministicFiniteAutomaton> {
+        log::info!("convert SLPN to SDFA");
+
+        self.verify_bounded()?;
+
+        let mut result = StochasticDeterministicFiniteAutomaton::new();
+        result.set_activity_key(self.get_activity_key());
+
+        let initial_state = self.get_deterministic_initial_state()?;
+        let mut state2index = HashMap::new();
+        state2index.insert(initial_state.clone(), result.get_initial_state());
+        let mut queue = vec![initial_state];
+
+        while let Some(state) = queue.pop() {
+            if self.get_deterministic_silent_livelock_probability(&state).is_positive() {
+                return Err(anyhow!("The stochastic labelled Petri net has a silent livelock, which cannot be represented in a stochastic deterministic finite automaton."));
+            }
+
+            let source = *state2index.get(&state).ok_or_else(|| anyhow!("State not found."))?;
+            let mut activities = self.get_deterministic_enabled_activities(&state);
+            activities.sort();
+
+            for activity in activities {
+                let probability = self.get_deterministic_activity_probability(&state, activity);
+                let new_state = self.execute_deterministic_activity(&state, activity)?;
+
+                let target = match state2index.get(&new_state) {
+                    Some(target) => *target,
+                    None => {
+                        if state2index.len() >= max_states {
+                            return Err(anyhow!("The stochastic deterministic finite automaton would have more than {} states. The net may have infinitely many distinct probability distributions over its markings.", max_states));
+                        }
+                        let target = result.add_state();
+                        state2index.insert(new_state.clone(), target);
+                        queue.push(new_state);
+                        target
+                    }
+                };
+
+                result.add_transition(source, activity, target, probability)?;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl FromEbiTraitObject for StochasticLabelledPetriNet {
+    fn from_trait_object(object: EbiInput) -> Result<Box<Self>> {
+        match object {
+            EbiInput::Object(EbiObject::StochasticLabelledPetriNet(e), _) => Ok(Box::new(e)),
+            _ => Err(anyhow!("cannot read {} {} as a stochastic labelled Petri net", object.get_type().get_article(), object.get_type()))
+        }
+    }
 }
 
 impl Exportable for StochasticLabelledPetriNet {
@@ -0,0 +1,601 @@
+use std::{fmt::Display, io::{self, BufRead}, str::FromStr};
+
+use anyhow::{anyhow, Context, Error, Result};
+use layout::topo::layout::VisualGraph;
+
+use crate::{ebi_framework::{activity_key::{Activity, ActivityKey, ActivityKeyTranslator, HasActivityKey}, ebi_file_handler::EbiFileHandler, ebi_input::{self, EbiInput, EbiObjectImporter, EbiTraitImporter}, ebi_object::EbiObject, ebi_output::{EbiObjectExporter, EbiOutput}, ebi_trait::FromEbiTraitObject, exportable::Exportable, importable::Importable, infoable::Infoable}, ebi_traits::{ebi_trait_graphable::{self, EbiTraitGraphable}, ebi_trait_queriable_stochastic_language, ebi_trait_semantics::{EbiTraitSemantics, ToSemantics}, ebi_trait_stochastic_deterministic_semantics::{EbiTraitStochasticDeterministicSemantics, ToStochasticDeterministicSemantics}, ebi_trait_stochastic_semantics::{EbiTraitStochasticSemantics, ToStochasticSemantics, TransitionIndex}}, line_reader::LineReader, math::fraction::Fraction};
+
+use super::{labelled_petri_net::LabelledPetriNet, process_tree::{Node, Operator, ProcessTree}, stochastic_labelled_petri_net::StochasticLabelledPetriNet};
+
+pub const HEADER: &str = "stochastic process tree";
+
+pub const FORMAT_SPECIFICATION: &str = "A stochastic process tree is a line-based structure. Lines starting with a \\# are ignored.
+    This first line is exactly `stochastic process tree'.
+    The subsequent lines contain the nodes, as in a process tree, with weights added to the operators that make a choice.
+    Right after its number of children, such an operator has a line for each of its options, with the weight of that option:
+    \\begin{itemize}
+        \\item A `xor' has a weight for each child. Weights are non-negative, and at least one of them must be positive;
+        \\item A `loop' has a weight for exiting the loop, followed by a weight for each redo child. Weights are non-negative, and at least one of them must be positive;
+        \\item An `or' has a probability between 0 and 1 for each child that the child is executed. At least one of these probabilities must be positive.
+    \\end{itemize}
+    Weights may be any fraction or decimal number.
+
+    A xor chooses a child, and a loop chooses between exiting and its redo children after each execution of its body, with a probability proportional to the weight of the option.
+    An or executes a non-empty set of children $S$ with a probability proportional to $\\prod_{i \\in S} p_i \\prod_{i \\notin S} (1 - p_i)$.
+    An or can have at most 20 children.
+    A loop with a single child executes its body once, as in the semantics of process trees.
+
+    For instance:
+    \\lstinputlisting[language=ebilines, style=boxed]{../testfiles/all_operators.sptree}";
+
+pub const EBI_STOCHASTIC_PROCESS_TREE: EbiFileHandler = EbiFileHandler {
+    name: "stochastic process tree",
+    article: "a",
+    file_extension: "sptree",
+    format_specification: &FORMAT_SPECIFICATION,
+    validator: ebi_input::validate::<StochasticProcessTree>,
+    trait_importers: &[
+        EbiTraitImporter::QueriableStochasticLanguage(ebi_trait_queriable_stochastic_language::import::<StochasticProcessTree>),
+        EbiTraitImporter::StochasticDeterministicSemantics(StochasticProcessTree::import_as_stochastic_deterministic_semantics),
+        EbiTraitImporter::StochasticSemantics(StochasticProcessTree::import_as_stochastic_semantics),
+        EbiTraitImporter::Semantics(StochasticProcessTree::import_as_semantics),
+        EbiTraitImporter::Graphable(ebi_trait_graphable::import::<StochasticProcessTree>),
+    ],
+    object_importers: &[
+        EbiObjectImporter::StochasticProcessTree(StochasticProcessTree::import_as_object),
+        EbiObjectImporter::ProcessTree(StochasticProcessTree::import_as_process_tree),
+        EbiObjectImporter::StochasticLabelledPetriNet(StochasticProcessTree::import_as_stochastic_labelled_petri_net),
+        EbiObjectImporter::LabelledPetriNet(StochasticProcessTree::import_as_labelled_petri_net),
+    ],
+    object_exporters: &[
+        EbiObjectExporter::StochasticProcessTree(StochasticProcessTree::export_from_object),
+    ],
+    java_object_handlers: &[],
+};
+
+/**
+ * The maximum number of children of an or: each non-empty set of children is a separate option.
+ */
+pub const MAX_OR_CHILDREN: usize = 20;
+
+/**
+ * A process tree with weights on the options of its choices: the children of xors, the exits and redo children of loops, and the children of ors.
+ * 
+ * The semantics has a transition for each leaf, then a silent transition for each option of each choice, and finally the termination transition.
+ * The weights of the options of a choice are normalised, such that in each state, every choice that can be made contributes a total weight of 1.
+ */
+#[derive(Debug,Clone)]
+pub struct StochasticProcessTree {
+    pub(crate) tree: ProcessTree,
+    pub(crate) weights: Vec<Vec<Fraction>>, //for each node, the weights of its options as given
+    pub(crate) decisions: Vec<Decision>,
+    pub(crate) transition2weight: Vec<Fraction>,
+}
+
+/**
+ * An option of a choice in a stochastic process tree.
+ */
+#[derive(Debug,Clone)]
+pub(crate) enum Decision {
+    Xor { node: usize, child: usize },
+    LoopExit { node: usize },
+    LoopRedo { node: usize, child: usize },
+    OrSubset { node: usize, children: Vec<usize> },
+}
+
+impl Decision {
+    pub(crate) fn get_node(&self) -> usize {
+        match self {
+            Decision::Xor { node, .. } | Decision::LoopExit { node } | Decision::LoopRedo { node, .. } | Decision::OrSubset { node, .. } => *node,
+        }
+    }
+}
+
+impl StochasticProcessTree {
+
+    /**
+     * Create a stochastic process tree, with for each node the weights of its options, in the order of the nodes.
+     * Leaves, sequences, concurrent and interleaved nodes have no options.
+     */
+    pub fn new(tree: ProcessTree, weights: Vec<Vec<Fraction>>) -> Result<Self> {
+        if weights.len() != tree.get_number_of_nodes() {
+            return Err(anyhow!("The process tree has {} nodes, but weights were given for {} nodes.", tree.get_number_of_nodes(), weights.len()));
+        }
+
+        let mut decisions = vec![];
+        let mut transition2weight = vec![Fraction::one(); tree.transition2node.len()];
+        for (node, node_weights) in weights.iter().enumerate() {
+            let number_of_options = match tree.tree[node] {
+                Node::Operator(Operator::Xor, number_of_children) | Node::Operator(Operator::Loop, number_of_children) | Node::Operator(Operator::Or, number_of_children) => number_of_children,
+                _ => 0,
+            };
+            if node_weights.len() != number_of_options {
+                return Err(anyhow!("Node {} has {} options, but {} weights were given.", node, number_of_options, node_weights.len()));
+            }
+            if let Some(weight) = node_weights.iter().find(|weight| weight.is_negative()) {
+                return Err(anyhow!("Node {} has a negative weight {}.", node, weight));
+            }
+
+            match tree.tree[node] {
+                Node::Operator(Operator::Xor, _) | Node::Operator(Operator::Loop, _) => {
+                    let sum = node_weights.iter().sum::<Fraction>();
+                    if !sum.is_positive() {
+                        return Err(anyhow!("The weights of node {} should have a positive sum.", node));
+                    }
+
+                    for (rank, weight) in node_weights.iter().enumerate() {
+                        decisions.push(match tree.tree[node] {
+                            Node::Operator(Operator::Xor, _) => Decision::Xor { node: node, child: tree.get_child(node, rank) },
+                            _ if rank == 0 => Decision::LoopExit { node: node },
+                            _ => Decision::LoopRedo { node: node, child: tree.get_child(node, rank) },
+                        });
+                        transition2weight.push(weight / &sum);
+                    }
+                },
+                Node::Operator(Operator::Or, number_of_children) => {
+                    if number_of_children > MAX_OR_CHILDREN {
+                        return Err(anyhow!("Or node {} has {} children, while at most {} are supported.", node, number_of_children, MAX_OR_CHILDREN));
+                    }
+                    if let Some(probability) = node_weights.iter().find(|probability| probability > &&Fraction::one()) {
+                        return Err(anyhow!("Or node {} has a probability {} that is larger than 1.", node, probability));
+                    }
+                    if !node_weights.iter().any(|probability| probability.is_positive()) {
+                        return Err(anyhow!("Or node {} should have at least one positive probability.", node));
+                    }
+
+                    //each non-empty set of children is an option
+                    let mut subset_weights = vec![];
+                    for subset in 1..(1usize << number_of_children) {
+                        let mut weight = Fraction::one();
+                        for (rank, probability) in node_weights.iter().enumerate() {
+                            if subset & (1 << rank) != 0 {
+                                weight *= probability;
+                            } else {
+                                weight *= probability.clone().one_minus();
+                            }
+                        }
+                        decisions.push(Decision::OrSubset { node: node, children: (0..number_of_children).filter(|rank| subset & (1 << rank) != 0).map(|rank| tree.get_child(node, rank)).collect() });
+                        subset_weights.push(weight);
+                    }
+                    let sum = subset_weights.iter().sum::<Fraction>();
+                    transition2weight.extend(subset_weights.iter().map(|weight| weight / &sum));
+                },
+                _ => {}
+            }
+        }
+
+        //termination
+        transition2weight.push(Fraction::one());
+
+        Ok(Self {
+            tree: tree,
+            weights: weights,
+            decisions: decisions,
+            transition2weight: transition2weight,
+        })
+    }
+
+    /**
+     * Returns the weights of the options of a node that all options equally likely.
+     */
+    pub fn get_uniform_weights(tree: &ProcessTree, node: usize) -> Vec<Fraction> {
+        match tree.tree[node] {
+            Node::Operator(Operator::Xor, number_of_children) | Node::Operator(Operator::Loop, number_of_children) => vec![Fraction::one(); number_of_children],
+            Node::Operator(Operator::Or, number_of_children) => vec![Fraction::from((1, 2)); number_of_children],
+            _ => vec![],
+        }
+    }
+
+    pub fn import_as_process_tree(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::ProcessTree(Self::import(reader)?.tree))
+    }
+
+    pub fn import_as_stochastic_labelled_petri_net(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::StochasticLabelledPetriNet(Self::import(reader)?.get_stochastic_labelled_petri_net()?))
+    }
+
+    pub fn import_as_labelled_petri_net(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::LabelledPetriNet(Self::import(reader)?.tree.get_labelled_petri_net()))
+    }
+
+    pub fn get_process_tree(&self) -> &ProcessTree {
+        &self.tree
+    }
+
+    /**
+     * Returns the weights of the options of the node, as given.
+     */
+    pub fn get_weights_of_node(&self, node: usize) -> Option<&Vec<Fraction>> {
+        self.weights.get(node)
+    }
+
+    pub(crate) fn get_decision(&self, transition: TransitionIndex) -> Option<&Decision> {
+        self.decisions.get(transition.checked_sub(self.tree.transition2node.len())?)
+    }
+
+    /**
+     * Returns the transitions of the options of the node, in the order of its weights (for an or: in the order of the sets of children).
+     */
+    pub(crate) fn get_decision_transitions(&self, node: usize) -> Vec<TransitionIndex> {
+        self.decisions.iter().enumerate().filter(|(_, decision)| decision.get_node() == node).map(|(index, _)| self.tree.transition2node.len() + index).collect()
+    }
+
+    /**
+     * Translates the tree into a stochastic labelled Petri net with the same distribution.
+     * The split and join transitions of concurrent, interleaved and or nodes are silent transitions that are not in the tree.
+     * If such a node is nested in another such node, these transitions would race with the other concurrent transitions, which would change the distribution. Then, an error is returned.
+     */
+    pub fn get_stochastic_labelled_petri_net(&self) -> Result<StochasticLabelledPetriNet> {
+        for node in 0..self.tree.get_number_of_nodes() {
+            if let Some(operator) = Self::get_concurrent_operator(&self.tree.tree[node]) {
+                for (parent, _) in self.tree.get_parents(node) {
+                    if let Some(parent_operator) = Self::get_concurrent_operator(&self.tree.tree[parent]) {
+                        return Err(anyhow!("The {} node {} is nested in the {} node {}. A stochastic labelled Petri net cannot express this without changing the distribution, as its silent transitions would race with the concurrent transitions.", operator.to_string(), node, parent_operator.to_string(), parent));
+                    }
+                }
+            }
+        }
+
+        let mut net = LabelledPetriNet::new();
+        let mut weights = vec![];
+        let translator = ActivityKeyTranslator::new(self.tree.get_activity_key(), net.get_activity_key_mut());
+        let source = net.add_place();
+        net.get_initial_marking_mut().increase(source, 1)?;
+        if self.tree.get_number_of_nodes() > 0 {
+            let sink = net.add_place();
+            self.node_to_slpn(self.tree.get_root(), &mut net, &mut weights, &translator, source, sink)?;
+        }
+
+        Ok((net, weights).into())
+    }
+
+    fn get_concurrent_operator(node: &Node) -> Option<&Operator> {
+        match node {
+            Node::Operator(operator @ Operator::Concurrent, _) | Node::Operator(operator @ Operator::Interleaved, _) | Node::Operator(operator @ Operator::Or, _) => Some(operator),
+            _ => None,
+        }
+    }
+
+    fn add_transition(net: &mut LabelledPetriNet, weights: &mut Vec<Fraction>, label: Option<Activity>, weight: Fraction) -> TransitionIndex {
+        weights.push(weight);
+        net.add_transition(label)
+    }
+
+    fn node_to_slpn(&self, node: usize, net: &mut LabelledPetriNet, weights: &mut Vec<Fraction>, translator: &ActivityKeyTranslator, source: usize, sink: usize) -> Result<usize> {
+        match self.tree.tree[node] {
+            Node::Tau => {
+                let transition = Self::add_transition(net, weights, None, Fraction::one());
+                net.add_place_transition_arc(source, transition, 1)?;
+                net.add_transition_place_arc(transition, sink, 1)?;
+                Ok(node + 1)
+            },
+            Node::Activity(activity) => {
+                let transition = Self::add_transition(net, weights, Some(translator.translate_activity(&activity)), Fraction::one());
+                net.add_place_transition_arc(source, transition, 1)?;
+                net.add_transition_place_arc(transition, sink, 1)?;
+                Ok(node + 1)
+            },
+            Node::Operator(Operator::Concurrent, number_of_children) => {
+                let split = Self::add_transition(net, weights, None, Fraction::one());
+                net.add_place_transition_arc(source, split, 1)?;
+                let join = Self::add_transition(net, weights, None, Fraction::one());
+                net.add_transition_place_arc(join, sink, 1)?;
+
+                let mut child = node + 1;
+                for _ in 0..number_of_children {
+                    let child_source = net.add_place();
+                    net.add_transition_place_arc(split, child_source, 1)?;
+                    let child_sink = net.add_place();
+                    net.add_place_transition_arc(child_sink, join, 1)?;
+                    child = self.node_to_slpn(child, net, weights, translator, child_source, child_sink)?;
+                }
+                Ok(child)
+            },
+            Node::Operator(Operator::Interleaved, number_of_children) => {
+                let split = Self::add_transition(net, weights, None, Fraction::one());
+                net.add_place_transition_arc(source, split, 1)?;
+                let join = Self::add_transition(net, weights, None, Fraction::one());
+                net.add_transition_place_arc(join, sink, 1)?;
+                let milestone = net.add_place();
+                net.add_transition_place_arc(split, milestone, 1)?;
+                net.add_place_transition_arc(milestone, join, 1)?;
+
+                let mut child = node + 1;
+                for _ in 0..number_of_children {
+                    let child_source = net.add_place();
+                    net.add_transition_place_arc(split, child_source, 1)?;
+
+                    let child_start = Self::add_transition(net, weights, None, Fraction::one());
+                    net.add_place_transition_arc(child_source, child_start, 1)?;
+                    net.add_place_transition_arc(milestone, child_start, 1)?;
+
+                    let child_source_2 = net.add_place();
+                    net.add_transition_place_arc(child_start, child_source_2, 1)?;
+
+                    let child_sink = net.add_place();
+                    net.add_place_transition_arc(child_sink, join, 1)?;
+
+                    let child_stop = Self::add_transition(net, weights, None, Fraction::one());
+                    net.add_transition_place_arc(child_stop, child_sink, 1)?;
+                    net.add_transition_place_arc(child_stop, milestone, 1)?;
+
+                    let child_sink_2 = net.add_place();
+                    net.add_place_transition_arc(child_sink_2, child_stop, 1)?;
+
+                    child = self.node_to_slpn(child, net, weights, translator, child_source_2, child_sink_2)?;
+                }
+                Ok(child)
+            },
+            Node::Operator(Operator::Loop, _) => {
+                //the body starts in the source; after the body, a decision is made to exit or to redo
+                let body_sink = net.add_place();
+                let mut child = self.node_to_slpn(node + 1, net, weights, translator, source, body_sink)?;
+
+                for transition in self.get_decision_transitions(node) {
+                    let decision = Self::add_transition(net, weights, None, self.transition2weight[transition].clone());
+                    net.add_place_transition_arc(body_sink, decision, 1)?;
+                    match self.get_decision(transition) {
+                        Some(Decision::LoopExit { .. }) => {
+                            net.add_transition_place_arc(decision, sink, 1)?;
+                        },
+                        Some(Decision::LoopRedo { .. }) => {
+                            //after the redo child, the body is executed again
+                            let redo_source = net.add_place();
+                            net.add_transition_place_arc(decision, redo_source, 1)?;
+                            child = self.node_to_slpn(child, net, weights, translator, redo_source, source)?;
+                        },
+                        _ => unreachable!(),
+                    }
+                }
+
+                Ok(child)
+            },
+            Node::Operator(Operator::Or, number_of_children) => {
+                //each child has its own source and sink
+                let mut child_sources = vec![];
+                let mut child_sinks = vec![];
+                let mut child = node + 1;
+                for _ in 0..number_of_children {
+                    let child_source = net.add_place();
+                    let child_sink = net.add_place();
+                    child_sources.push(child_source);
+                    child_sinks.push(child_sink);
+                    child = self.node_to_slpn(child, net, weights, translator, child_source, child_sink)?;
+                }
+
+                //each set of children is chosen by a decision, and has its own join
+                for transition in self.get_decision_transitions(node) {
+                    if let Some(Decision::OrSubset { children, .. }) = self.get_decision(transition) {
+                        let decision = Self::add_transition(net, weights, None, self.transition2weight[transition].clone());
+                        net.add_place_transition_arc(source, decision, 1)?;
+                        let chosen = net.add_place();
+                        net.add_transition_place_arc(decision, chosen, 1)?;
+                        let join = Self::add_transition(net, weights, None, Fraction::one());
+                        net.add_place_transition_arc(chosen, join, 1)?;
+                        net.add_transition_place_arc(join, sink, 1)?;
+
+                        for (rank, or_child) in self.tree.get_children(node).enumerate() {
+                            if children.contains(&or_child) {
+                                net.add_transition_place_arc(decision, child_sources[rank], 1)?;
+                                net.add_place_transition_arc(child_sinks[rank], join, 1)?;
+                            }
+                        }
+                    }
+                }
+
+                Ok(child)
+            },
+            Node::Operator(Operator::Sequence, number_of_children) => {
+                let intermediate_nodes = (0..(number_of_children-1)).map(|_| net.add_place()).collect::<Vec<_>>();
+
+                let mut child = node + 1;
+                for i in 0..number_of_children {
+                    let child_entry = if i == 0 { source } else { intermediate_nodes[i-1] };
+                    let child_exit = if i == number_of_children - 1 { sink } else { intermediate_nodes[i] };
+
+                    child = self.node_to_slpn(child, net, weights, translator, child_entry, child_exit)?;
+                }
+                Ok(child)
+            },
+            Node::Operator(Operator::Xor, _) => {
+                //each child gets its own source, such that the decision is made before the child starts
+                let mut child = node + 1;
+                for transition in self.get_decision_transitions(node) {
+                    let decision = Self::add_transition(net, weights, None, self.transition2weight[transition].clone());
+                    net.add_place_transition_arc(source, decision, 1)?;
+                    let child_source = net.add_place();
+                    net.add_transition_place_arc(decision, child_source, 1)?;
+                    child = self.node_to_slpn(child, net, weights, translator, child_source, sink)?;
+                }
+                Ok(child)
+            }
+        }
+    }
+
+    fn node_to_string(&self, indent: usize, node: usize, f: &mut std::fmt::Formatter<'_>) -> Result<usize> {
+        let id = "\t".repeat(indent);
+        match self.tree.get_node(node).ok_or_else(|| anyhow!("node does not exist"))? {
+            Node::Tau => {
+                writeln!(f, "{}tau", id)?;
+                Ok(node + 1)
+            },
+            Node::Activity(activity) => {
+                writeln!(f, "{}activity {}", id, self.tree.get_activity_key().get_activity_label(&activity))?;
+                Ok(node + 1)
+            },
+            Node::Operator(operator, number_of_children) => {
+                writeln!(f, "{}{}", id, operator.to_string())?;
+                writeln!(f, "{}{}", id, number_of_children)?;
+                for weight in &self.weights[node] {
+                    writeln!(f, "{}{}", id, weight)?;
+                }
+                let mut child = node + 1;
+                for _ in 0..*number_of_children {
+                    child = self.node_to_string(indent + 1, child, f)?;
+                }
+                Ok(child)
+            },
+        }
+    }
+
+    ///read one node, recursively
+    fn string_to_tree(lreader: &mut LineReader<'_>, tree: &mut Vec<Node>, weights: &mut Vec<Vec<Fraction>>, activity_key: &mut ActivityKey) -> Result<()> {
+        let node_type_line = lreader.next_line_string().with_context(|| format!("failed to read node {} at line {}", tree.len(), lreader.get_last_line_number()))?;
+
+        if node_type_line.trim_start().starts_with("tau") {
+            tree.push(Node::Tau);
+            weights.push(vec![]);
+        } else if node_type_line.trim_start().starts_with("activity ") {
+            let label = node_type_line.trim_start()[9..].to_string();
+            let activity = activity_key.process_activity(&label);
+            tree.push(Node::Activity(activity));
+            weights.push(vec![]);
+        } else if let Ok(operator) = node_type_line.trim_start().trim_end().parse::<Operator>() {
+            let number_of_children = lreader.next_line_index().with_context(|| format!("failed to read number of children for node {} at line {}", tree.len(), lreader.get_last_line_number()))?;
+            if number_of_children < 1 {
+                return Err(anyhow!("{} node {} at line {} has no children", operator.to_string(), tree.len(), lreader.get_last_line_number()));
+            }
+            let mut node_weights = vec![];
+            if let Operator::Xor | Operator::Loop | Operator::Or = operator {
+                for option in 0..number_of_children {
+                    node_weights.push(lreader.next_line_weight().with_context(|| format!("failed to read weight {} of node {} at line {}", option, tree.len(), lreader.get_last_line_number()))?);
+                }
+            }
+            tree.push(Node::Operator(operator, number_of_children));
+            weights.push(node_weights);
+            for _ in 0..number_of_children {
+                Self::string_to_tree(lreader, tree, weights, activity_key)?;
+            }
+        } else {
+            return Err(anyhow!("Could not parse type of node {} at line {}. Expected `tau`, `activity`, `concurrent`, `interleaved`, `loop`, `or`, `sequence` or `xor`.", tree.len(), lreader.get_last_line_number()));
+        }
+
+        Ok(())
+    }
+}
+
+impl HasActivityKey for StochasticProcessTree {
+    fn get_activity_key(&self) -> &ActivityKey {
+        self.tree.get_activity_key()
+    }
+
+    fn get_activity_key_mut(&mut self) -> &mut ActivityKey {
+        self.tree.get_activity_key_mut()
+    }
+}
+
+impl FromEbiTraitObject for StochasticProcessTree {
+    fn from_trait_object(object: EbiInput) -> Result<Box<Self>> {
+        match object {
+            EbiInput::Object(EbiObject::StochasticProcessTree(e), _) => Ok(Box::new(e)),
+            _ => Err(anyhow!("cannot read {} {} as a stochastic process tree", object.get_type().get_article(), object.get_type()))
+        }
+    }
+}
+
+impl Importable for StochasticProcessTree {
+    fn import_as_object(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::StochasticProcessTree(Self::import(reader)?))
+    }
+
+    fn import(reader: &mut dyn BufRead) -> Result<Self> where Self: Sized {
+        let mut lreader = LineReader::new(reader);
+
+        let head = lreader.next_line_string().with_context(|| format!("failed to read header, which should be {}", HEADER))?;
+        if head != HEADER {
+            return Err(anyhow!("first line should be exactly `{}`, but found `{}` on line `{}`", HEADER, lreader.get_last_line(), lreader.get_last_line_number()));
+        }
+
+        let mut activity_key = ActivityKey::new();
+        let mut tree = vec![];
+        let mut weights = vec![];
+        Self::string_to_tree(&mut lreader, &mut tree, &mut weights, &mut activity_key)?;
+
+        Self::new(ProcessTree::new(activity_key, tree), weights)
+    }
+}
+
+impl FromStr for StochasticProcessTree {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
+        let mut reader = io::Cursor::new(s);
+        Self::import(&mut reader)
+    }
+}
+
+impl Exportable for StochasticProcessTree {
+    fn export_from_object(object: EbiOutput, f: &mut dyn std::io::Write) -> Result<()> {
+        match object {
+            EbiOutput::Object(EbiObject::StochasticProcessTree(tree)) => tree.export(f),
+            _ => unreachable!()
+        }
+    }
+
+    fn export(&self, f: &mut dyn std::io::Write) -> Result<()> {
+        Ok(write!(f, "{}", self)?)
+    }
+}
+
+impl Display for StochasticProcessTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+        match self.node_to_string(0, 0, f) {
+            Ok(_) => Ok(()),
+            Err(_) => write!(f, ""),
+        }
+    }
+}
+
+impl Infoable for StochasticProcessTree {
+    fn info(&self, f: &mut impl std::io::Write) -> Result<()> {
+        writeln!(f, "Number of nodes\t\t{}", self.tree.get_number_of_nodes())?;
+        writeln!(f, "Number of activities\t\t{}", self.get_activity_key().get_number_of_activities())?;
+
+        Ok(write!(f, "")?)
+    }
+}
+
+impl EbiTraitGraphable for StochasticProcessTree {
+    fn to_dot(&self) -> VisualGraph {
+        self.tree.to_dot()
+    }
+}
+
+impl ToSemantics for StochasticProcessTree {
+    fn to_semantics(self) -> EbiTraitSemantics {
+        EbiTraitSemantics::NodeStates(Box::new(self))
+    }
+}
+
+impl ToStochasticSemantics for StochasticProcessTree {
+    fn to_stochastic_semantics(self) -> EbiTraitStochasticSemantics {
+        EbiTraitStochasticSemantics::NodeStates(Box::new(self))
+    }
+}
+
+impl ToStochasticDeterministicSemantics for StochasticProcessTree {
+    fn to_stochastic_deterministic_semantics(self) -> EbiTraitStochasticDeterministicSemantics {
+        EbiTraitStochasticDeterministicSemantics::NodeStates(Box::new(self))
+    }
+}
+
+impl From<StochasticProcessTree> for ProcessTree {
+    fn from(value: StochasticProcessTree) -> Self {
+        value.tree
+    }
+}
+
+impl TryFrom<StochasticProcessTree> for StochasticLabelledPetriNet {
+    type Error = Error;
+
+    fn try_from(value: StochasticProcessTree) -> std::result::Result<Self, Self::Error> {
+        value.get_stochastic_labelled_petri_net()
+    }
+}
+
+impl From<StochasticProcessTree> for LabelledPetriNet {
+    fn from(value: StochasticProcessTree) -> Self {
+        value.tree.get_labelled_petri_net()
+    }
+}
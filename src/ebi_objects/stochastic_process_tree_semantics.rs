@@ -0,0 +1,138 @@
+use anyhow::{anyhow, Result};
+
+use crate::{ebi_framework::activity_key::Activity, ebi_traits::{ebi_trait_semantics::Semantics, ebi_trait_stochastic_semantics::{StochasticSemantics, TransitionIndex}}, math::fraction::Fraction};
+
+use super::{process_tree::{Node, Operator}, process_tree_semantics::{NodeState, NodeStates}, stochastic_process_tree::{Decision, StochasticProcessTree}};
+
+impl Semantics for StochasticProcessTree {
+    type SemState = NodeStates;
+
+    fn get_initial_state(&self) -> NodeStates {
+        self.tree.get_initial_state()
+    }
+
+    fn execute_transition(&self, state: &mut NodeStates, transition: TransitionIndex) -> Result<()> {
+        let before = state.clone();
+        let number_of_leaves = self.tree.transition2node.len();
+
+        if transition < number_of_leaves {
+            self.tree.execute_transition(state, transition)?;
+        } else if let Some(decision) = self.get_decision(transition) {
+            match decision {
+                Decision::Xor { node, child } => self.tree.start_node(state, *node, Some(*child)),
+                Decision::LoopExit { node } => self.tree.close_node(state, *node),
+                Decision::LoopRedo { child, .. } => self.tree.enable_node(state, *child),
+                Decision::OrSubset { node, children } => {
+                    for child in self.tree.get_children(*node) {
+                        if !children.contains(&child) {
+                            self.tree.withdraw_enablement(state, child);
+                        }
+                    }
+                    self.tree.start_node(state, *node, None);
+                },
+            }
+        } else if transition == self.get_number_of_transitions() - 1 {
+            //termination
+            self.tree.execute_transition(state, number_of_leaves)?;
+        } else {
+            return Err(anyhow!("Transition does not exist."));
+        }
+
+        //a loop of which the body just finished waits for the decision to exit or to redo, so its redo children are not enabled yet
+        for node in 0..self.tree.get_number_of_nodes() {
+            if let Node::Operator(Operator::Loop, _) = self.tree.tree[node] {
+                let body = self.tree.get_child(node, 0);
+                if before[body] != NodeState::Closed && state[body] == NodeState::Closed && state[node] != NodeState::Closed {
+                    for redo in self.tree.get_children(node).skip(1) {
+                        self.tree.withdraw_enablement(state, redo);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_final_state(&self, state: &NodeStates) -> bool {
+        self.tree.is_final_state(state)
+    }
+
+    fn is_transition_silent(&self, transition: TransitionIndex) -> bool {
+        //decisions and the termination are silent
+        transition >= self.tree.transition2node.len() || self.tree.is_transition_silent(transition)
+    }
+
+    fn get_transition_activity(&self, transition: TransitionIndex) -> Option<Activity> {
+        if transition < self.tree.transition2node.len() {
+            self.tree.get_transition_activity(transition)
+        } else {
+            None
+        }
+    }
+
+    fn get_enabled_transitions(&self, state: &NodeStates) -> Vec<TransitionIndex> {
+        let mut result = vec![];
+        if self.is_final_state(state) {
+            return result;
+        }
+
+        //leaves can only be executed once the choices above them have been made
+        for (transition, node) in self.tree.transition2node.iter().enumerate() {
+            if self.tree.can_execute(state, *node) && self.is_decided(state, *node) {
+                result.push(transition);
+            }
+        }
+
+        //decisions without a positive weight are never made
+        for (index, decision) in self.decisions.iter().enumerate() {
+            let transition = self.tree.transition2node.len() + index;
+            if self.transition2weight[transition].is_positive() && match decision {
+                Decision::Xor { node, .. } | Decision::OrSubset { node, .. } => self.tree.can_execute(state, *node) && self.is_decided(state, *node),
+                Decision::LoopExit { node } | Decision::LoopRedo { node, .. } => state[*node] == NodeState::Started && self.tree.get_children(*node).all(|child| state[child] == NodeState::Closed),
+            } {
+                result.push(transition);
+            }
+        }
+
+        if state[self.tree.get_root()] == NodeState::Closed {
+            result.push(self.get_number_of_transitions() - 1);
+        }
+
+        result
+    }
+
+    fn get_number_of_transitions(&self) -> usize {
+        self.transition2weight.len()
+    }
+}
+
+impl StochasticSemantics for StochasticProcessTree {
+    type StoSemState = NodeStates;
+
+    fn get_transition_weight(&self, _state: &NodeStates, transition: TransitionIndex) -> &Fraction {
+        &self.transition2weight[transition]
+    }
+
+    fn get_total_weight_of_enabled_transitions(&self, state: &NodeStates) -> Result<Fraction> {
+        let mut sum = Fraction::zero();
+        for transition in self.get_enabled_transitions(state) {
+            sum += self.get_transition_weight(state, transition);
+        }
+        if sum.is_zero() {
+            return Err(anyhow!("total enabled weight is 0"));
+        }
+        Ok(sum)
+    }
+}
+
+impl StochasticProcessTree {
+    /**
+     * Returns whether all xor and or nodes above the node have made their choice.
+     */
+    fn is_decided(&self, state: &NodeStates, node: usize) -> bool {
+        self.tree.get_parents(node).all(|(parent, _)| match self.tree.tree[parent] {
+            Node::Operator(Operator::Xor, _) | Node::Operator(Operator::Or, _) => state[parent] == NodeState::Started,
+            _ => true,
+        })
+    }
+}
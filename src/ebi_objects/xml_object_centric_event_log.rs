@@ -0,0 +1,49 @@
+use std::io::BufRead;
+use anyhow::{anyhow, Result};
+
+use crate::ebi_framework::{ebi_file_handler::EbiFileHandler, ebi_input::{self, EbiObjectImporter}, ebi_object::EbiObject, importable::Importable};
+
+use super::object_centric_event_log::ObjectCentricEventLog;
+
+pub const FORMAT_SPECIFICATION: &str = "An XML object-centric event log file follows the XML variant of the OCEL 2.0 format~\\cite{DBLP:journals/corr/abs-2403-01975}.
+Parsing is performed by the Rust4PM crate~\\cite{DBLP:conf/bpm/KustersA24}.
+As for object-centric event logs in JSON, the log can be used as an event log only after flattening it on an object type, using \\texttt{Ebi convert flatten}.";
+
+pub const EBI_XML_OBJECT_CENTRIC_EVENT_LOG: EbiFileHandler = EbiFileHandler {
+    name: "XML object-centric event log",
+    article: "an",
+    file_extension: "xmlocel",
+    format_specification: &FORMAT_SPECIFICATION,
+    validator: ebi_input::validate::<XmlObjectCentricEventLog>,
+    trait_importers: &[],
+    object_importers: &[
+        EbiObjectImporter::ObjectCentricEventLog(XmlObjectCentricEventLog::import_as_object),
+    ],
+    object_exporters: &[],
+    java_object_handlers: &[],
+};
+
+pub struct XmlObjectCentricEventLog {
+    log: ObjectCentricEventLog
+}
+
+impl Importable for XmlObjectCentricEventLog {
+    fn import_as_object(reader: &mut dyn BufRead) -> Result<EbiObject> {
+        Ok(EbiObject::ObjectCentricEventLog(Self::import(reader)?.log))
+    }
+
+    fn import(reader: &mut dyn BufRead) -> Result<Self> where Self: Sized {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes)?;
+
+        //only hand XML documents to the parser
+        if !String::from_utf8_lossy(&bytes).trim_start().starts_with('<') {
+            return Err(anyhow!("file is not an XML document"));
+        }
+
+        let ocel = process_mining::import_ocel_xml_slice(&bytes);
+        Ok(Self {
+            log: ObjectCentricEventLog::new(ocel)?
+        })
+    }
+}
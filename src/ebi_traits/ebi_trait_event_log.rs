@@ -3,11 +3,11 @@ use anyhow::{anyhow, Result};
 use chrono::{DateTime, FixedOffset};
 use process_mining::event_log::{AttributeValue, Event, XESEditableAttribute};
 
-use crate::{ebi_framework::{activity_key::{Activity, ActivityKey}, ebi_input::EbiInput, ebi_object::EbiTraitObject, ebi_trait::FromEbiTraitObject}, ebi_objects::event_log::DataType, math::fraction::Fraction};
+use crate::{ebi_framework::{activity_key::{Activity, ActivityKey, HasActivityKey}, ebi_input::EbiInput, ebi_object::EbiTraitObject, ebi_trait::FromEbiTraitObject}, ebi_objects::event_log::DataType, math::fraction::Fraction};
 
 pub const ATTRIBUTE_TIME: &str = "time:timestamp";
 
-pub trait EbiTraitEventLog: IndexTrace {
+pub trait EbiTraitEventLog: IndexTrace + HasActivityKey {
     /**
      * Provides access to the underlying Rust4pm data structure. Prefer to use other methods if possible.
      */
@@ -2,11 +2,12 @@ use std::io::BufRead;
 
 use anyhow::{anyhow, Result};
 
-use crate::{ebi_framework::{activity_key::{Activity, HasActivityKey}, displayable::Displayable, ebi_input::EbiInput, ebi_object::EbiTraitObject, ebi_trait::FromEbiTraitObject, importable::Importable}, ebi_objects::labelled_petri_net::LPNMarking, math::fraction::Fraction, techniques::{deterministic_semantics_for_stochastic_semantics::PMarking, livelocks::Livelock}};
+use crate::{ebi_framework::{activity_key::{Activity, HasActivityKey}, displayable::Displayable, ebi_input::EbiInput, ebi_object::EbiTraitObject, ebi_trait::FromEbiTraitObject, importable::Importable}, ebi_objects::{labelled_petri_net::LPNMarking, process_tree_semantics::NodeStates}, math::fraction::Fraction, techniques::{deterministic_semantics_for_stochastic_semantics::PMarking, livelocks::Livelock}};
 
 pub enum EbiTraitStochasticDeterministicSemantics {
 	Usize(Box<dyn StochasticDeterministicSemantics<DetState = usize, LivState = usize>>),
-    PMarking(Box<dyn StochasticDeterministicSemantics<DetState = PMarking<LPNMarking>, LivState = PMarking<LPNMarking>>>)
+    PMarking(Box<dyn StochasticDeterministicSemantics<DetState = PMarking<LPNMarking>, LivState = PMarking<LPNMarking>>>),
+    NodeStates(Box<dyn StochasticDeterministicSemantics<DetState = PMarking<NodeStates>, LivState = PMarking<NodeStates>>>),
 }
 
 impl FromEbiTraitObject for EbiTraitStochasticDeterministicSemantics {
@@ -1,13 +1,14 @@
 use std::io::BufRead;
 use anyhow::{anyhow, Result};
 
-use crate::{ebi_framework::{activity_key::ActivityKey, ebi_input::EbiInput, ebi_object::EbiTraitObject, ebi_trait::FromEbiTraitObject, importable::Importable}, ebi_objects::labelled_petri_net::LPNMarking, math::fraction::Fraction};
+use crate::{ebi_framework::{activity_key::ActivityKey, ebi_input::EbiInput, ebi_object::EbiTraitObject, ebi_trait::FromEbiTraitObject, importable::Importable}, ebi_objects::{labelled_petri_net::LPNMarking, process_tree_semantics::NodeStates}, math::fraction::Fraction};
 
 use super::ebi_trait_semantics::Semantics;
 
 pub enum EbiTraitStochasticSemantics {
 	Usize(Box<dyn StochasticSemantics<StoSemState = usize, SemState = usize, AliState = usize>>),
 	Marking(Box<dyn StochasticSemantics<StoSemState = LPNMarking, SemState = LPNMarking, AliState = LPNMarking>>),
+	NodeStates(Box<dyn StochasticSemantics<StoSemState = NodeStates, SemState = NodeStates, AliState = NodeStates>>),
 }
 
 impl FromEbiTraitObject for EbiTraitStochasticSemantics {
@@ -24,6 +25,7 @@ impl EbiTraitStochasticSemantics {
 		match self {
 			EbiTraitStochasticSemantics::Marking(sem) => sem.get_activity_key(),
 			EbiTraitStochasticSemantics::Usize(sem) => sem.get_activity_key(),
+			EbiTraitStochasticSemantics::NodeStates(sem) => sem.get_activity_key(),
 		}
 	}
 
@@ -31,6 +33,7 @@ impl EbiTraitStochasticSemantics {
 		match self {
 			EbiTraitStochasticSemantics::Marking(sem) => sem.get_activity_key_mut(),
 			EbiTraitStochasticSemantics::Usize(sem) => sem.get_activity_key_mut(),
+			EbiTraitStochasticSemantics::NodeStates(sem) => sem.get_activity_key_mut(),
 		}
 	}
 }
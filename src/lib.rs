@@ -17,19 +17,24 @@ pub mod ebi_commands {
     pub mod ebi_command_analyse;
     pub mod ebi_command_analyse_non_stochastic;
     pub mod ebi_command_association;
+    pub mod ebi_command_compare;
     pub mod ebi_command_conformance;
     pub mod ebi_command_convert;
     pub mod ebi_command_discover;
     pub mod ebi_command_info;
     pub mod ebi_command_itself;
     pub mod ebi_command_probability;
+    pub mod ebi_command_reduce;
     pub mod ebi_command_sample;
     pub mod ebi_command_test;
     pub mod ebi_command_validate;
     pub mod ebi_command_visualise;
 }
 pub mod ebi_objects {
+    pub mod business_process_model_and_notation;
     pub mod compressed_event_log;
+    pub mod csv_event_log;
+    pub mod csv_executions;
     pub mod deterministic_finite_automaton;
     pub mod directly_follows_model;
     pub mod directly_follows_model_semantics;
@@ -41,14 +46,22 @@ pub mod ebi_objects {
     pub mod labelled_petri_net;
     pub mod labelled_petri_net_semantics;
     pub mod language_of_alignments;
+    pub mod object_centric_event_log;
     pub mod petri_net_markup_language;
+    pub mod pm4py_process_tree;
     pub mod process_tree;
+    pub mod process_tree_markup_language;
     pub mod process_tree_semantics;
     pub mod stochastic_deterministic_finite_automaton;
     pub mod stochastic_deterministic_finite_automaton_semantics;
+    pub mod stochastic_directly_follows_model;
+    pub mod stochastic_directly_follows_model_semantics;
     pub mod stochastic_labelled_petri_net;
     pub mod stochastic_labelled_petri_net_semantics;
     pub mod stochastic_language_of_alignments;
+    pub mod stochastic_process_tree;
+    pub mod stochastic_process_tree_semantics;
+    pub mod xml_object_centric_event_log;
 }
 pub mod ebi_traits {
     pub mod ebi_trait_event_log;
@@ -75,24 +88,39 @@ pub mod math {
     pub mod matrix;
     pub mod root;
     pub mod root_log_div;
+    pub mod trace_distance;
 }
 pub mod techniques {
+    pub mod alergia_miner;
     pub mod align;
     pub mod alignment_stochastic_miner;
+    pub mod alpha_miner;
     pub mod association;
     pub mod completeness;
+    pub mod coverability;
     pub mod deterministic_semantics_for_stochastic_semantics;
+    pub mod determinisation;
+    pub mod directly_follows_miner;
     pub mod earth_movers_stochastic_conformance;
     pub mod entropic_relevance;
+    pub mod estimator_stochastic_miner;
     pub mod executions;
+    pub mod executions_stochastic_miner;
     pub mod explain_trace;
+    pub mod inductive_miner;
+    pub mod invariants;
     pub mod jensen_shannon_stochastic_conformance;
+    pub mod language_comparison;
     pub mod livelocks;
+    pub mod maximum_likelihood_stochastic_miner;
     pub mod medoid_non_stochastic;
     pub mod occurrences_stochastic_miner;
     pub mod probability_queries;
     pub mod process_variety;
+    pub mod reduction;
     pub mod sample;
+    pub mod soundness;
+    pub mod split_miner;
     pub mod statistical_test;
     pub mod trace_probability;
     pub mod uniform_stochastic_miner;
@@ -101,6 +129,7 @@ pub mod techniques {
 pub mod optimization_algorithms {
     pub mod network_simplex;
     pub mod network_simplex_value_type;
+    pub mod simplex;
 }
 pub mod distances;
 pub mod follower_semantics;
@@ -111,6 +140,7 @@ pub mod medoid;
 pub mod multiple_reader;
 pub mod tests;
 pub mod text;
+pub mod xml;
 
 #[macro_use]
 extern crate derive_activity_key;
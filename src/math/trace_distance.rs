@@ -0,0 +1,189 @@
+use std::{collections::{HashMap, HashSet}, fmt::Display};
+use anyhow::{anyhow, Result};
+
+use crate::ebi_framework::activity_key::{Activity, ActivityKey};
+
+use super::{fraction::Fraction, levenshtein};
+
+pub const TRACE_DISTANCE_NAMES: &[&str] = &["levenshtein", "normalised-levenshtein", "weighted-edit", "damerau-levenshtein", "jaccard", "multiset-jaccard", "zero-one"];
+
+/**
+ * A ground distance between two traces.
+ * Except for Levenshtein, all distances are between 0 and 1.
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub enum TraceDistance {
+    /**
+     * The minimum number of insertions, deletions and substitutions of activities to transform one trace into the other.
+     */
+    Levenshtein,
+
+    /**
+     * The Levenshtein distance, divided by the length of the longest trace.
+     */
+    NormalisedLevenshtein,
+
+    /**
+     * An edit distance in which inserting or deleting an activity costs the cost of that activity, and substituting an activity for another costs the highest of their costs.
+     * Activities without a cost cost 1.
+     * The distance is divided by the highest total cost of the two traces, and capped at 1.
+     * If all activities cost 1, this is the normalised Levenshtein distance.
+     */
+    WeightedEdit(HashMap<String, Fraction>),
+
+    /**
+     * The Levenshtein distance in which swapping two adjacent activities is an edit as well, divided by the length of the longest trace.
+     */
+    DamerauLevenshtein,
+
+    /**
+     * One minus the number of activities that occur in both traces, divided by the number of activities that occur in either trace.
+     */
+    JaccardSet,
+
+    /**
+     * One minus the summed minimum multiplicity of the activities, divided by the summed maximum multiplicity of the activities.
+     */
+    JaccardMultiset,
+
+    /**
+     * Zero for equal traces and one otherwise.
+     * With this distance, Earth Mover's stochastic conformance equals one minus the total variation distance.
+     */
+    ZeroOne,
+}
+
+impl TraceDistance {
+    pub fn new(name: &str, activity_costs: HashMap<String, Fraction>) -> Result<Self> {
+        if !activity_costs.is_empty() && name != "weighted-edit" {
+            return Err(anyhow!("activity costs can only be used with the weighted-edit distance"));
+        }
+        Ok(match name {
+            "levenshtein" => Self::Levenshtein,
+            "normalised-levenshtein" => Self::NormalisedLevenshtein,
+            "weighted-edit" => Self::WeightedEdit(activity_costs),
+            "damerau-levenshtein" => Self::DamerauLevenshtein,
+            "jaccard" => Self::JaccardSet,
+            "multiset-jaccard" => Self::JaccardMultiset,
+            "zero-one" => Self::ZeroOne,
+            _ => return Err(anyhow!("unknown distance `{}`; the available distances are {}", name, TRACE_DISTANCE_NAMES.join(", "))),
+        })
+    }
+
+    /**
+     * Returns whether all distances are between 0 and 1.
+     */
+    pub fn is_normalised(&self) -> bool {
+        !matches!(self, Self::Levenshtein)
+    }
+
+    /**
+     * The activity key is used to look up the costs of the weighted edit distance.
+     */
+    pub fn distance(&self, activity_key: &ActivityKey, trace1: &Vec<Activity>, trace2: &Vec<Activity>) -> Fraction {
+        match self {
+            Self::Levenshtein => Fraction::from(levenshtein::distance(trace1, trace2)),
+            Self::NormalisedLevenshtein => {
+                if trace1.is_empty() && trace2.is_empty() {
+                    Fraction::zero()
+                } else {
+                    levenshtein::normalised(trace1, trace2)
+                }
+            },
+            Self::WeightedEdit(activity_costs) => Self::weighted_edit(activity_costs, activity_key, trace1, trace2),
+            Self::DamerauLevenshtein => {
+                if trace1.is_empty() && trace2.is_empty() {
+                    Fraction::zero()
+                } else {
+                    Fraction::from((strsim::generic_damerau_levenshtein(trace1, trace2), trace1.len().max(trace2.len())))
+                }
+            },
+            Self::JaccardSet => {
+                let set1 = trace1.iter().collect::<HashSet<_>>();
+                let set2 = trace2.iter().collect::<HashSet<_>>();
+                let union = set1.union(&set2).count();
+                if union == 0 {
+                    return Fraction::zero();
+                }
+                Fraction::from((union - set1.intersection(&set2).count(), union))
+            },
+            Self::JaccardMultiset => {
+                let mut multiplicities: HashMap<&Activity, (usize, usize)> = HashMap::new();
+                for activity in trace1 {
+                    multiplicities.entry(activity).or_default().0 += 1;
+                }
+                for activity in trace2 {
+                    multiplicities.entry(activity).or_default().1 += 1;
+                }
+                let minimum = multiplicities.values().map(|(a, b)| a.min(b)).sum::<usize>();
+                let maximum = multiplicities.values().map(|(a, b)| a.max(b)).sum::<usize>();
+                if maximum == 0 {
+                    return Fraction::zero();
+                }
+                Fraction::from((maximum - minimum, maximum))
+            },
+            Self::ZeroOne => {
+                if trace1 == trace2 {
+                    Fraction::zero()
+                } else {
+                    Fraction::one()
+                }
+            },
+        }
+    }
+
+    fn weighted_edit(activity_costs: &HashMap<String, Fraction>, activity_key: &ActivityKey, trace1: &[Activity], trace2: &[Activity]) -> Fraction {
+        let cost = |activity: &Activity| activity_costs.get(activity_key.get_activity_label(activity)).cloned().unwrap_or_else(Fraction::one);
+        let costs1 = trace1.iter().map(cost).collect::<Vec<_>>();
+        let costs2 = trace2.iter().map(cost).collect::<Vec<_>>();
+
+        let normaliser = costs1.iter().sum::<Fraction>().max(costs2.iter().sum::<Fraction>());
+        if !normaliser.is_positive() {
+            return Fraction::zero();
+        }
+
+        //the first row: insert the prefixes of the second trace
+        let mut previous = vec![Fraction::zero()];
+        for cost2 in &costs2 {
+            let next = previous.last().unwrap() + cost2;
+            previous.push(next);
+        }
+
+        for (activity1, cost1) in trace1.iter().zip(costs1.iter()) {
+            let mut current = vec![&previous[0] + cost1];
+            for (j, (activity2, cost2)) in trace2.iter().zip(costs2.iter()).enumerate() {
+                let substitution = if activity1 == activity2 {
+                    previous[j].clone()
+                } else {
+                    &previous[j] + cost1.max(cost2)
+                };
+                let deletion = &previous[j + 1] + cost1;
+                let insertion = &current[j] + cost2;
+                current.push(substitution.min(deletion).min(insertion));
+            }
+            previous = current;
+        }
+
+        (&previous[trace2.len()] / &normaliser).min(Fraction::one())
+    }
+}
+
+impl Default for TraceDistance {
+    fn default() -> Self {
+        Self::NormalisedLevenshtein
+    }
+}
+
+impl Display for TraceDistance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Levenshtein => write!(f, "Levenshtein"),
+            Self::NormalisedLevenshtein => write!(f, "normalised Levenshtein"),
+            Self::WeightedEdit(_) => write!(f, "weighted edit"),
+            Self::DamerauLevenshtein => write!(f, "normalised Damerau-Levenshtein"),
+            Self::JaccardSet => write!(f, "Jaccard"),
+            Self::JaccardMultiset => write!(f, "multiset Jaccard"),
+            Self::ZeroOne => write!(f, "zero-one"),
+        }
+    }
+}
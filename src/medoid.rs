@@ -1,17 +1,17 @@
 use anyhow::{anyhow, Result};
 use fraction::One;
 
-use crate::{distances::TriangularDistanceMatrix, ebi_objects::finite_language::FiniteLanguage, ebi_traits::ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, math::fraction::Fraction};
+use crate::{distances::TriangularDistanceMatrix, ebi_objects::finite_language::FiniteLanguage, ebi_traits::ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, math::{fraction::Fraction, trace_distance::TraceDistance}};
 
 
-pub fn medoid<T>(log: &T, number_of_traces: &usize) -> Result<FiniteLanguage> where T: EbiTraitFiniteStochasticLanguage + ?Sized {
+pub fn medoid<T>(log: &T, number_of_traces: &usize, distance: &TraceDistance) -> Result<FiniteLanguage> where T: EbiTraitFiniteStochasticLanguage + ?Sized {
 
     let activity_key = log.get_activity_key().clone();
     let mut result = FiniteLanguage::new_hashmap();
 
     log::info!("Computing {} medoid traces", number_of_traces);
 
-    let distances = TriangularDistanceMatrix::new(log);
+    let distances = TriangularDistanceMatrix::new(log, log.get_activity_key(), distance);
 
     if number_of_traces.is_one() {
         let trace_number = medoid_single(log, &distances);
@@ -0,0 +1,112 @@
+use anyhow::{anyhow, Result};
+
+const EPSILON: f64 = 1e-9;
+
+/**
+ * Solves the linear program
+ *      maximise objective * x
+ *      subject to constraints * x <= bounds
+ *                 x >= 0
+ * using the primal simplex method on a dense tableau, starting from the all-slack basis.
+ * As the origin must be feasible, all bounds must be non-negative. Bland's rule is used to avoid cycling.
+ *
+ * Returns the optimal value and an optimal solution, or an error if the program is unbounded.
+ */
+pub fn maximise(objective: &Vec<f64>, constraints: &Vec<Vec<f64>>, bounds: &Vec<f64>) -> Result<(f64, Vec<f64>)> {
+    let number_of_variables = objective.len();
+    let number_of_constraints = constraints.len();
+    let width = number_of_variables + number_of_constraints + 1;
+    let rhs = width - 1;
+
+    if bounds.len() != number_of_constraints {
+        return Err(anyhow!("{} constraints were given, but {} bounds", number_of_constraints, bounds.len()));
+    }
+
+    //build the tableau: the constraints, with a slack variable for each of them
+    let mut tableau = Vec::with_capacity(number_of_constraints + 1);
+    for (row, (constraint, bound)) in constraints.iter().zip(bounds.iter()).enumerate() {
+        if constraint.len() != number_of_variables {
+            return Err(anyhow!("constraint {} has {} coefficients, but there are {} variables", row, constraint.len(), number_of_variables));
+        }
+        if *bound < 0.0 {
+            return Err(anyhow!("constraint {} has a negative bound, so the origin is not feasible", row));
+        }
+        let mut line = vec![0.0; width];
+        line[..number_of_variables].copy_from_slice(constraint);
+        line[number_of_variables + row] = 1.0;
+        line[rhs] = *bound;
+        tableau.push(line);
+    }
+
+    //the last row holds the reduced costs
+    let mut costs = vec![0.0; width];
+    for (variable, coefficient) in objective.iter().enumerate() {
+        costs[variable] = -coefficient;
+    }
+    tableau.push(costs);
+    let cost_row = number_of_constraints;
+
+    let mut basis = (number_of_variables..number_of_variables + number_of_constraints).collect::<Vec<_>>();
+
+    loop {
+        //select the entering column: the first one with a negative reduced cost (Bland's rule)
+        let entering = match (0..rhs).find(|column| tableau[cost_row][*column] < -EPSILON) {
+            Some(column) => column,
+            None => break,
+        };
+
+        //select the leaving row by the minimum ratio test; ties are broken on the smallest basic variable
+        let mut leaving: Option<(usize, f64)> = None;
+        for row in 0..number_of_constraints {
+            let coefficient = tableau[row][entering];
+            if coefficient > EPSILON {
+                let ratio = tableau[row][rhs] / coefficient;
+                leaving = match leaving {
+                    None => Some((row, ratio)),
+                    Some((best_row, best_ratio)) => {
+                        if ratio < best_ratio - EPSILON || (ratio < best_ratio + EPSILON && basis[row] < basis[best_row]) {
+                            Some((row, ratio))
+                        } else {
+                            Some((best_row, best_ratio))
+                        }
+                    }
+                };
+            }
+        }
+        let leaving = match leaving {
+            Some((row, _)) => row,
+            None => return Err(anyhow!("the linear program is unbounded")),
+        };
+
+        pivot(&mut tableau, leaving, entering);
+        basis[leaving] = entering;
+    }
+
+    let mut solution = vec![0.0; number_of_variables];
+    for (row, variable) in basis.iter().enumerate() {
+        if *variable < number_of_variables {
+            solution[*variable] = tableau[row][rhs].max(0.0);
+        }
+    }
+
+    Ok((tableau[cost_row][rhs], solution))
+}
+
+fn pivot(tableau: &mut Vec<Vec<f64>>, pivot_row: usize, pivot_column: usize) {
+    let divisor = tableau[pivot_row][pivot_column];
+    tableau[pivot_row].iter_mut().for_each(|value| *value /= divisor);
+    tableau[pivot_row][pivot_column] = 1.0;
+
+    let pivot_line = tableau[pivot_row].clone();
+    for (row, line) in tableau.iter_mut().enumerate() {
+        if row != pivot_row {
+            let factor = line[pivot_column];
+            if factor.abs() > EPSILON {
+                for (value, pivot_value) in line.iter_mut().zip(pivot_line.iter()) {
+                    *value -= factor * pivot_value;
+                }
+            }
+            line[pivot_column] = 0.0;
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+
+use num_traits::ToPrimitive;
+
+use anyhow::{anyhow, Result};
+
+use crate::{ebi_framework::activity_key::{Activity, HasActivityKey}, ebi_objects::stochastic_deterministic_finite_automaton::StochasticDeterministicFiniteAutomaton, ebi_traits::ebi_trait_event_log::EbiTraitEventLog, math::fraction::Fraction};
+
+pub trait AlergiaMiner {
+    /**
+     * Discovers a stochastic deterministic finite automaton using ALERGIA.
+     * Starting from the prefix tree of the log, states are merged if their outgoing frequencies are not significantly different according to a Hoeffding bound with the given confidence parameter (alpha).
+     * A lower alpha merges more states.
+     */
+    fn mine_alergia(&self, alpha: &Fraction) -> Result<StochasticDeterministicFiniteAutomaton>;
+}
+
+impl AlergiaMiner for dyn EbiTraitEventLog {
+    fn mine_alergia(&self, alpha: &Fraction) -> Result<StochasticDeterministicFiniteAutomaton> {
+        let alpha_f64 = match alpha {
+            Fraction::Exact(fraction) => fraction.to_f64(),
+            Fraction::Approx(value) => Some(*value),
+            Fraction::CannotCombineExactAndApprox => None,
+        };
+        let alpha_f64 = match alpha_f64 {
+            Some(alpha_f64) if alpha_f64 > 0.0 && alpha_f64 <= 1.0 => alpha_f64,
+            _ => return Err(anyhow!("alpha {} should be larger than 0 and at most 1", alpha)),
+        };
+
+        let mut result = StochasticDeterministicFiniteAutomaton::new();
+
+        //create the frequency prefix tree
+        log::info!("create prefix tree");
+        let mut tree = FrequencyAutomaton::new();
+        for trace_index in 0..self.len() {
+            let trace = self.read_trace_with_activity_key(result.get_activity_key_mut(), &trace_index);
+            tree.add_trace(&trace);
+        }
+
+        //merge states
+        log::info!("merge states");
+        let bound = (0.5 * (2.0 / alpha_f64).ln()).sqrt();
+        let red = tree.merge(bound);
+
+        //create the automaton
+        let mut red_2_state = BTreeMap::new();
+        for state in &red {
+            let sdfa_state = if *state == 0 { result.get_initial_state() } else { result.add_state() };
+            red_2_state.insert(*state, sdfa_state);
+        }
+        for state in &red {
+            let total = Fraction::from(tree.get_total(*state));
+            for (activity, (target, count)) in &tree.transitions[*state] {
+                result.add_transition(red_2_state[state], *activity, red_2_state[target], &Fraction::from(*count) / &total)?;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+struct FrequencyAutomaton {
+    transitions: Vec<BTreeMap<Activity, (usize, u64)>>, //state -> activity -> (target, frequency)
+    ends: Vec<u64>, //state -> number of traces that end in the state
+    depths: Vec<usize>, //state -> length of the shortest prefix that reaches the state in the prefix tree
+    parents: Vec<Option<(usize, Activity)>>, //state -> parent in the prefix tree
+}
+
+impl FrequencyAutomaton {
+    fn new() -> Self {
+        Self {
+            transitions: vec![BTreeMap::new()],
+            ends: vec![0],
+            depths: vec![0],
+            parents: vec![None],
+        }
+    }
+
+    fn add_trace(&mut self, trace: &Vec<Activity>) {
+        let mut state = 0;
+        for activity in trace {
+            state = match self.transitions[state].get_mut(activity) {
+                Some((target, count)) => {
+                    *count += 1;
+                    *target
+                },
+                None => {
+                    let target = self.transitions.len();
+                    self.transitions.push(BTreeMap::new());
+                    self.ends.push(0);
+                    self.depths.push(self.depths[state] + 1);
+                    self.parents.push(Some((state, *activity)));
+                    self.transitions[state].insert(*activity, (target, 1));
+                    target
+                },
+            };
+        }
+        self.ends[state] += 1;
+    }
+
+    fn get_total(&self, state: usize) -> u64 {
+        self.ends[state] + self.transitions[state].values().map(|(_, count)| count).sum::<u64>()
+    }
+
+    /**
+     * Applies the red-blue merging procedure, and returns the red states, which are the states of the result.
+     */
+    fn merge(&mut self, bound: f64) -> Vec<usize> {
+        let mut red = vec![0];
+        loop {
+            //the blue states are the successors of red states that are not red themselves; take the one closest to the root
+            let blue = red.iter()
+                .flat_map(|state| self.transitions[*state].values().map(|(target, _)| *target))
+                .filter(|state| !red.contains(state))
+                .min_by_key(|state| (self.depths[*state], *state));
+            let blue = match blue {
+                Some(blue) => blue,
+                None => break,
+            };
+
+            match red.iter().find(|red_state| self.is_compatible(**red_state, blue, bound)) {
+                Some(red_state) => {
+                    let red_state = *red_state;
+                    //redirect the transition into the blue state
+                    let (parent, activity) = self.parents[blue].unwrap();
+                    self.transitions[parent].get_mut(&activity).unwrap().0 = red_state;
+                    self.fold(red_state, blue);
+                },
+                None => red.push(blue),
+            }
+        }
+        red.sort();
+        red
+    }
+
+    /**
+     * Hoeffding test of whether the frequencies f1/n1 and f2/n2 are different.
+     */
+    fn is_different(f1: u64, n1: u64, f2: u64, n2: u64, bound: f64) -> bool {
+        if n1 == 0 || n2 == 0 {
+            return false;
+        }
+        let (f1, n1, f2, n2) = (f1 as f64, n1 as f64, f2 as f64, n2 as f64);
+        (f1 / n1 - f2 / n2).abs() > bound * (1.0 / n1.sqrt() + 1.0 / n2.sqrt())
+    }
+
+    /**
+     * The state in the automaton is compatible with the state in the tree if their frequencies are not different, recursively.
+     */
+    fn is_compatible(&self, state: usize, tree_state: usize, bound: f64) -> bool {
+        let n1 = self.get_total(state);
+        let n2 = self.get_total(tree_state);
+        if Self::is_different(self.ends[state], n1, self.ends[tree_state], n2, bound) {
+            return false;
+        }
+
+        for activity in self.transitions[state].keys().chain(self.transitions[tree_state].keys()) {
+            let (target_1, f1) = self.transitions[state].get(activity).cloned().map_or((None, 0), |(target, count)| (Some(target), count));
+            let (target_2, f2) = self.transitions[tree_state].get(activity).cloned().map_or((None, 0), |(target, count)| (Some(target), count));
+            if Self::is_different(f1, n1, f2, n2, bound) {
+                return false;
+            }
+            if let (Some(target_1), Some(target_2)) = (target_1, target_2) {
+                if !self.is_compatible(target_1, target_2, bound) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /**
+     * Adds the frequencies of the subtree of tree_state to state, recursively.
+     */
+    fn fold(&mut self, state: usize, tree_state: usize) {
+        self.ends[state] += self.ends[tree_state];
+        let tree_transitions = std::mem::take(&mut self.transitions[tree_state]);
+        for (activity, (tree_target, tree_count)) in tree_transitions {
+            match self.transitions[state].get_mut(&activity) {
+                Some((target, count)) => {
+                    *count += tree_count;
+                    let target = *target;
+                    self.fold(target, tree_target);
+                },
+                None => {
+                    self.transitions[state].insert(activity, (tree_target, tree_count));
+                    self.parents[tree_target] = Some((state, activity));
+                },
+            }
+        }
+    }
+}
@@ -1,8 +1,8 @@
-use std::{fmt::{Debug, Display}, hash::Hash, sync::{Arc, Mutex}};
+use std::{cell::RefCell, collections::HashMap, fmt::{Debug, Display}, hash::Hash, sync::{Arc, Mutex}};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use anyhow::{anyhow, Context, Error, Result};
 
-use crate::{ebi_framework::{activity_key::{Activity, ActivityKeyTranslator}, displayable::Displayable, ebi_command::EbiCommand}, ebi_objects::{deterministic_finite_automaton::DeterministicFiniteAutomaton, directly_follows_model::DirectlyFollowsModel, finite_stochastic_language_semantics::FiniteStochasticLanguageSemantics, labelled_petri_net::{LPNMarking, LabelledPetriNet}, language_of_alignments::{LanguageOfAlignments, Move}, process_tree::ProcessTree, process_tree_semantics::NodeStates, stochastic_deterministic_finite_automaton::StochasticDeterministicFiniteAutomaton, stochastic_labelled_petri_net::StochasticLabelledPetriNet, stochastic_language_of_alignments::StochasticLanguageOfAlignments}, ebi_traits::{ebi_trait_finite_language::EbiTraitFiniteLanguage, ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_semantics::{EbiTraitSemantics, Semantics}, ebi_trait_stochastic_semantics::TransitionIndex}};
+use crate::{ebi_framework::{activity_key::{Activity, ActivityKeyTranslator}, displayable::Displayable, ebi_command::EbiCommand}, ebi_objects::{deterministic_finite_automaton::DeterministicFiniteAutomaton, directly_follows_model::DirectlyFollowsModel, finite_stochastic_language_semantics::FiniteStochasticLanguageSemantics, labelled_petri_net::{LPNMarking, LabelledPetriNet}, language_of_alignments::{LanguageOfAlignments, Move}, process_tree::{Node, Operator, ProcessTree}, process_tree_semantics::{NodeState, NodeStates}, stochastic_deterministic_finite_automaton::StochasticDeterministicFiniteAutomaton, stochastic_directly_follows_model::StochasticDirectlyFollowsModel, stochastic_labelled_petri_net::StochasticLabelledPetriNet, stochastic_language_of_alignments::StochasticLanguageOfAlignments, stochastic_process_tree::StochasticProcessTree}, ebi_traits::{ebi_trait_finite_language::EbiTraitFiniteLanguage, ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_semantics::{EbiTraitSemantics, Semantics}, ebi_trait_stochastic_semantics::TransitionIndex}, optimization_algorithms::simplex};
 
 pub trait Align {
     fn align_language(&mut self, log: Box<dyn EbiTraitFiniteLanguage>) -> Result<LanguageOfAlignments>;
@@ -176,7 +176,7 @@ pub fn align_astar<T, State>(semantics: &T, trace: &Vec<Activity>) -> Option<(Ve
             let new_trace_index = trace_index + 1;
             let new_state = state.clone();
             // log::debug!("\tlog move {} to {} {}", trace[*trace_index], new_trace_index, new_state);
-            result.push(((new_trace_index, new_state), 10000));
+            result.push(((new_trace_index, new_state), COST_LOG_MOVE));
         }
 
         //walk through the enabled transitions in the model
@@ -187,7 +187,7 @@ pub fn align_astar<T, State>(semantics: &T, trace: &Vec<Activity>) -> Option<(Ve
 
             if let Some(activity) = semantics.get_transition_activity(transition) {
                 //non-silent model move
-                result.push(((*trace_index, new_state.clone()), 10000));
+                result.push(((*trace_index, new_state.clone()), COST_MODEL_MOVE));
                 // log::debug!("\tmodel move t{} {} to {} {}", transition, activity, trace_index, new_state);
 
                 //which may also be a synchronous move
@@ -200,7 +200,7 @@ pub fn align_astar<T, State>(semantics: &T, trace: &Vec<Activity>) -> Option<(Ve
             } else {
                 //silent move
                 // log::debug!("\tsilent move t{} to {} {}", transition, trace_index, new_state);
-                result.push(((*trace_index, new_state), 1));
+                result.push(((*trace_index, new_state), COST_SILENT_MOVE));
             }
         }
 
@@ -227,7 +227,7 @@ pub fn align_astar<T, State>(semantics: &T, trace: &Vec<Activity>) -> Option<(Ve
  * The a* function we use returns a sequence of states, while we need a sequence of moves.
  * This function transforms the sequence of states into a sequence of moves.
  * 
- * This function assumes equal costs (of 10000) for the log and model moves, and 1 for the silent moves.
+ * This function assumes that silent moves (COST_SILENT_MOVE) are cheaper than labelled model moves (COST_MODEL_MOVE).
  */
 pub fn transform_alignment<T, State>(semantics: &T, trace: &Vec<Activity>, states: Vec<(usize, State)>) -> Result<Vec<Move>> where T: Semantics<SemState = State> + ?Sized, State: Display + Debug + Clone + Hash + Eq {
     let mut alignment = vec![];
@@ -314,26 +314,44 @@ pub fn find_labelled_transition<T, FS>(semantics: &T, from: &FS, to: &FS) -> Res
     Err(anyhow!("There is no transition with any activity enabled that brings the model from {} to {}", from, to))
 }
 
+/**
+ * Storage for the alignment heuristic of one search through the synchronous product.
+ * The data is computed once by initialise_alignment_heuristic_cache; the solutions map states of the synchronous product to their estimate and, if applicable, the (sparse) solution vector that proves it.
+ */
+pub struct AlignmentHeuristicCache<State> {
+    pub data: Vec<Vec<usize>>,
+    pub solutions: RefCell<HashMap<(usize, State), (usize, Vec<(usize, f64)>)>>,
+}
+
+impl <State> AlignmentHeuristicCache<State> {
+    pub fn new(data: Vec<Vec<usize>>) -> Self {
+        Self {
+            data: data,
+            solutions: RefCell::new(HashMap::new()),
+        }
+    }
+}
+
 pub trait AlignmentHeuristics {
     type AliState;
 
-    fn initialise_alignment_heuristic_cache(&self) -> Vec<Vec<usize>>;
+    fn initialise_alignment_heuristic_cache(&self) -> AlignmentHeuristicCache<Self::AliState>;
 
     /**
      * Return a lower bound on the cost to reach a final state in the synchronous product.
      * If not sure: 0 is a valid return value, though better bounds will make searches more efficient.
      */
-    fn underestimate_cost_to_final_synchronous_state(&self, trace: &Vec<Activity>, trace_index: &usize, state: &Self::AliState, cache: &Vec<Vec<usize>>) -> usize;
+    fn underestimate_cost_to_final_synchronous_state(&self, trace: &Vec<Activity>, trace_index: &usize, state: &Self::AliState, cache: &AlignmentHeuristicCache<Self::AliState>) -> usize;
 }
 
 impl AlignmentHeuristics for DeterministicFiniteAutomaton {
     type AliState = usize;
 
-    fn initialise_alignment_heuristic_cache(&self) -> Vec<Vec<usize>> {
-        vec![]
+    fn initialise_alignment_heuristic_cache(&self) -> AlignmentHeuristicCache<usize> {
+        AlignmentHeuristicCache::new(vec![])
     }
     
-    fn underestimate_cost_to_final_synchronous_state(&self, _: &Vec<Activity>, _: &usize, _: &usize, _: &Vec<Vec<usize>>) -> usize {
+    fn underestimate_cost_to_final_synchronous_state(&self, _: &Vec<Activity>, _: &usize, _: &usize, _: &AlignmentHeuristicCache<usize>) -> usize {
         0
     }
 }
@@ -341,11 +359,11 @@ impl AlignmentHeuristics for DeterministicFiniteAutomaton {
 impl AlignmentHeuristics for FiniteStochasticLanguageSemantics {
     type AliState = usize;
 
-    fn initialise_alignment_heuristic_cache(&self) -> Vec<Vec<usize>> {
-        vec![]
+    fn initialise_alignment_heuristic_cache(&self) -> AlignmentHeuristicCache<usize> {
+        AlignmentHeuristicCache::new(vec![])
     }
 
-    fn underestimate_cost_to_final_synchronous_state(&self, _: &Vec<Activity>, _: &usize, _: &usize, _: &Vec<Vec<usize>>) -> usize {
+    fn underestimate_cost_to_final_synchronous_state(&self, _: &Vec<Activity>, _: &usize, _: &usize, _: &AlignmentHeuristicCache<usize>) -> usize {
         0
     }
 }
@@ -353,35 +371,35 @@ impl AlignmentHeuristics for FiniteStochasticLanguageSemantics {
 impl AlignmentHeuristics for LabelledPetriNet {
     type AliState = LPNMarking;
 
-    fn initialise_alignment_heuristic_cache(&self) -> Vec<Vec<usize>> {
-        vec![]
+    fn initialise_alignment_heuristic_cache(&self) -> AlignmentHeuristicCache<LPNMarking> {
+        AlignmentHeuristicCache::new(vec![])
     }
 
-    fn underestimate_cost_to_final_synchronous_state(&self, _trace: &Vec<Activity>, _trace_index: &usize, _state: &LPNMarking, _cache: &Vec<Vec<usize>>) -> usize {
-        0
+    fn underestimate_cost_to_final_synchronous_state(&self, trace: &Vec<Activity>, trace_index: &usize, state: &LPNMarking, cache: &AlignmentHeuristicCache<LPNMarking>) -> usize {
+        marking_equation_heuristic(self, |transition| self.incidence_vector(transition), trace, trace_index, state, cache)
     }
 }
 
 impl AlignmentHeuristics for StochasticLabelledPetriNet {
     type AliState = LPNMarking;
     
-    fn initialise_alignment_heuristic_cache(&self) -> Vec<Vec<usize>> {
-        vec![]
+    fn initialise_alignment_heuristic_cache(&self) -> AlignmentHeuristicCache<LPNMarking> {
+        AlignmentHeuristicCache::new(vec![])
     }
 
-    fn underestimate_cost_to_final_synchronous_state(&self, _trace: &Vec<Activity>, _trace_index: &usize, _state: &LPNMarking, _cache: &Vec<Vec<usize>>) -> usize {
-        0
+    fn underestimate_cost_to_final_synchronous_state(&self, trace: &Vec<Activity>, trace_index: &usize, state: &LPNMarking, cache: &AlignmentHeuristicCache<LPNMarking>) -> usize {
+        marking_equation_heuristic(self, |transition| self.incidence_vector(transition), trace, trace_index, state, cache)
     }
 }
 
 impl AlignmentHeuristics for StochasticDeterministicFiniteAutomaton {
     type AliState = usize;
     
-    fn initialise_alignment_heuristic_cache(&self) -> Vec<Vec<usize>> {
-        vec![]
+    fn initialise_alignment_heuristic_cache(&self) -> AlignmentHeuristicCache<usize> {
+        AlignmentHeuristicCache::new(vec![])
     }
 
-    fn underestimate_cost_to_final_synchronous_state(&self, _: &Vec<Activity>,  _: &usize, _: &usize, _: &Vec<Vec<usize>>) -> usize {
+    fn underestimate_cost_to_final_synchronous_state(&self, _: &Vec<Activity>,  _: &usize, _: &usize, _: &AlignmentHeuristicCache<usize>) -> usize {
         0
     }
 }
@@ -389,12 +407,40 @@ impl AlignmentHeuristics for StochasticDeterministicFiniteAutomaton {
 impl AlignmentHeuristics for ProcessTree {
     type AliState = NodeStates;
 
-    fn initialise_alignment_heuristic_cache(&self) -> Vec<Vec<usize>> {
-        vec![]
+    /**
+     * The cache holds, for each node, the minimum number of activities to execute the node from scratch.
+     */
+    fn initialise_alignment_heuristic_cache(&self) -> AlignmentHeuristicCache<NodeStates> {
+        let mut minimum = vec![0; self.get_number_of_nodes()];
+
+        //children come after their parents in the tree, so a backwards walk sees all children first
+        for node in (0..self.get_number_of_nodes()).rev() {
+            minimum[node] = match self.tree[node] {
+                Node::Tau => 0,
+                Node::Activity(_) => 1,
+                Node::Operator(Operator::Sequence, _) | Node::Operator(Operator::Concurrent, _) | Node::Operator(Operator::Interleaved, _) => self.get_children(node).map(|child| minimum[child]).sum(),
+                Node::Operator(Operator::Xor, _) | Node::Operator(Operator::Or, _) => self.get_children(node).map(|child| minimum[child]).min().unwrap_or(0),
+                Node::Operator(Operator::Loop, _) => minimum[self.get_child(node, 0)],
+            };
+        }
+
+        AlignmentHeuristicCache::new(vec![minimum])
     }
 
-    fn underestimate_cost_to_final_synchronous_state(&self, _: &Vec<Activity>,  _: &usize, _: &Self::AliState, _: &Vec<Vec<usize>>) -> usize {
-        0
+    fn underestimate_cost_to_final_synchronous_state(&self, trace: &Vec<Activity>, trace_index: &usize, state: &Self::AliState, cache: &AlignmentHeuristicCache<NodeStates>) -> usize {
+        process_tree_heuristic(self, trace, trace_index, state, cache)
+    }
+}
+
+impl AlignmentHeuristics for StochasticProcessTree {
+    type AliState = NodeStates;
+
+    fn initialise_alignment_heuristic_cache(&self) -> AlignmentHeuristicCache<NodeStates> {
+        self.tree.initialise_alignment_heuristic_cache()
+    }
+
+    fn underestimate_cost_to_final_synchronous_state(&self, trace: &Vec<Activity>, trace_index: &usize, state: &Self::AliState, cache: &AlignmentHeuristicCache<NodeStates>) -> usize {
+        process_tree_heuristic(&self.tree, trace, trace_index, state, cache)
     }
 }
 
@@ -402,11 +448,359 @@ impl AlignmentHeuristics for ProcessTree {
 impl AlignmentHeuristics for DirectlyFollowsModel {
     type AliState = usize;
 
-    fn initialise_alignment_heuristic_cache(&self) -> Vec<Vec<usize>> {
-        vec![]
+    fn initialise_alignment_heuristic_cache(&self) -> AlignmentHeuristicCache<usize> {
+        AlignmentHeuristicCache::new(vec![])
     }
     
-    fn underestimate_cost_to_final_synchronous_state(&self, _: &Vec<Activity>, _: &usize, _: &Self::AliState, _: &Vec<Vec<usize>>) -> usize {
+    fn underestimate_cost_to_final_synchronous_state(&self, _: &Vec<Activity>, _: &usize, _: &Self::AliState, _: &AlignmentHeuristicCache<usize>) -> usize {
         0
     }
-}
\ No newline at end of file
+}
+
+impl AlignmentHeuristics for StochasticDirectlyFollowsModel {
+    type AliState = usize;
+
+    fn initialise_alignment_heuristic_cache(&self) -> AlignmentHeuristicCache<usize> {
+        AlignmentHeuristicCache::new(vec![])
+    }
+    
+    fn underestimate_cost_to_final_synchronous_state(&self, _: &Vec<Activity>, _: &usize, _: &Self::AliState, _: &AlignmentHeuristicCache<usize>) -> usize {
+        0
+    }
+}
+
+const COST_LOG_MOVE: usize = 10000;
+const COST_MODEL_MOVE: usize = 10000;
+const COST_SILENT_MOVE: usize = 1;
+const EPSILON: f64 = 1e-6;
+
+/**
+ * Marking-equation heuristic for Petri nets (Adriansyah et al.).
+ * 
+ * As final markings in Ebi are deadlocks rather than given markings, the marking equation is relaxed to m + C x >= 0.
+ * The variables are the number of firings x_t of each transition and, for each activity a of the trace, the number of synchronous moves s_a.
+ * The linear program minimises
+ *      sum_a COST_LOG_MOVE * (#a in the remaining trace - s_a) + sum_a COST_MODEL_MOVE * (sum_{t labelled a} x_t - s_a) + sum_{t silent} COST_SILENT_MOVE * x_t
+ * subject to m + C x >= 0, s_a <= #a in the remaining trace and s_a <= sum_{t labelled a} x_t.
+ * Every path through the synchronous product yields a feasible solution with the same cost, hence the optimum is admissible.
+ * 
+ * If the solution of a state fires a transition (or has a log move left) at least once, then the solution minus that move is optimal for the successor state.
+ * Such derived solutions are stored in the cache, so that most successors do not need to solve a linear program.
+ */
+fn marking_equation_heuristic<T, F>(net: &T, incidence_vector: F, trace: &Vec<Activity>, trace_index: &usize, state: &LPNMarking, cache: &AlignmentHeuristicCache<LPNMarking>) -> usize where T: Semantics<SemState = LPNMarking> + ?Sized, F: Fn(TransitionIndex) -> Vec<i128> {
+    let key = (*trace_index, state.clone());
+    if let Some((estimate, _)) = cache.solutions.borrow().get(&key) {
+        return *estimate;
+    }
+
+    //the activities of the trace, which are the synchronous-move variables
+    let mut activities = vec![];
+    for activity in trace {
+        if !activities.contains(activity) {
+            activities.push(*activity);
+        }
+    }
+    let mut remaining = vec![0usize; activities.len()];
+    for activity in trace.iter().skip(*trace_index) {
+        remaining[activities.iter().position(|a| a == activity).unwrap()] += 1;
+    }
+
+    let number_of_transitions = net.get_number_of_transitions();
+    let number_of_variables = number_of_transitions + activities.len();
+    let number_of_places = state.marking.get_place2token().len();
+    let incidence = (0..number_of_transitions).map(|transition| incidence_vector(transition)).collect::<Vec<_>>();
+
+    //objective (maximised): the savings w.r.t. doing every remaining trace event as a log move
+    let mut objective = vec![0.0; number_of_variables];
+    for transition in 0..number_of_transitions {
+        objective[transition] = if net.is_transition_silent(transition) {
+            -(COST_SILENT_MOVE as f64)
+        } else {
+            -(COST_MODEL_MOVE as f64)
+        };
+    }
+    for activity_index in 0..activities.len() {
+        objective[number_of_transitions + activity_index] = (COST_LOG_MOVE + COST_MODEL_MOVE) as f64;
+    }
+
+    let mut constraints = vec![];
+    let mut bounds = vec![];
+
+    //marking equation: -C x <= m
+    for place in 0..number_of_places {
+        let mut constraint = vec![0.0; number_of_variables];
+        for transition in 0..number_of_transitions {
+            constraint[transition] = -(incidence[transition][place] as f64);
+        }
+        constraints.push(constraint);
+        bounds.push(state.marking.get_place2token()[place] as f64);
+    }
+
+    //synchronous moves are limited by the remaining trace and by the firings of the corresponding transitions
+    for (activity_index, activity) in activities.iter().enumerate() {
+        let mut constraint = vec![0.0; number_of_variables];
+        constraint[number_of_transitions + activity_index] = 1.0;
+        constraints.push(constraint);
+        bounds.push(remaining[activity_index] as f64);
+
+        let mut constraint = vec![0.0; number_of_variables];
+        constraint[number_of_transitions + activity_index] = 1.0;
+        for transition in 0..number_of_transitions {
+            if net.get_transition_activity(transition) == Some(*activity) {
+                constraint[transition] = -1.0;
+            }
+        }
+        constraints.push(constraint);
+        bounds.push(0.0);
+    }
+
+    let (estimate, solution) = match simplex::maximise(&objective, &constraints, &bounds) {
+        Ok((savings, solution)) => {
+            let cost = ((trace.len() - trace_index) * COST_LOG_MOVE) as f64 - savings;
+            let estimate = (cost - EPSILON).ceil().max(0.0) as usize;
+            let solution = solution.into_iter().enumerate().filter(|(_, value)| *value > EPSILON).collect::<Vec<_>>();
+            (estimate, solution)
+        },
+        Err(_) => {
+            //cannot happen, as the objective is bounded; 0 is always admissible
+            (0, vec![])
+        }
+    };
+
+    //derive the solutions of successor states
+    let mut solutions = cache.solutions.borrow_mut();
+    let get = |variable: usize| solution.iter().find(|(v, _)| *v == variable).map_or(0.0, |(_, value)| *value);
+    let subtract = |variables: &[usize]| {
+        solution.iter().filter_map(|(variable, value)| {
+            let value = if variables.contains(variable) { value - 1.0 } else { *value };
+            if value > EPSILON { Some((*variable, value)) } else { None }
+        }).collect::<Vec<_>>()
+    };
+
+    if *trace_index < trace.len() {
+        //log move
+        let activity_index = activities.iter().position(|a| a == &trace[*trace_index]).unwrap();
+        if remaining[activity_index] as f64 - get(number_of_transitions + activity_index) >= 1.0 - EPSILON && estimate >= COST_LOG_MOVE {
+            solutions.entry((trace_index + 1, state.clone())).or_insert_with(|| (estimate - COST_LOG_MOVE, solution.clone()));
+        }
+    }
+
+    for transition in net.get_enabled_transitions(state) {
+        if get(transition) < 1.0 - EPSILON {
+            continue;
+        }
+
+        let mut new_state = state.clone();
+        if net.execute_transition(&mut new_state, transition).is_err() {
+            continue;
+        }
+
+        if let Some(activity) = net.get_transition_activity(transition) {
+            let activity_index = activities.iter().position(|a| *a == activity);
+            let synchronous = activity_index.map_or(0.0, |activity_index| get(number_of_transitions + activity_index));
+            let firings = (0..number_of_transitions).filter(|t| net.get_transition_activity(*t) == Some(activity)).map(|t| get(t)).sum::<f64>();
+
+            //model move
+            if firings - synchronous >= 1.0 - EPSILON && estimate >= COST_MODEL_MOVE {
+                solutions.entry((*trace_index, new_state.clone())).or_insert_with(|| (estimate - COST_MODEL_MOVE, subtract(&[transition])));
+            }
+
+            //synchronous move
+            if let Some(activity_index) = activity_index {
+                if *trace_index < trace.len() && trace[*trace_index] == activity && synchronous >= 1.0 - EPSILON {
+                    solutions.entry((trace_index + 1, new_state)).or_insert_with(|| (estimate, subtract(&[transition, number_of_transitions + activity_index])));
+                }
+            }
+        } else {
+            //silent move
+            if estimate >= COST_SILENT_MOVE {
+                solutions.entry((*trace_index, new_state)).or_insert_with(|| (estimate - COST_SILENT_MOVE, subtract(&[transition])));
+            }
+        }
+    }
+
+    solutions.insert(key, (estimate, solution));
+    estimate
+}
+/**
+ * Structural heuristic for process trees.
+ * 
+ * From the node states, it derives the minimum number M of activities the tree must still execute, and for each activity the maximum number of times it can still be executed.
+ * The L events of the remaining trace that exceed these maxima cannot be matched by the subtrees in which they could occur, so at most n - L of the n remaining events become synchronous moves.
+ * An alignment in which the model executes k >= M activities thus costs at least COST_LOG_MOVE * (n - s) + COST_MODEL_MOVE * (k - s) with s <= min(k, n - L).
+ * This is minimal for k = max(M, n - L), which keeps the estimate admissible. Silent moves are not counted.
+ */
+fn process_tree_heuristic(tree: &ProcessTree, trace: &Vec<Activity>, trace_index: &usize, state: &NodeStates, cache: &AlignmentHeuristicCache<NodeStates>) -> usize {
+    let key = (*trace_index, state.clone());
+    if let Some((estimate, _)) = cache.solutions.borrow().get(&key) {
+        return *estimate;
+    }
+
+    let (minimum, maximum) = if tree.is_final_state(state) || tree.tree.is_empty() {
+        (0, HashMap::new())
+    } else {
+        process_tree_remaining(tree, state, tree.get_root(), &cache.data[0])
+    };
+
+    //count the remaining events that cannot be matched
+    let mut remaining = HashMap::new();
+    for activity in &trace[*trace_index..] {
+        *remaining.entry(*activity).or_insert(0usize) += 1;
+    }
+    let unmatchable = remaining.iter().map(|(activity, count)| count.saturating_sub(*maximum.get(activity).unwrap_or(&0))).sum::<usize>();
+    let matchable = (trace.len() - trace_index) - unmatchable;
+
+    let estimate = COST_LOG_MOVE * unmatchable + COST_MODEL_MOVE * minimum.saturating_sub(matchable);
+    cache.solutions.borrow_mut().insert(key, (estimate, vec![]));
+    estimate
+}
+
+/**
+ * For a node that may have been started, returns the minimum number of activities it must still execute, and for each activity the maximum number of times it may still be executed (usize::MAX if unbounded).
+ * A closed node has nothing left to execute; children that have not been enabled yet are handled by their parent.
+ */
+fn process_tree_remaining(tree: &ProcessTree, state: &NodeStates, node: usize, fresh_minimum: &Vec<usize>) -> (usize, HashMap<Activity, usize>) {
+    if state[node] == NodeState::Closed {
+        return (0, HashMap::new());
+    }
+
+    match tree.tree[node] {
+        Node::Tau | Node::Activity(_) => process_tree_fresh(tree, node, fresh_minimum),
+        Node::Operator(Operator::Sequence, _) => {
+            //the first non-closed child is the current one; the children after it still have to be executed from scratch
+            match tree.get_children(node).position(|child| state[child] != NodeState::Closed) {
+                Some(current_rank) => {
+                    let (mut minimum, mut maximum) = process_tree_remaining(tree, state, tree.get_child(node, current_rank), fresh_minimum);
+                    for child in tree.get_children(node).skip(current_rank + 1) {
+                        let (child_minimum, child_maximum) = process_tree_fresh(tree, child, fresh_minimum);
+                        minimum += child_minimum;
+                        add_maxima(&mut maximum, child_maximum);
+                    }
+                    (minimum, maximum)
+                },
+                None => {
+                    //we cannot tell which children are done; be optimistic
+                    let mut maximum = HashMap::new();
+                    for child in tree.get_children(node) {
+                        add_maxima(&mut maximum, process_tree_fresh(tree, child, fresh_minimum).1);
+                    }
+                    (0, maximum)
+                }
+            }
+        },
+        Node::Operator(Operator::Concurrent, _) | Node::Operator(Operator::Interleaved, _) => {
+            //all children must finish
+            let mut minimum = 0;
+            let mut maximum = HashMap::new();
+            for child in tree.get_children(node) {
+                let (child_minimum, child_maximum) = process_tree_remaining(tree, state, child, fresh_minimum);
+                minimum += child_minimum;
+                add_maxima(&mut maximum, child_maximum);
+            }
+            (minimum, maximum)
+        },
+        Node::Operator(Operator::Xor, _) => {
+            if let Some(started) = tree.get_children(node).find(|child| state[*child] == NodeState::Started) {
+                //the choice has been made
+                process_tree_remaining(tree, state, started, fresh_minimum)
+            } else {
+                //any of the enabled children may be chosen
+                let mut minimum = None;
+                let mut maximum = HashMap::new();
+                for child in tree.get_children(node) {
+                    if state[child] != NodeState::Closed {
+                        let (child_minimum, child_maximum) = process_tree_remaining(tree, state, child, fresh_minimum);
+                        minimum = Some(minimum.map_or(child_minimum, |m: usize| m.min(child_minimum)));
+                        for (activity, count) in child_maximum {
+                            let entry = maximum.entry(activity).or_insert(0);
+                            *entry = count.max(*entry);
+                        }
+                    }
+                }
+                (minimum.unwrap_or(0), maximum)
+            }
+        },
+        Node::Operator(Operator::Or, _) => {
+            //started children must finish, and if no child has been executed yet, at least one must be
+            let mut minimum = 0;
+            let mut enabled_minimum = None;
+            let mut any_executed = false;
+            let mut maximum = HashMap::new();
+            for child in tree.get_children(node) {
+                match state[child] {
+                    NodeState::Closed => any_executed = true,
+                    NodeState::Started => {
+                        any_executed = true;
+                        let (child_minimum, child_maximum) = process_tree_remaining(tree, state, child, fresh_minimum);
+                        minimum += child_minimum;
+                        add_maxima(&mut maximum, child_maximum);
+                    },
+                    NodeState::Enabled => {
+                        let (child_minimum, child_maximum) = process_tree_remaining(tree, state, child, fresh_minimum);
+                        enabled_minimum = Some(enabled_minimum.map_or(child_minimum, |m: usize| m.min(child_minimum)));
+                        add_maxima(&mut maximum, child_maximum);
+                    },
+                }
+            }
+            if !any_executed {
+                minimum += enabled_minimum.unwrap_or(0);
+            }
+            (minimum, maximum)
+        },
+        Node::Operator(Operator::Loop, _) => {
+            let body = tree.get_child(node, 0);
+            let minimum = if state[body] != NodeState::Closed {
+                //the body must finish, after which the loop may be exited
+                process_tree_remaining(tree, state, body, fresh_minimum).0
+            } else if let Some(redo) = tree.get_children(node).skip(1).find(|child| state[*child] == NodeState::Started) {
+                //a started redo child must finish, after which the body must be executed again
+                process_tree_remaining(tree, state, redo, fresh_minimum).0 + fresh_minimum[body]
+            } else {
+                0
+            };
+            (minimum, process_tree_fresh(tree, node, fresh_minimum).1)
+        },
+    }
+}
+
+/**
+ * For a node that is executed from scratch, returns the minimum number of activities it executes, and for each activity the maximum number of times it may be executed (usize::MAX if unbounded).
+ */
+fn process_tree_fresh(tree: &ProcessTree, node: usize, fresh_minimum: &Vec<usize>) -> (usize, HashMap<Activity, usize>) {
+    let mut maximum = HashMap::new();
+    match tree.tree[node] {
+        Node::Tau => {},
+        Node::Activity(activity) => {
+            maximum.insert(activity, 1);
+        },
+        Node::Operator(Operator::Sequence, _) | Node::Operator(Operator::Concurrent, _) | Node::Operator(Operator::Interleaved, _) | Node::Operator(Operator::Or, _) => {
+            for child in tree.get_children(node) {
+                add_maxima(&mut maximum, process_tree_fresh(tree, child, fresh_minimum).1);
+            }
+        },
+        Node::Operator(Operator::Xor, _) => {
+            for child in tree.get_children(node) {
+                for (activity, count) in process_tree_fresh(tree, child, fresh_minimum).1 {
+                    let entry = maximum.entry(activity).or_insert(0);
+                    *entry = count.max(*entry);
+                }
+            }
+        },
+        Node::Operator(Operator::Loop, _) => {
+            //every activity in a loop may be executed arbitrarily often
+            for descendant in node..tree.traverse(node) {
+                if let Node::Activity(activity) = tree.tree[descendant] {
+                    maximum.insert(activity, usize::MAX);
+                }
+            }
+        },
+    }
+    (fresh_minimum[node], maximum)
+}
+
+fn add_maxima(maximum: &mut HashMap<Activity, usize>, other: HashMap<Activity, usize>) {
+    for (activity, count) in other {
+        let entry = maximum.entry(activity).or_insert(0);
+        *entry = entry.saturating_add(count);
+    }
+}
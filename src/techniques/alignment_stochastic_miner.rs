@@ -1,30 +1,76 @@
 use anyhow::{anyhow, Result};
-use crate::{ebi_objects::{language_of_alignments::Move, labelled_petri_net::LabelledPetriNet, stochastic_labelled_petri_net::StochasticLabelledPetriNet}, ebi_traits::{ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_semantics::Semantics}, math::fraction::Fraction};
+use crate::{ebi_objects::{language_of_alignments::Move, labelled_petri_net::LabelledPetriNet, process_tree::{Node, Operator, ProcessTree}, stochastic_labelled_petri_net::StochasticLabelledPetriNet, stochastic_process_tree::{Decision, StochasticProcessTree}}, ebi_traits::{ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_semantics::Semantics}, math::fraction::Fraction};
 
-use super::align::Align;
+use super::{align::Align, uniform_stochastic_miner::UniformStochasticMiner};
 
 pub trait AlignmentMiner {
-    fn mine_stochastic_alignment(self, language: Box<dyn EbiTraitFiniteStochasticLanguage>) -> Result<StochasticLabelledPetriNet>;
+    type StochasticModel;
+
+    fn mine_stochastic_alignment(self, language: Box<dyn EbiTraitFiniteStochasticLanguage>) -> Result<Self::StochasticModel>;
 }
 
 impl AlignmentMiner for LabelledPetriNet {
+    type StochasticModel = StochasticLabelledPetriNet;
+
     fn mine_stochastic_alignment(mut self, language: Box<dyn EbiTraitFiniteStochasticLanguage>) -> Result<StochasticLabelledPetriNet> {
-        let mut probabilities: Vec<Fraction> = vec![Fraction::zero(); self.get_number_of_transitions()];
-
-        let alignments = self.align_stochastic_language(language)?;
-        for index in 0..alignments.len() {
-            let probability = alignments.get_probability(index).ok_or_else(|| anyhow!("should not happen"))?;
-            
-            for movee in alignments.get(index).ok_or_else(|| anyhow!("should not happen"))? {
-                match movee {
-                    Move::LogMove(_) => {},
-                    Move::ModelMove(_, transition) |  Move::SynchronousMove(_, transition) | Move::SilentMove(transition) => {
-                        probabilities[*transition] += probability;
-                    },
-                }
+        let probabilities = count_aligned_transitions(&mut self, language)?;
+        Ok((self, probabilities).into())
+    }
+}
+
+impl AlignmentMiner for ProcessTree {
+    type StochasticModel = StochasticProcessTree;
+
+    fn mine_stochastic_alignment(self, language: Box<dyn EbiTraitFiniteStochasticLanguage>) -> Result<StochasticProcessTree> {
+        //align with the tree in which all options are equally likely, such that the alignments show which decisions were made
+        let mut tree = self.mine_uniform_stochastic()?;
+        let probabilities = count_aligned_transitions(&mut tree, language)?;
+
+        let mut weights = vec![];
+        for node in 0..tree.tree.get_number_of_nodes() {
+            let transitions = tree.get_decision_transitions(node);
+            let total = transitions.iter().map(|transition| &probabilities[*transition]).sum::<Fraction>();
+            if !total.is_positive() {
+                //a choice that is never made keeps its uniform weights
+                weights.push(StochasticProcessTree::get_uniform_weights(&tree.tree, node));
+            } else if let Node::Operator(Operator::Or, _) = tree.tree.tree[node] {
+                //the probability of a child is the fraction of the decisions that include it
+                weights.push(tree.tree.get_children(node).map(|child| {
+                    let mut included = Fraction::zero();
+                    for transition in &transitions {
+                        if let Some(Decision::OrSubset { children, .. }) = tree.get_decision(*transition) {
+                            if children.contains(&child) {
+                                included += &probabilities[*transition];
+                            }
+                        }
+                    }
+                    &included / &total
+                }).collect());
+            } else {
+                weights.push(transitions.iter().map(|transition| probabilities[*transition].clone()).collect());
             }
         }
-    
-        Ok((self, probabilities).into())
+
+        StochasticProcessTree::new(tree.tree, weights)
     }
-}
\ No newline at end of file
+}
+
+fn count_aligned_transitions<T: Semantics + Send + Sync>(semantics: &mut T, language: Box<dyn EbiTraitFiniteStochasticLanguage>) -> Result<Vec<Fraction>> {
+    let mut probabilities: Vec<Fraction> = vec![Fraction::zero(); semantics.get_number_of_transitions()];
+
+    let alignments = semantics.align_stochastic_language(language)?;
+    for index in 0..alignments.len() {
+        let probability = alignments.get_probability(index).ok_or_else(|| anyhow!("should not happen"))?;
+        
+        for movee in alignments.get(index).ok_or_else(|| anyhow!("should not happen"))? {
+            match movee {
+                Move::LogMove(_) => {},
+                Move::ModelMove(_, transition) |  Move::SynchronousMove(_, transition) | Move::SilentMove(transition) => {
+                    probabilities[*transition] += probability;
+                },
+            }
+        }
+    }
+
+    Ok(probabilities)
+}
@@ -0,0 +1,188 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{ebi_framework::activity_key::HasActivityKey, ebi_objects::labelled_petri_net::LabelledPetriNet, ebi_traits::ebi_trait_finite_language::EbiTraitFiniteLanguage};
+
+pub trait AlphaMiner {
+    /**
+     * Discovers a labelled Petri net using the Alpha+ algorithm, that is, the Alpha algorithm extended with the handling of short loops:
+     * activities that directly follow themselves are removed from the log and added afterwards as self-loops on the place in which they occur, and length-two loops are recognised from the pattern aba.
+     */
+    fn mine_alpha(&self) -> LabelledPetriNet;
+}
+
+impl AlphaMiner for dyn EbiTraitFiniteLanguage {
+    fn mine_alpha(&self) -> LabelledPetriNet {
+        let mut result = LabelledPetriNet::new();
+        let language = self.translate_using_activity_key(result.get_activity_key_mut());
+        let traces = (0..language.len()).filter_map(|trace_index| language.get_trace(trace_index).cloned()).collect::<Vec<_>>();
+
+        let mut activities = traces.iter().flatten().cloned().collect::<Vec<_>>();
+        activities.sort();
+        activities.dedup();
+
+        //length-one loops are removed from the log
+        let length_one_loops = traces.iter().flat_map(|trace| trace.windows(2).filter(|pair| pair[0] == pair[1]).map(|pair| pair[0])).collect::<BTreeSet<_>>();
+        let reduced_traces = traces.iter().map(|trace| trace.iter().filter(|activity| !length_one_loops.contains(activity)).cloned().collect::<Vec<_>>()).collect::<Vec<_>>();
+        let reduced_activities = activities.iter().filter(|activity| !length_one_loops.contains(activity)).cloned().collect::<Vec<_>>();
+        let activity_2_index = reduced_activities.iter().enumerate().map(|(index, activity)| (*activity, index)).collect::<HashMap<_, _>>();
+        let n = reduced_activities.len();
+
+        //footprint
+        let mut follows = vec![vec![false; n]; n];
+        let mut length_two_loop = vec![vec![false; n]; n];
+        let mut starts = vec![false; n];
+        let mut ends = vec![false; n];
+        for trace in &reduced_traces {
+            if let (Some(first), Some(last)) = (trace.first(), trace.last()) {
+                starts[activity_2_index[first]] = true;
+                ends[activity_2_index[last]] = true;
+            }
+            for pair in trace.windows(2) {
+                follows[activity_2_index[&pair[0]]][activity_2_index[&pair[1]]] = true;
+            }
+            for triple in trace.windows(3) {
+                if triple[0] == triple[2] {
+                    length_two_loop[activity_2_index[&triple[0]]][activity_2_index[&triple[1]]] = true;
+                }
+            }
+        }
+        let mut causal = vec![vec![false; n]; n];
+        let mut choice = vec![vec![false; n]; n];
+        for a in 0..n {
+            for b in 0..n {
+                let diamond = length_two_loop[a][b] && length_two_loop[b][a];
+                causal[a][b] = follows[a][b] && (!follows[b][a] || diamond);
+                choice[a][b] = !follows[a][b] && !follows[b][a];
+            }
+        }
+
+        //transitions
+        let mut activity_2_transition = HashMap::new();
+        for activity in &activities {
+            activity_2_transition.insert(*activity, result.add_transition(Some(*activity)));
+        }
+
+        //places
+        let source = result.add_place();
+        result.get_initial_marking_mut().increase(source, 1).unwrap();
+        let sink = result.add_place();
+        let mut places = vec![];
+        for (a, start) in starts.iter().enumerate() {
+            if *start {
+                result.add_place_transition_arc(source, activity_2_transition[&reduced_activities[a]], 1).unwrap();
+            }
+        }
+        for (a, end) in ends.iter().enumerate() {
+            if *end {
+                result.add_transition_place_arc(activity_2_transition[&reduced_activities[a]], sink, 1).unwrap();
+            }
+        }
+        if reduced_traces.iter().any(|trace| trace.is_empty()) {
+            //a trace without activities, or with length-one loops only, goes from the source to the sink silently
+            let transition = result.add_transition(None);
+            result.add_place_transition_arc(source, transition, 1).unwrap();
+            result.add_transition_place_arc(transition, sink, 1).unwrap();
+        }
+        for (inputs, outputs) in get_maximal_places(&causal, &choice) {
+            let place = result.add_place();
+            for a in &inputs {
+                result.add_transition_place_arc(activity_2_transition[&reduced_activities[*a]], place, 1).unwrap();
+            }
+            for b in &outputs {
+                result.add_place_transition_arc(place, activity_2_transition[&reduced_activities[*b]], 1).unwrap();
+            }
+            places.push((inputs, outputs, place));
+        }
+
+        //length-one loops are added as self-loops on the place between their neighbours
+        let mut final_place = None;
+        for activity in &length_one_loops {
+            let mut loop_places = BTreeSet::new();
+            for trace in &traces {
+                for (i, _) in trace.iter().enumerate().filter(|(_, a)| *a == activity) {
+                    let before = trace[..i].iter().rev().find(|a| !length_one_loops.contains(a)).map(|a| activity_2_index[a]);
+                    let after = trace[i + 1..].iter().find(|a| !length_one_loops.contains(a)).map(|a| activity_2_index[a]);
+
+                    let place = match (before, after) {
+                        (None, _) => Some(source),
+                        (_, None) => Some(sink),
+                        (Some(before), Some(after)) => {
+                            places.iter().find(|(inputs, outputs, _)| inputs.contains(&before) && outputs.contains(&after))
+                                .or_else(|| places.iter().find(|(inputs, _, _)| inputs.contains(&before)))
+                                .or_else(|| places.iter().find(|(_, outputs, _)| outputs.contains(&after)))
+                                .map(|(_, _, place)| *place)
+                        },
+                    };
+                    loop_places.extend(place);
+                }
+            }
+
+            //a net without enabled transitions is final, so a self-loop after the end needs a silent transition to leave it
+            if loop_places.contains(&sink) && final_place.is_none() {
+                let place = result.add_place();
+                let transition = result.add_transition(None);
+                result.add_place_transition_arc(sink, transition, 1).unwrap();
+                result.add_transition_place_arc(transition, place, 1).unwrap();
+                final_place = Some(place);
+            }
+
+            //each place gets its own transition, as the places may be alternatives
+            let mut transition = activity_2_transition[activity];
+            for (i, place) in loop_places.into_iter().enumerate() {
+                if i > 0 {
+                    transition = result.add_transition(Some(*activity));
+                }
+                result.add_place_transition_arc(place, transition, 1).unwrap();
+                result.add_transition_place_arc(transition, place, 1).unwrap();
+            }
+        }
+
+        result
+    }
+}
+
+/**
+ * Returns the maximal pairs of sets (A, B), such that each element of A is causally followed by each element of B, and the elements within A and within B are pairwise in choice.
+ */
+fn get_maximal_places(causal: &[Vec<bool>], choice: &[Vec<bool>]) -> Vec<(Vec<usize>, Vec<usize>)> {
+    let n = causal.len();
+
+    let mut candidates = BTreeSet::new();
+    let mut queue = vec![];
+    for a in 0..n {
+        for b in 0..n {
+            if causal[a][b] && choice[a][a] && choice[b][b] {
+                queue.push((vec![a], vec![b]));
+            }
+        }
+    }
+
+    //extend the pairs one activity at a time
+    while let Some((inputs, outputs)) = queue.pop() {
+        if !candidates.insert((inputs.clone(), outputs.clone())) {
+            continue;
+        }
+        for c in 0..n {
+            if !inputs.contains(&c) && inputs.iter().all(|a| choice[*a][c]) && choice[c][c] && outputs.iter().all(|b| causal[c][*b]) {
+                let mut new_inputs = inputs.clone();
+                new_inputs.push(c);
+                new_inputs.sort();
+                queue.push((new_inputs, outputs.clone()));
+            }
+            if !outputs.contains(&c) && outputs.iter().all(|b| choice[*b][c]) && choice[c][c] && inputs.iter().all(|a| causal[*a][c]) {
+                let mut new_outputs = outputs.clone();
+                new_outputs.push(c);
+                new_outputs.sort();
+                queue.push((inputs.clone(), new_outputs));
+            }
+        }
+    }
+
+    //keep the maximal pairs
+    let is_subset = |small: &Vec<usize>, large: &Vec<usize>| small.iter().all(|x| large.contains(x));
+    candidates.iter().filter(|(inputs, outputs)| {
+        !candidates.iter().any(|(other_inputs, other_outputs)| {
+            (other_inputs.len() + other_outputs.len() > inputs.len() + outputs.len()) && is_subset(inputs, other_inputs) && is_subset(outputs, other_outputs)
+        })
+    }).cloned().collect()
+}
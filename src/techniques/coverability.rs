@@ -0,0 +1,229 @@
+use std::{collections::HashMap, fmt::Display};
+use anyhow::{anyhow, Result};
+
+use crate::{ebi_objects::{labelled_petri_net::LabelledPetriNet, stochastic_labelled_petri_net::StochasticLabelledPetriNet}, ebi_traits::ebi_trait_stochastic_semantics::TransitionIndex};
+
+/**
+ * The number of tokens of a place in a marking of the coverability graph that can grow without bound.
+ */
+pub const OMEGA: u64 = u64::MAX;
+
+/**
+ * A Karp-Miller coverability graph. Each node is a marking in which places may carry OMEGA tokens.
+ * If no marking contains OMEGA, the net is bounded and the coverability graph is its reachability graph.
+ */
+pub struct CoverabilityGraph {
+    pub markings: Vec<Vec<u64>>,
+    pub edges: Vec<Vec<(TransitionIndex, usize)>>,
+}
+
+impl CoverabilityGraph {
+    pub fn get_initial_node(&self) -> usize {
+        0
+    }
+
+    pub fn get_number_of_nodes(&self) -> usize {
+        self.markings.len()
+    }
+
+    pub fn is_bounded(&self) -> bool {
+        !self.markings.iter().flatten().any(|tokens| *tokens == OMEGA)
+    }
+
+    /**
+     * Returns the places that have an OMEGA in some marking, sorted.
+     */
+    pub fn get_unbounded_places(&self) -> Vec<usize> {
+        let mut result = vec![];
+        if let Some(marking) = self.markings.first() {
+            for place in 0..marking.len() {
+                if self.markings.iter().any(|marking| marking[place] == OMEGA) {
+                    result.push(place);
+                }
+            }
+        }
+        result
+    }
+
+    /**
+     * Returns for each place the maximum number of tokens it can hold, or None if it is unbounded.
+     */
+    pub fn get_place_bounds(&self) -> Vec<Option<u64>> {
+        let mut result = vec![Some(0); self.markings.first().map_or(0, |marking| marking.len())];
+        for marking in &self.markings {
+            for (bound, tokens) in result.iter_mut().zip(marking.iter()) {
+                if *tokens == OMEGA {
+                    *bound = None;
+                } else if let Some(bound) = bound {
+                    *bound = (*bound).max(*tokens);
+                }
+            }
+        }
+        result
+    }
+
+    /**
+     * Returns for each node whether a node that satisfies the predicate can be reached from it.
+     */
+    pub fn can_reach(&self, target: impl Fn(usize) -> bool) -> Vec<bool> {
+        let mut predecessors = vec![vec![]; self.get_number_of_nodes()];
+        for (source, edges) in self.edges.iter().enumerate() {
+            for (_, target) in edges {
+                predecessors[*target].push(source);
+            }
+        }
+
+        let mut result = (0..self.get_number_of_nodes()).map(|node| target(node)).collect::<Vec<_>>();
+        let mut queue = (0..self.get_number_of_nodes()).filter(|node| result[*node]).collect::<Vec<_>>();
+        while let Some(node) = queue.pop() {
+            for predecessor in &predecessors[node] {
+                if !result[*predecessor] {
+                    result[*predecessor] = true;
+                    queue.push(*predecessor);
+                }
+            }
+        }
+        result
+    }
+
+    pub fn display_marking(marking: &Vec<u64>) -> String {
+        let mut result = "[".to_string();
+        let mut first = true;
+        for (place, tokens) in marking.iter().enumerate() {
+            if *tokens > 0 {
+                if !first {
+                    result.push_str(", ");
+                }
+                first = false;
+                if *tokens == OMEGA {
+                    result.push_str(&format!("p{}:ω", place));
+                } else {
+                    result.push_str(&format!("p{}:{}", place, tokens));
+                }
+            }
+        }
+        result.push(']');
+        result
+    }
+}
+
+impl Display for CoverabilityGraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (node, marking) in self.markings.iter().enumerate() {
+            writeln!(f, "node {}: {}", node, Self::display_marking(marking))?;
+            for (transition, target) in &self.edges[node] {
+                writeln!(f, "\ttransition {} -> node {}", transition, target)?;
+            }
+        }
+        write!(f, "")
+    }
+}
+
+pub trait Coverability {
+    /**
+     * Computes the Karp-Miller coverability graph. This terminates for unbounded nets as well, though the graph may be large.
+     */
+    fn get_coverability_graph(&self) -> Result<CoverabilityGraph>;
+
+    /**
+     * Returns an error that lists the unbounded places if the net is unbounded.
+     */
+    fn verify_bounded(&self) -> Result<()> {
+        let unbounded_places = self.get_coverability_graph()?.get_unbounded_places();
+        if unbounded_places.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!("The net is unbounded: place(s) {} can hold an unbounded number of tokens.", unbounded_places.iter().map(|place| format!("p{}", place)).collect::<Vec<_>>().join(", ")))
+        }
+    }
+}
+
+macro_rules! coverability {
+    ($t:ident, $can_fire:expr) => {
+        impl Coverability for $t {
+            fn get_coverability_graph(&self) -> Result<CoverabilityGraph> {
+                log::info!("compute coverability graph");
+
+                let can_fire: fn(&$t, TransitionIndex) -> bool = $can_fire;
+
+                let mut result = CoverabilityGraph {
+                    markings: vec![self.initial_marking.get_place2token().clone()],
+                    edges: vec![vec![]],
+                };
+                let mut parents: Vec<Option<usize>> = vec![None];
+                let mut marking2node = HashMap::new();
+                marking2node.insert(result.markings[0].clone(), 0);
+
+                let mut queue = vec![0];
+                while let Some(node) = queue.pop() {
+                    for transition in 0..self.transition2input_places.len() {
+                        if !can_fire(self, transition) {
+                            continue;
+                        }
+                        let mut new_marking = match fire(&result.markings[node], &self.transition2input_places[transition], &self.transition2input_places_cardinality[transition], &self.transition2output_places[transition], &self.transition2output_places_cardinality[transition])? {
+                            Some(new_marking) => new_marking,
+                            None => continue,
+                        };
+
+                        //accelerate: if the new marking strictly covers an ancestor, the difference can be pumped without bound
+                        let mut ancestor = Some(node);
+                        while let Some(a) = ancestor {
+                            let ancestor_marking = &result.markings[a];
+                            if ancestor_marking != &new_marking && ancestor_marking.iter().zip(new_marking.iter()).all(|(x, y)| x <= y) {
+                                for (x, y) in ancestor_marking.iter().zip(new_marking.iter_mut()) {
+                                    if x < y {
+                                        *y = OMEGA;
+                                    }
+                                }
+                            }
+                            ancestor = parents[a];
+                        }
+
+                        let target = match marking2node.get(&new_marking) {
+                            Some(target) => *target,
+                            None => {
+                                let target = result.markings.len();
+                                marking2node.insert(new_marking.clone(), target);
+                                result.markings.push(new_marking);
+                                result.edges.push(vec![]);
+                                parents.push(Some(node));
+                                queue.push(target);
+                                target
+                            }
+                        };
+                        result.edges[node].push((transition, target));
+                    }
+                }
+
+                Ok(result)
+            }
+        }
+    };
+}
+
+coverability!(LabelledPetriNet, |_, _| true);
+coverability!(StochasticLabelledPetriNet, |net, transition| net.weights[transition].is_positive()); //transitions without weight never fire
+
+/**
+ * Fires a transition in a marking that may contain OMEGA, or returns None if the transition is not enabled.
+ */
+fn fire(marking: &Vec<u64>, input_places: &Vec<usize>, input_cardinalities: &Vec<u64>, output_places: &Vec<usize>, output_cardinalities: &Vec<u64>) -> Result<Option<Vec<u64>>> {
+    for (place, cardinality) in input_places.iter().zip(input_cardinalities.iter()) {
+        if marking[*place] < *cardinality {
+            return Ok(None);
+        }
+    }
+
+    let mut result = marking.clone();
+    for (place, cardinality) in input_places.iter().zip(input_cardinalities.iter()) {
+        if result[*place] != OMEGA {
+            result[*place] -= cardinality;
+        }
+    }
+    for (place, cardinality) in output_places.iter().zip(output_cardinalities.iter()) {
+        if result[*place] != OMEGA {
+            result[*place] = result[*place].checked_add(*cardinality).filter(|tokens| *tokens != OMEGA).ok_or_else(|| anyhow!("Too many tokens in place {}.", place))?;
+        }
+    }
+    Ok(Some(result))
+}
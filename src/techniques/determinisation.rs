@@ -0,0 +1,289 @@
+use std::collections::{HashMap, VecDeque};
+use anyhow::{anyhow, Result};
+
+use crate::{ebi_framework::{activity_key::Activity, displayable::Displayable}, ebi_objects::deterministic_finite_automaton::DeterministicFiniteAutomaton, ebi_traits::ebi_trait_semantics::{EbiTraitSemantics, Semantics}};
+
+macro_rules! default_max_states {
+    () => {100000};
+}
+pub(crate) use default_max_states;
+
+/**
+ * The default maximum number of states of the semantics that will be explored when determinising.
+ */
+pub const DEFAULT_MAX_STATES: usize = default_max_states!();
+
+pub trait Determinise {
+    /**
+     * Explores the reachability graph, removes silent transitions by taking epsilon-closures, and determinises the result with the subset construction.
+     * Returns an error if more than max_states states of the semantics are reachable, which happens for unbounded models.
+     */
+    fn determinise(&self, max_states: usize) -> Result<DeterministicFiniteAutomaton>;
+}
+
+impl Determinise for EbiTraitSemantics {
+    fn determinise(&self, max_states: usize) -> Result<DeterministicFiniteAutomaton> {
+        match self {
+            EbiTraitSemantics::Usize(sem) => sem.determinise(max_states),
+            EbiTraitSemantics::Marking(sem) => sem.determinise(max_states),
+            EbiTraitSemantics::NodeStates(sem) => sem.determinise(max_states),
+        }
+    }
+}
+
+impl <T, State> Determinise for T where T: Semantics<SemState = State> + ?Sized, State: Displayable {
+    fn determinise(&self, max_states: usize) -> Result<DeterministicFiniteAutomaton> {
+        log::info!("determinise semantics");
+
+        let mut explorer = Explorer {
+            semantics: self,
+            state2index: HashMap::new(),
+            index2state: vec![],
+            max_states: max_states,
+        };
+
+        let mut result = DeterministicFiniteAutomaton::new();
+        result.set_activity_key(self.get_activity_key().clone());
+
+        //a state of the DFA is a sorted set of indices of states of the semantics, closed under silent transitions
+        let initial_state = explorer.index(self.get_initial_state())?;
+        let initial_subset = explorer.closure(vec![initial_state])?;
+
+        let mut subset2state = HashMap::new();
+        subset2state.insert(initial_subset.clone(), result.get_initial_state());
+        let mut queue = VecDeque::new();
+        queue.push_back(initial_subset);
+
+        while let Some(subset) = queue.pop_front() {
+            let dfa_state = *subset2state.get(&subset).ok_or_else(|| anyhow!("Subset not found."))?;
+
+            if subset.iter().any(|state| self.is_final_state(&explorer.index2state[*state])) {
+                result.set_final_state(dfa_state, true);
+            }
+
+            //gather the labelled steps
+            let mut activity2states: HashMap<Activity, Vec<usize>> = HashMap::new();
+            for state in subset.iter() {
+                for transition in self.get_enabled_transitions(&explorer.index2state[*state]) {
+                    if let Some(activity) = self.get_transition_activity(transition) {
+                        let mut new_state = explorer.index2state[*state].clone();
+                        self.execute_transition(&mut new_state, transition)?;
+                        let new_index = explorer.index(new_state)?;
+                        activity2states.entry(activity).or_default().push(new_index);
+                    }
+                }
+            }
+
+            let mut activities = activity2states.keys().cloned().collect::<Vec<_>>();
+            activities.sort();
+            for activity in activities {
+                let new_subset = explorer.closure(activity2states.remove(&activity).unwrap())?;
+                let target = match subset2state.get(&new_subset) {
+                    Some(target) => *target,
+                    None => {
+                        let target = result.add_state();
+                        subset2state.insert(new_subset.clone(), target);
+                        queue.push_back(new_subset);
+                        target
+                    }
+                };
+                result.add_transition(dfa_state, activity, target)?;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+struct Explorer<'a, T, State> where T: Semantics<SemState = State> + ?Sized, State: Displayable {
+    semantics: &'a T,
+    state2index: HashMap<State, usize>,
+    index2state: Vec<State>,
+    max_states: usize,
+}
+
+impl <'a, T, State> Explorer<'a, T, State> where T: Semantics<SemState = State> + ?Sized, State: Displayable {
+    fn index(&mut self, state: State) -> Result<usize> {
+        if let Some(index) = self.state2index.get(&state) {
+            return Ok(*index);
+        }
+        if self.index2state.len() >= self.max_states {
+            return Err(anyhow!("The model has more than {} reachable states. It may be unbounded.", self.max_states));
+        }
+        let index = self.index2state.len();
+        self.state2index.insert(state.clone(), index);
+        self.index2state.push(state);
+        Ok(index)
+    }
+
+    /**
+     * Extends the given states with all states reachable by silent transitions, and returns them sorted.
+     */
+    fn closure(&mut self, mut states: Vec<usize>) -> Result<Vec<usize>> {
+        states.sort();
+        states.dedup();
+        let mut queue = states.clone();
+        while let Some(state) = queue.pop() {
+            for transition in self.semantics.get_enabled_transitions(&self.index2state[state]) {
+                if self.semantics.get_transition_activity(transition).is_none() {
+                    let mut new_state = self.index2state[state].clone();
+                    self.semantics.execute_transition(&mut new_state, transition)?;
+                    let new_index = self.index(new_state)?;
+                    if let Err(position) = states.binary_search(&new_index) {
+                        states.insert(position, new_index);
+                        queue.push(new_index);
+                    }
+                }
+            }
+        }
+        Ok(states)
+    }
+}
+
+impl DeterministicFiniteAutomaton {
+    /**
+     * Returns the minimal DFA with the same language, using Hopcroft's partition refinement.
+     * Unreachable states and states that cannot reach a final state are removed, and the states are numbered in breadth-first order, such that the result is canonical.
+     */
+    pub fn minimise(&self) -> DeterministicFiniteAutomaton {
+        log::info!("minimise DFA");
+
+        //index the reachable states; the last index is an implicit sink that completes the transition function
+        let mut state2index = HashMap::new();
+        let mut index2state = vec![self.get_initial_state()];
+        state2index.insert(self.get_initial_state(), 0);
+        let mut outgoing: Vec<Vec<(Activity, usize)>> = vec![vec![]; self.get_max_state() + 1];
+        for (source, (target, activity)) in self.get_sources().iter().zip(self.get_targets().iter().zip(self.get_activities().iter())) {
+            outgoing[*source].push((*activity, *target));
+        }
+        let mut next = 0;
+        while next < index2state.len() {
+            for (_, target) in &outgoing[index2state[next]] {
+                if !state2index.contains_key(target) {
+                    state2index.insert(*target, index2state.len());
+                    index2state.push(*target);
+                }
+            }
+            next += 1;
+        }
+        let sink = index2state.len();
+        let number_of_states = sink + 1;
+
+        let mut activities = self.get_activities().clone();
+        activities.sort();
+        activities.dedup();
+        let activity2index = activities.iter().enumerate().map(|(i, activity)| (*activity, i)).collect::<HashMap<_, _>>();
+
+        //complete transition function and its inverse
+        let mut delta = vec![vec![sink; activities.len()]; number_of_states];
+        for (index, state) in index2state.iter().enumerate() {
+            for (activity, target) in &outgoing[*state] {
+                delta[index][activity2index[activity]] = state2index[target];
+            }
+        }
+        let mut inverse = vec![vec![vec![]; number_of_states]; activities.len()];
+        for (source, row) in delta.iter().enumerate() {
+            for (activity, target) in row.iter().enumerate() {
+                inverse[activity][*target].push(source);
+            }
+        }
+
+        //initial partition: final and non-final states
+        let is_final = |index: usize| index != sink && self.can_terminate_in_state(index2state[index]);
+        let mut blocks: Vec<Vec<usize>> = vec![];
+        let mut block_of = vec![0; number_of_states];
+        {
+            let (finals, non_finals): (Vec<usize>, Vec<usize>) = (0..number_of_states).partition(|index| is_final(*index));
+            for block in [finals, non_finals] {
+                if !block.is_empty() {
+                    for index in &block {
+                        block_of[*index] = blocks.len();
+                    }
+                    blocks.push(block);
+                }
+            }
+        }
+        let mut in_worklist = vec![true; blocks.len()];
+        let mut worklist = (0..blocks.len()).collect::<Vec<_>>();
+
+        //refine
+        while let Some(splitter) = worklist.pop() {
+            in_worklist[splitter] = false;
+            let splitter_states = blocks[splitter].clone();
+
+            for activity in 0..activities.len() {
+                //gather the predecessors, per block
+                let mut block2predecessors: HashMap<usize, Vec<usize>> = HashMap::new();
+                for state in &splitter_states {
+                    for predecessor in &inverse[activity][*state] {
+                        block2predecessors.entry(block_of[*predecessor]).or_default().push(*predecessor);
+                    }
+                }
+
+                for (block, mut predecessors) in block2predecessors {
+                    predecessors.sort();
+                    predecessors.dedup();
+                    if predecessors.len() == blocks[block].len() {
+                        continue;
+                    }
+
+                    //split the block
+                    let new_block = blocks.len();
+                    blocks[block].retain(|state| predecessors.binary_search(state).is_err());
+                    for state in &predecessors {
+                        block_of[*state] = new_block;
+                    }
+                    blocks.push(predecessors);
+                    in_worklist.push(false);
+
+                    if in_worklist[block] {
+                        in_worklist[new_block] = true;
+                        worklist.push(new_block);
+                    } else {
+                        let smaller = if blocks[new_block].len() <= blocks[block].len() { new_block } else { block };
+                        in_worklist[smaller] = true;
+                        worklist.push(smaller);
+                    }
+                }
+            }
+        }
+
+        //construct the result, numbering blocks breadth-first and leaving out the dead block of the sink
+        let mut result = DeterministicFiniteAutomaton::new();
+        result.set_activity_key(self.activity_key.clone());
+        let dead = block_of[sink];
+        if block_of[0] == dead {
+            //the language is empty
+            return result;
+        }
+
+        let mut block2state = HashMap::new();
+        block2state.insert(block_of[0], result.get_initial_state());
+        let mut queue = VecDeque::new();
+        queue.push_back(block_of[0]);
+        while let Some(block) = queue.pop_front() {
+            let state = block2state[&block];
+            let representative = blocks[block][0];
+            result.set_final_state(state, is_final(representative));
+
+            for (activity_index, target) in delta[representative].iter().enumerate() {
+                let target_block = block_of[*target];
+                if target_block == dead {
+                    continue;
+                }
+                let target_state = match block2state.get(&target_block) {
+                    Some(target_state) => *target_state,
+                    None => {
+                        let target_state = result.add_state();
+                        block2state.insert(target_block, target_state);
+                        queue.push_back(target_block);
+                        target_state
+                    }
+                };
+                result.add_transition(state, activities[activity_index], target_state).unwrap();
+            }
+        }
+
+        result
+    }
+}
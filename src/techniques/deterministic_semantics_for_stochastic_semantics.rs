@@ -9,19 +9,19 @@ use std::collections::hash_map::Entry;
 use crate::ebi_framework::activity_key::Activity;
 use crate::ebi_framework::displayable::Displayable;
 use crate::ebi_objects::labelled_petri_net::LPNMarking;
+use crate::ebi_objects::process_tree_semantics::NodeStates;
 use crate::ebi_objects::stochastic_labelled_petri_net::StochasticLabelledPetriNet;
+use crate::ebi_objects::stochastic_process_tree::StochasticProcessTree;
 use crate::ebi_traits::ebi_trait_semantics::Semantics;
 use crate::ebi_traits::ebi_trait_stochastic_deterministic_semantics::StochasticDeterministicSemantics;
 use crate::ebi_traits::ebi_trait_stochastic_semantics::StochasticSemantics;
 use crate::math::fraction::Fraction;
 use crate::math::markov_model::MarkovModel;
 
-// macro_rules! default_stochastic_deterministic_semantics {
-    // ($t:ident, $s:ident) => {
-        // impl StochasticDeterministicSemantics for $t {
-            // type DetState = PMarking<$s>;
-    impl StochasticDeterministicSemantics for StochasticLabelledPetriNet {
-        type DetState = PMarking<LPNMarking>;
+macro_rules! default_stochastic_deterministic_semantics {
+    ($t:ident, $s:ident) => {
+        impl StochasticDeterministicSemantics for $t {
+            type DetState = PMarking<$s>;
 
             fn get_deterministic_initial_state(&self) -> Result<Self::DetState> {
                 let mut result = Self::DetState {
@@ -32,8 +32,8 @@ use crate::math::markov_model::MarkovModel;
                     activity_2_p_markings: HashMap::new(),
                     activity_2_probability: HashMap::new(),
                 };
-                let initial_state = <StochasticLabelledPetriNet as Semantics>::get_initial_state(self).clone();
-                if <StochasticLabelledPetriNet as Semantics>::is_final_state(self, &initial_state) {
+                let initial_state = <$t as Semantics>::get_initial_state(self).clone();
+                if <$t as Semantics>::is_final_state(self, &initial_state) {
                     result.termination_probability = Fraction::one();
                 }
                 result.p_marking.insert(initial_state, Fraction::one());
@@ -75,12 +75,11 @@ use crate::math::markov_model::MarkovModel;
             }
         }
 
-        // impl $t {
-        impl StochasticLabelledPetriNet {
+        impl $t {
             /**
              * Compute the next q-state.
              */
-            fn compute_next(&self, q_state: &mut PMarking<LPNMarking>) -> Result<()> {
+            fn compute_next(&self, q_state: &mut PMarking<$s>) -> Result<()> {
                 // log::debug!("compute next q-states for {:?}", q_state);
 
                 //create the extended matrix
@@ -176,8 +175,8 @@ use crate::math::markov_model::MarkovModel;
                 }).collect()
             }
 
-            fn create_markov_model(&self, q_state: &PMarking<LPNMarking>) -> Result<MarkovModel<MarkovMarking<LPNMarking>>> {
-                let mut markov: MarkovModel<MarkovMarking<LPNMarking>> = MarkovModel::new();
+            fn create_markov_model(&self, q_state: &PMarking<$s>) -> Result<MarkovModel<MarkovMarking<$s>>> {
+                let mut markov: MarkovModel<MarkovMarking<$s>> = MarkovModel::new();
 
                 let mut queue = vec![];
                 {
@@ -197,12 +196,12 @@ use crate::math::markov_model::MarkovModel;
                     let total_weight = self.get_total_weight_of_enabled_transitions(&marking)?;
 
                     for transition in self.get_enabled_transitions(&marking) {
-                        let probability = self.get_transition_weight(transition) / &total_weight;
+                        let probability = <$t as StochasticSemantics>::get_transition_weight(self, &marking, transition) / &total_weight;
         
                         let mut new_marking = marking.clone();
                         self.execute_transition(&mut new_marking, transition)?;
         
-                        if self.is_transition_silent(transition) {
+                        if <$t as Semantics>::is_transition_silent(self, transition) {
                             //we follow a silent transition   
         
                             if self.is_final_state(&new_marking) {
@@ -232,10 +231,11 @@ use crate::math::markov_model::MarkovModel;
                 Ok(markov)
             }
         }
-    // }
-// }
+    }
+}
 
-// default_stochastic_deterministic_semantics!(StochasticLabelledPetriNet, LPNMarking);
+default_stochastic_deterministic_semantics!(StochasticLabelledPetriNet, LPNMarking);
+default_stochastic_deterministic_semantics!(StochasticProcessTree, NodeStates);
 
 /**
  * Idea: as the computation of next p-states is expensive, it is performed once, and stored in this p-marking struct.
@@ -0,0 +1,98 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{anyhow, Result};
+
+use crate::{ebi_framework::activity_key::{Activity, ActivityKey}, ebi_objects::directly_follows_model::DirectlyFollowsModel, ebi_traits::{ebi_trait_event_log::EbiTraitEventLog, ebi_trait_finite_language::EbiTraitFiniteLanguage}, math::fraction::Fraction};
+
+pub trait DirectlyFollowsMiner {
+    /**
+     * Discovers a directly follows model.
+     * An activity is kept if it occurs at least activity_threshold times as often as the most frequent activity; other activities are removed from the traces.
+     * A start activity, edge or end activity is kept if it occurs at least edge_threshold times as often as the most frequent way to leave its source, where the empty trace counts as leaving the start.
+     */
+    fn mine_directly_follows(&self, activity_threshold: &Fraction, edge_threshold: &Fraction) -> Result<DirectlyFollowsModel>;
+}
+
+impl DirectlyFollowsMiner for dyn EbiTraitEventLog {
+    fn mine_directly_follows(&self, activity_threshold: &Fraction, edge_threshold: &Fraction) -> Result<DirectlyFollowsModel> {
+        let mut activity_key = ActivityKey::new();
+        let traces = (0..self.len()).map(|trace_index| self.read_trace_with_activity_key(&mut activity_key, &trace_index)).collect::<Vec<_>>();
+        mine_directly_follows(activity_key, traces, activity_threshold, edge_threshold)
+    }
+}
+
+impl DirectlyFollowsMiner for dyn EbiTraitFiniteLanguage {
+    fn mine_directly_follows(&self, activity_threshold: &Fraction, edge_threshold: &Fraction) -> Result<DirectlyFollowsModel> {
+        let mut activity_key = ActivityKey::new();
+        let language = self.translate_using_activity_key(&mut activity_key);
+        let traces = (0..language.len()).filter_map(|trace_index| language.get_trace(trace_index).cloned()).collect::<Vec<_>>();
+        mine_directly_follows(activity_key, traces, activity_threshold, edge_threshold)
+    }
+}
+
+fn mine_directly_follows(activity_key: ActivityKey, traces: Vec<Vec<Activity>>, activity_threshold: &Fraction, edge_threshold: &Fraction) -> Result<DirectlyFollowsModel> {
+    for threshold in [activity_threshold, edge_threshold] {
+        if threshold.is_negative() || threshold > &Fraction::one() {
+            return Err(anyhow!("threshold {} should be between 0 and 1", threshold));
+        }
+    }
+    log::info!("discover directly follows model from {} traces", traces.len());
+
+    //filter activities
+    let mut activity_2_count: HashMap<Activity, usize> = HashMap::new();
+    for activity in traces.iter().flatten() {
+        *activity_2_count.entry(*activity).or_insert(0) += 1;
+    }
+    let max_count = activity_2_count.values().max().copied().unwrap_or(0);
+    let minimum = activity_threshold * &Fraction::from(max_count);
+    let kept_activities = activity_2_count.into_iter().filter_map(|(activity, count)| if Fraction::from(count) >= minimum {Some(activity)} else {None}).collect::<HashSet<_>>();
+
+    //create nodes, in order of first appearance
+    let mut node_2_activity = vec![];
+    let mut activity_2_node = HashMap::new();
+    for activity in traces.iter().flatten() {
+        if kept_activities.contains(activity) && !activity_2_node.contains_key(activity) {
+            activity_2_node.insert(*activity, node_2_activity.len());
+            node_2_activity.push(*activity);
+        }
+    }
+
+    //count; the start is source number_of_nodes, and the end is target number_of_nodes
+    let number_of_nodes = node_2_activity.len();
+    let mut counts = vec![vec![0usize; number_of_nodes + 1]; number_of_nodes + 1];
+    for trace in &traces {
+        let mut source = number_of_nodes;
+        for activity in trace {
+            if let Some(target) = activity_2_node.get(activity) {
+                counts[source][*target] += 1;
+                source = *target;
+            }
+        }
+        counts[source][number_of_nodes] += 1;
+    }
+
+    //filter edges
+    let mut edges = vec![vec![false; number_of_nodes]; number_of_nodes];
+    let mut start_nodes = HashSet::new();
+    let mut end_nodes = HashSet::new();
+    let mut empty_traces = false;
+    for source in 0..=number_of_nodes {
+        let max_count = counts[source].iter().max().copied().unwrap_or(0);
+        let minimum = edge_threshold * &Fraction::from(max_count);
+        for target in 0..=number_of_nodes {
+            let count = counts[source][target];
+            if count == 0 || Fraction::from(count) < minimum {
+                continue;
+            }
+
+            match (source == number_of_nodes, target == number_of_nodes) {
+                (true, true) => empty_traces = true,
+                (true, false) => {start_nodes.insert(target);},
+                (false, true) => {end_nodes.insert(source);},
+                (false, false) => edges[source][target] = true,
+            }
+        }
+    }
+
+    Ok(DirectlyFollowsModel::new(activity_key, empty_traces, edges, node_2_activity, start_nodes, end_nodes))
+}
@@ -2,9 +2,9 @@ use crate::distances::DistanceMatrix;
 use crate::optimization_algorithms::network_simplex::NetworkSimplex;
 use crate::{
     ebi_traits::ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage,
-    math::fraction::Fraction,
+    math::{fraction::Fraction, trace_distance::TraceDistance},
 };
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use fraction::BigInt;
 use num_bigint::ToBigInt;
 use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
@@ -15,6 +15,7 @@ pub trait EarthMoversStochasticConformance {
     fn earth_movers_stochastic_conformance(
         &self,
         lang_b: &mut dyn EbiTraitFiniteStochasticLanguage,
+        distance: &TraceDistance,
     ) -> Result<Fraction>;
 }
 
@@ -33,10 +34,11 @@ impl EarthMoversStochasticConformance for dyn EbiTraitFiniteStochasticLanguage {
     /// # Description
     /// Function is called on the first language, the second language is passed as an argument.
     /// Note that the second language is mutable, as the it is necessary to translate it to match the first language.
+    /// The ground distance between traces must be normalised, that is, between 0 and 1.
     ///
     /// # Example
     /// ```
-    /// lang_a.earth_movers_stochastic_conformance(lang_b.as_mut())
+    /// lang_a.earth_movers_stochastic_conformance(lang_b.as_mut(), &TraceDistance::NormalisedLevenshtein)
     /// ```
     ///
     /// # Algorithm
@@ -66,10 +68,15 @@ impl EarthMoversStochasticConformance for dyn EbiTraitFiniteStochasticLanguage {
     fn earth_movers_stochastic_conformance(
         &self,
         lang_b: &mut dyn EbiTraitFiniteStochasticLanguage,
+        distance: &TraceDistance,
     ) -> Result<Fraction> {
+        if !distance.is_normalised() {
+            return Err(anyhow!("Earth Mover's stochastic conformance requires a distance between 0 and 1, which the {} distance is not", distance));
+        }
+
         // 1. Compute all pairwise distances between the traces of the two languages (parallized, see DistanceMatrix).
 
-        let distances = DistanceMatrix::new(self, lang_b);
+        let distances = DistanceMatrix::new(self, lang_b, distance);
         let distances: Vec<Vec<Fraction>> = distances
             .distances
             .par_iter()
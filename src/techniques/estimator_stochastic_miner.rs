@@ -0,0 +1,243 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{ebi_framework::activity_key::{Activity, ActivityKeyTranslator, HasActivityKey}, ebi_objects::{labelled_petri_net::LabelledPetriNet, stochastic_labelled_petri_net::StochasticLabelledPetriNet}, ebi_traits::{ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_semantics::Semantics, ebi_trait_stochastic_semantics::TransitionIndex}, math::fraction::Fraction};
+
+/**
+ * The weight estimators of Burke et al. that are based on frequencies of activities and directly follows pairs of activities in the log.
+ * In all estimators, silent transitions get a weight of 1.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeightEstimator {
+    /**
+     * The frequency of the label of the transition, divided over the transitions with that label.
+     */
+    Frequency,
+
+    /**
+     * The frequency of the pairs in which the label of the transition is on the left-hand side, and the label of a successor transition (or the end of the trace) is on the right-hand side.
+     */
+    LeftHandPair,
+
+    /**
+     * The frequency of the pairs in which the label of the transition is on the right-hand side, and the label of a predecessor transition (or the start of the trace) is on the left-hand side.
+     */
+    RightHandPair,
+
+    /**
+     * For each predecessor of the transition, the fraction of its label's occurrences that is directly followed by the label of the transition, summed.
+     */
+    PairScale,
+
+    /**
+     * For each input place of the transition, the share of the frequency of its label among the frequencies of the labels of all output transitions of the place, averaged.
+     */
+    Fork,
+
+    /**
+     * Triangulates the frequency, left-hand pair and right-hand pair estimators by taking their average.
+     */
+    BillClinton,
+}
+
+pub trait EstimatorStochasticMiner {
+    fn mine_estimator_stochastic(self, language: Box<dyn EbiTraitFiniteStochasticLanguage>, estimator: WeightEstimator) -> StochasticLabelledPetriNet;
+}
+
+impl EstimatorStochasticMiner for LabelledPetriNet {
+    fn mine_estimator_stochastic(mut self, language: Box<dyn EbiTraitFiniteStochasticLanguage>, estimator: WeightEstimator) -> StochasticLabelledPetriNet {
+        let frequencies = Frequencies::new(&mut self, language);
+        let structure = Structure::new(&self);
+
+        let mut weights = vec![];
+        for transition in 0..self.get_number_of_transitions() {
+            match self.get_transition_label(transition) {
+                Some(activity) => weights.push(estimator.estimate(&self, &structure, &frequencies, transition, activity)),
+                None => weights.push(Fraction::one()),
+            }
+        }
+
+        (self, weights).into()
+    }
+}
+
+impl WeightEstimator {
+    fn estimate(&self, net: &LabelledPetriNet, structure: &Structure, frequencies: &Frequencies, transition: TransitionIndex, activity: Activity) -> Fraction {
+        match self {
+            WeightEstimator::Frequency => {
+                let duplicates = (0..net.get_number_of_transitions()).filter(|other| net.get_transition_label(*other) == Some(activity)).count();
+                &frequencies.get_activity(&Some(activity)) / &Fraction::from(duplicates)
+            },
+            WeightEstimator::LeftHandPair => {
+                let mut result = Fraction::zero();
+                for successor in &structure.successors[transition] {
+                    result += &frequencies.get_pair(&Some(activity), successor);
+                }
+                result
+            },
+            WeightEstimator::RightHandPair => {
+                let mut result = Fraction::zero();
+                for predecessor in &structure.predecessors[transition] {
+                    result += &frequencies.get_pair(predecessor, &Some(activity));
+                }
+                result
+            },
+            WeightEstimator::PairScale => {
+                let mut result = Fraction::zero();
+                for predecessor in &structure.predecessors[transition] {
+                    let predecessor_frequency = frequencies.get_activity(predecessor);
+                    if predecessor_frequency.is_positive() {
+                        result += &(&frequencies.get_pair(predecessor, &Some(activity)) / &predecessor_frequency);
+                    }
+                }
+                result
+            },
+            WeightEstimator::Fork => {
+                let frequency = frequencies.get_activity(&Some(activity));
+                let input_places = &net.transition2input_places[transition];
+                if input_places.is_empty() {
+                    return frequency;
+                }
+
+                let mut result = Fraction::zero();
+                for place in input_places {
+                    let mut sum = Fraction::zero();
+                    for other in &net.place2output_transitions[*place] {
+                        match net.get_transition_label(*other) {
+                            Some(other_activity) => sum += &frequencies.get_activity(&Some(other_activity)),
+                            None => sum += &Fraction::one(),
+                        }
+                    }
+                    if sum.is_positive() {
+                        result += &(&frequency / &sum);
+                    }
+                }
+                &result / &Fraction::from(input_places.len())
+            },
+            WeightEstimator::BillClinton => {
+                let mut result = WeightEstimator::Frequency.estimate(net, structure, frequencies, transition, activity);
+                result += &WeightEstimator::LeftHandPair.estimate(net, structure, frequencies, transition, activity);
+                result += &WeightEstimator::RightHandPair.estimate(net, structure, frequencies, transition, activity);
+                &result / &Fraction::from(3)
+            },
+        }
+    }
+}
+
+/**
+ * Frequencies of activities and directly follows pairs in the log, in which None denotes the start or the end of a trace.
+ * The frequency of the start is the size of the log.
+ */
+struct Frequencies {
+    activities: HashMap<Option<Activity>, Fraction>,
+    pairs: HashMap<(Option<Activity>, Option<Activity>), Fraction>,
+}
+
+impl Frequencies {
+    fn new(net: &mut LabelledPetriNet, language: Box<dyn EbiTraitFiniteStochasticLanguage>) -> Self {
+        let translator = ActivityKeyTranslator::new(language.get_activity_key(), net.get_activity_key_mut());
+
+        let mut activities = HashMap::new();
+        let mut pairs = HashMap::new();
+        for (trace, probability) in language.iter_trace_probability() {
+            *activities.entry(None).or_insert_with(Fraction::zero) += probability;
+            let mut previous = None;
+            for log_activity in trace {
+                let activity = Some(translator.translate_activity(log_activity));
+                *activities.entry(activity).or_insert_with(Fraction::zero) += probability;
+                *pairs.entry((previous, activity)).or_insert_with(Fraction::zero) += probability;
+                previous = activity;
+            }
+            *pairs.entry((previous, None)).or_insert_with(Fraction::zero) += probability;
+        }
+
+        Self {
+            activities,
+            pairs,
+        }
+    }
+
+    fn get_activity(&self, activity: &Option<Activity>) -> Fraction {
+        self.activities.get(activity).cloned().unwrap_or_else(Fraction::zero)
+    }
+
+    fn get_pair(&self, from: &Option<Activity>, to: &Option<Activity>) -> Fraction {
+        self.pairs.get(&(*from, *to)).cloned().unwrap_or_else(Fraction::zero)
+    }
+}
+
+/**
+ * For each transition, the labels of the transitions that may directly precede or follow it, looking through silent transitions.
+ * None denotes the start (an initially marked input place) or the end (an output place without output transitions).
+ */
+struct Structure {
+    predecessors: Vec<BTreeSet<Option<Activity>>>,
+    successors: Vec<BTreeSet<Option<Activity>>>,
+}
+
+impl Structure {
+    fn new(net: &LabelledPetriNet) -> Self {
+        let mut place2input_transitions = vec![vec![]; net.get_number_of_places()];
+        for transition in 0..net.get_number_of_transitions() {
+            for place in &net.transition2output_places[transition] {
+                place2input_transitions[*place].push(transition);
+            }
+        }
+
+        let mut predecessors = vec![];
+        let mut successors = vec![];
+        for transition in 0..net.get_number_of_transitions() {
+            let mut result = BTreeSet::new();
+            Self::add_predecessors(net, &place2input_transitions, transition, &mut BTreeSet::new(), &mut result);
+            predecessors.push(result);
+
+            let mut result = BTreeSet::new();
+            Self::add_successors(net, transition, &mut BTreeSet::new(), &mut result);
+            successors.push(result);
+        }
+
+        Self {
+            predecessors,
+            successors,
+        }
+    }
+
+    fn add_predecessors(net: &LabelledPetriNet, place2input_transitions: &[Vec<TransitionIndex>], transition: TransitionIndex, visited: &mut BTreeSet<TransitionIndex>, result: &mut BTreeSet<Option<Activity>>) {
+        for place in &net.transition2input_places[transition] {
+            if net.get_initial_marking().place2token[*place] > 0 {
+                result.insert(None);
+            }
+            for predecessor in &place2input_transitions[*place] {
+                match net.get_transition_label(*predecessor) {
+                    Some(activity) => {
+                        result.insert(Some(activity));
+                    },
+                    None => {
+                        if visited.insert(*predecessor) {
+                            Self::add_predecessors(net, place2input_transitions, *predecessor, visited, result);
+                        }
+                    },
+                }
+            }
+        }
+    }
+
+    fn add_successors(net: &LabelledPetriNet, transition: TransitionIndex, visited: &mut BTreeSet<TransitionIndex>, result: &mut BTreeSet<Option<Activity>>) {
+        for place in &net.transition2output_places[transition] {
+            if net.place2output_transitions[*place].is_empty() {
+                result.insert(None);
+            }
+            for successor in &net.place2output_transitions[*place] {
+                match net.get_transition_label(*successor) {
+                    Some(activity) => {
+                        result.insert(Some(activity));
+                    },
+                    None => {
+                        if visited.insert(*successor) {
+                            Self::add_successors(net, *successor, visited, result);
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
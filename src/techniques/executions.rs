@@ -1,10 +1,10 @@
-use std::{fmt::{Debug, Display}, hash::Hash, sync::{Arc, Mutex}};
+use std::{collections::BTreeMap, fmt::{Debug, Display}, hash::Hash, sync::{Arc, Mutex}};
 
 use anyhow::{Error, Ok, Result};
 use chrono::{DateTime, FixedOffset};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-use crate::{ebi_framework::{displayable::Displayable, ebi_command::EbiCommand}, ebi_objects::{language_of_alignments::Move, executions::{Execution, Executions}}, ebi_traits::{ebi_trait_event_log::{EbiTraitEventLog, ATTRIBUTE_TIME}, ebi_trait_semantics::{EbiTraitSemantics, Semantics}, ebi_trait_stochastic_semantics::TransitionIndex}, techniques::align::Align};
+use crate::{ebi_framework::{displayable::Displayable, ebi_command::EbiCommand}, ebi_objects::{language_of_alignments::Move, executions::{Execution, Executions, Feature}}, ebi_traits::{ebi_trait_event_log::{EbiTraitEventLog, ATTRIBUTE_TIME}, ebi_trait_semantics::{EbiTraitSemantics, Semantics}, ebi_trait_stochastic_semantics::TransitionIndex}, techniques::align::Align};
 
 pub trait FindExecutions {
     fn find_executions(&self, log: Box<dyn EbiTraitEventLog>) -> Result<Executions>;
@@ -95,27 +95,29 @@ impl C {
                     semantics.execute_transition(&mut state, transition)?;
                 },
                 Move::SynchronousMove(_, transition) => {
-                    let enabling_move_index = self.get_enabling_move(transition, semantics);
+                    let enabling_move_index = self.get_enabling_move(move_index, semantics);
 
                     executions.push(Execution{
+                        trace: self.trace_index,
                         transition: transition,
                         enabled_transitions_at_enablement: self.get_enabled_transitions(enabling_move_index, semantics)?,
-                        time_of_enablement: self.get_time(enabling_move_index, log),
+                        time_of_enablement: self.get_time_before(enabling_move_index, log),
                         time_of_execution: self.get_time(Some(move_index), log),
-                        features_at_enablement: None,
+                        features_at_enablement: Some(self.get_features_before(enabling_move_index, log)),
                     });
 
                     semantics.execute_transition(&mut state, transition)?;
                 },
                 Move::SilentMove(transition) => {
-                    let enabling_move_index = self.get_enabling_move(transition, semantics);
+                    let enabling_move_index = self.get_enabling_move(move_index, semantics);
 
                     executions.push(Execution{
+                        trace: self.trace_index,
                         transition: transition,
                         enabled_transitions_at_enablement: self.get_enabled_transitions(enabling_move_index, semantics)?,
-                        time_of_enablement: self.get_time(enabling_move_index, log),
+                        time_of_enablement: self.get_time_before(enabling_move_index, log),
                         time_of_execution: None,
-                        features_at_enablement: None,
+                        features_at_enablement: Some(self.get_features_before(enabling_move_index, log)),
                     });
 
                     semantics.execute_transition(&mut state, transition)?;
@@ -126,6 +128,40 @@ impl C {
         Ok(executions)
     }
 
+    /**
+     * Get the time of the last event before the move at the given index.
+     */
+    fn get_time_before(&self, move_index: Option<usize>, log: &Box<dyn EbiTraitEventLog>) -> Option<DateTime<FixedOffset>> {
+        let event_index = self.get_event_index(move_index?).checked_sub(1)?;
+
+        log.get_event_attribute_time(self.trace_index, event_index, &ATTRIBUTE_TIME.to_string())
+    }
+
+    /**
+     * Get the trace attributes, and the last value of each event attribute of the events before the move at the given index.
+     */
+    fn get_features_before(&self, move_index: Option<usize>, log: &Box<dyn EbiTraitEventLog>) -> BTreeMap<String, Feature> {
+        let mut result = BTreeMap::new();
+        let trace = &log.get_log().traces[self.trace_index];
+
+        for attribute in &trace.attributes {
+            if let Some(feature) = Feature::from_attribute_value(&attribute.value) {
+                result.insert(format!("trace:{}", attribute.key), feature);
+            }
+        }
+
+        let number_of_events = move_index.map_or(0, |move_index| self.get_event_index(move_index));
+        for event in trace.events.iter().take(number_of_events) {
+            for attribute in &event.attributes {
+                if let Some(feature) = Feature::from_attribute_value(&attribute.value) {
+                    result.insert(format!("event:{}", attribute.key), feature);
+                }
+            }
+        }
+
+        result
+    }
+
     fn get_time(&self, move_index: Option<usize>, log: &Box<dyn EbiTraitEventLog>) -> Option<DateTime<FixedOffset>> {
         let event_index = self.get_event_index(move_index?);
 
@@ -158,7 +194,10 @@ impl C {
     }
 
     /**
-     * Get the last non-silent move that enabled the move at the given index
+     * Get the index of the first move from which on the transition of the move at the given index is enabled continuously, up to that move.
+     * That is, the transition was enabled by the move just before the returned index, or initially if the returned index is 0.
+     * Silent moves are not skipped: a silent move that enables the transition is its enabling move, such that the transition is among the enabled transitions at enablement.
+     * (Recursing on the silent move would not terminate for the execution of that silent move itself.)
      */
     fn get_enabling_move<T, FS>(&self, move_index: usize, semantics: &T) -> Option<usize> where T: Semantics<SemState = FS> + Send + Sync + ?Sized, FS: Display + Debug + Clone + Hash + Eq {
         let transition = self.moves.get(move_index)?.get_transition()?;
@@ -167,7 +206,7 @@ impl C {
         let mut last_enabled_before_move = None;
         {
             let mut state = semantics.get_initial_state();
-            for (move_index2, move2) in self.moves.iter().take(move_index).enumerate() {
+            for (move_index2, move2) in self.moves.iter().take(move_index + 1).enumerate() {
                 if let Some(transition2) = move2.get_transition() {
 
                     if semantics.get_enabled_transitions(&state).contains(&transition) {
@@ -186,10 +225,6 @@ impl C {
             }
         }
 
-        if let Some(Move::SilentMove(_)) = self.moves.get(last_enabled_before_move?) {
-            self.get_enabling_move(last_enabled_before_move?, semantics)
-        } else {
-            last_enabled_before_move
-        }
+        last_enabled_before_move
     }
 }
\ No newline at end of file
@@ -0,0 +1,103 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+
+use crate::{ebi_objects::{executions::Executions, labelled_petri_net::LabelledPetriNet, stochastic_labelled_petri_net::StochasticLabelledPetriNet}, ebi_traits::ebi_trait_semantics::Semantics, math::fraction::Fraction};
+
+pub const EXECUTIONS_MAXIMUM_ITERATIONS: usize = 1000;
+pub const EXECUTIONS_TOLERANCE: (usize, usize) = (1, 1_000_000_000);
+
+pub trait ExecutionsStochasticMiner {
+    /**
+     * Estimates the weights of the transitions from executions, each of which is a choice of a transition among the transitions that were enabled at its enablement.
+     * The weights maximise the likelihood of these local choices, and are found using minorise-maximise iterations (as for the Bradley-Terry model), starting from uniform weights.
+     * The iterations stop when no weight changes by more than EXECUTIONS_TOLERANCE relative to the weight (or to 1 for weights below 1), or after EXECUTIONS_MAXIMUM_ITERATIONS iterations.
+     * Transitions that are never enabled in an execution keep a weight of 1; transitions that are enabled but never executed get a weight of 0.
+     * The weights do not depend on the features of the executions.
+     */
+    fn mine_executions_stochastic(self, executions: &Executions) -> Result<(StochasticLabelledPetriNet, ExecutionsReport)>;
+
+    //TODO: a mode that learns weights that depend on the features at enablement (e.g. using logistic regression) and exports them as a data-aware model
+}
+
+pub struct ExecutionsReport {
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+impl Display for ExecutionsReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.converged {
+            write!(f, "weights converged after {} iterations", self.iterations)
+        } else {
+            write!(f, "weights did not converge within {} iterations; the weights of the last iteration are used", self.iterations)
+        }
+    }
+}
+
+impl ExecutionsStochasticMiner for LabelledPetriNet {
+    fn mine_executions_stochastic(self, executions: &Executions) -> Result<(StochasticLabelledPetriNet, ExecutionsReport)> {
+        let number_of_transitions = self.get_number_of_transitions();
+
+        //gather the choices
+        let mut choices = vec![];
+        let mut executed = vec![0usize; number_of_transitions];
+        let mut enabled = vec![false; number_of_transitions];
+        for execution in &executions.executions {
+            let mut choice = match &execution.enabled_transitions_at_enablement {
+                Some(choice) => choice.clone(),
+                None => continue,
+            };
+            if !choice.contains(&execution.transition) {
+                choice.push(execution.transition);
+            }
+            if let Some(transition) = choice.iter().find(|transition| **transition >= number_of_transitions) {
+                return Err(anyhow!("the executions refer to transition {}, while the model has {} transitions", transition, number_of_transitions));
+            }
+
+            executed[execution.transition] += 1;
+            for transition in &choice {
+                enabled[*transition] = true;
+            }
+            choices.push(choice);
+        }
+        if choices.is_empty() {
+            return Err(anyhow!("none of the executions records its enabled transitions, so there are no choices to estimate weights from"));
+        }
+
+        let tolerance = Fraction::from(EXECUTIONS_TOLERANCE);
+        let mut weights = vec![Fraction::one(); number_of_transitions];
+        let mut converged = false;
+        let mut iterations = 0;
+        while !converged && iterations < EXECUTIONS_MAXIMUM_ITERATIONS {
+            //for each transition, sum the inverse total weight of the choices in which it is enabled
+            let mut denominators = vec![Fraction::zero(); number_of_transitions];
+            for choice in &choices {
+                let mut sum = Fraction::zero();
+                for transition in choice {
+                    sum += &weights[*transition];
+                }
+                let inverse = sum.recip();
+                for transition in choice {
+                    denominators[*transition] += &inverse;
+                }
+            }
+
+            let new_weights = (0..number_of_transitions).map(|transition| {
+                if enabled[transition] {
+                    &Fraction::from(executed[transition]) / &denominators[transition]
+                } else {
+                    Fraction::one()
+                }
+            }).collect::<Vec<_>>();
+
+            converged = weights.iter().zip(new_weights.iter()).all(|(old, new)| {
+                (new - old).abs() <= &tolerance * &old.clone().max(Fraction::one())
+            });
+            weights = new_weights;
+            iterations += 1;
+        }
+
+        Ok(((self, weights).into(), ExecutionsReport { iterations, converged }))
+    }
+}
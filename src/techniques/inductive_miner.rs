@@ -0,0 +1,462 @@
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::{anyhow, Result};
+
+use crate::{ebi_framework::activity_key::Activity, ebi_objects::process_tree::{Node, Operator, ProcessTree}, ebi_traits::ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, math::fraction::Fraction};
+
+/**
+ * A multiset of traces, in which each trace has a weight.
+ */
+type Log = BTreeMap<Vec<Activity>, Fraction>;
+
+pub trait InductiveMiner {
+    /**
+     * Discovers a process tree using the Inductive Miner (IM). The language of the tree contains all traces.
+     */
+    fn mine_inductive(&self) -> ProcessTree;
+
+    /**
+     * Discovers a process tree using the Inductive Miner - infrequent (IMf).
+     * If no cut can be found, the directly follows graph is filtered: edges, start activities and end activities that occur less than noise_threshold times the most frequent alternative are removed, and the traces are split according to the cut that is found on the filtered graph.
+     * Empty traces that occur less than noise_threshold times the total weight are removed.
+     * With a noise threshold of 0, this is equivalent to the Inductive Miner.
+     */
+    fn mine_inductive_infrequent(&self, noise_threshold: &Fraction) -> Result<ProcessTree>;
+}
+
+impl InductiveMiner for dyn EbiTraitFiniteStochasticLanguage {
+    fn mine_inductive(&self) -> ProcessTree {
+        let tree = mine(get_log(self), None);
+        ProcessTree::new(self.get_activity_key().clone(), tree)
+    }
+
+    fn mine_inductive_infrequent(&self, noise_threshold: &Fraction) -> Result<ProcessTree> {
+        if noise_threshold.is_negative() || noise_threshold > &Fraction::one() {
+            return Err(anyhow!("noise threshold {} should be between 0 and 1", noise_threshold));
+        }
+        let tree = mine(get_log(self), Some(noise_threshold));
+        Ok(ProcessTree::new(self.get_activity_key().clone(), tree))
+    }
+}
+
+fn get_log(language: &dyn EbiTraitFiniteStochasticLanguage) -> Log {
+    let mut log = Log::new();
+    for (trace, probability) in language.iter_trace_probability() {
+        *log.entry(trace.clone()).or_insert_with(Fraction::zero) += probability;
+    }
+    log
+}
+
+/**
+ * Returns the nodes of the discovered (sub)tree, in the order of a process tree.
+ */
+fn mine(mut log: Log, noise_threshold: Option<&Fraction>) -> Vec<Node> {
+    //base cases
+    if log.keys().all(|trace| trace.is_empty()) {
+        return vec![Node::Tau];
+    }
+    if log.len() == 1 {
+        if let Some(trace) = log.keys().next() {
+            if trace.len() == 1 {
+                return vec![Node::Activity(trace[0])];
+            }
+        }
+    }
+
+    //empty traces
+    if let Some(empty_weight) = log.get(&vec![]) {
+        let mut total_weight = Fraction::zero();
+        log.values().for_each(|weight| total_weight += weight);
+
+        let infrequent = match noise_threshold {
+            Some(noise_threshold) => empty_weight < &(noise_threshold * &total_weight),
+            None => false,
+        };
+
+        log.remove(&vec![]);
+        if !infrequent {
+            return operator(Operator::Xor, vec![vec![Node::Tau], mine(log, noise_threshold)]);
+        }
+    }
+
+    //cuts
+    let dfg = DirectlyFollowsGraph::new(&log);
+    if let Some((cut_operator, groups)) = dfg.find_cut() {
+        return mine_cut(&log, &dfg, cut_operator, groups, noise_threshold);
+    }
+
+    //cuts on the filtered directly follows graph
+    if let Some(noise_threshold) = noise_threshold {
+        let filtered_dfg = dfg.filter(noise_threshold);
+        if let Some((cut_operator, groups)) = filtered_dfg.find_cut() {
+            return mine_cut(&log, &filtered_dfg, cut_operator, groups, Some(noise_threshold));
+        }
+    }
+
+    //fall through: an activity that occurs exactly once in each trace
+    if dfg.activities.len() > 1 {
+        for activity in &dfg.activities {
+            if log.keys().all(|trace| trace.iter().filter(|a| *a == activity).count() == 1) {
+                let sublog = project(&log, |a| a != activity);
+                return operator(Operator::Concurrent, vec![vec![Node::Activity(*activity)], mine(sublog, noise_threshold)]);
+            }
+        }
+    }
+
+    //fall through: a tau loop, which splits the traces where an end activity is followed by a start activity
+    let mut sublog = Log::new();
+    let mut split = false;
+    for (trace, weight) in &log {
+        let mut subtrace = vec![];
+        for activity in trace {
+            if let Some(previous) = subtrace.last() {
+                if dfg.is_end(previous) && dfg.is_start(activity) {
+                    *sublog.entry(subtrace).or_insert_with(Fraction::zero) += weight;
+                    subtrace = vec![];
+                    split = true;
+                }
+            }
+            subtrace.push(*activity);
+        }
+        *sublog.entry(subtrace).or_insert_with(Fraction::zero) += weight;
+    }
+    if split {
+        return operator(Operator::Loop, vec![mine(sublog, noise_threshold), vec![Node::Tau]]);
+    }
+
+    //fall through: a flower model
+    let body = if dfg.activities.len() == 1 {
+        vec![Node::Activity(dfg.activities[0])]
+    } else {
+        operator(Operator::Xor, dfg.activities.iter().map(|activity| vec![Node::Activity(*activity)]).collect())
+    };
+    operator(Operator::Loop, vec![body, vec![Node::Tau]])
+}
+
+fn operator(operator: Operator, children: Vec<Vec<Node>>) -> Vec<Node> {
+    let mut result = vec![Node::Operator(operator, children.len())];
+    children.into_iter().for_each(|child| result.extend(child));
+    result
+}
+
+fn mine_cut(log: &Log, dfg: &DirectlyFollowsGraph, cut_operator: Operator, groups: Vec<Vec<usize>>, noise_threshold: Option<&Fraction>) -> Vec<Node> {
+    let mut activity_2_group = HashMap::new();
+    for (group_index, group) in groups.iter().enumerate() {
+        for activity_index in group {
+            activity_2_group.insert(dfg.activities[*activity_index], group_index);
+        }
+    }
+
+    let mut sublogs = vec![Log::new(); groups.len()];
+    match cut_operator {
+        Operator::Xor => {
+            //each trace goes to the group that has most of its activities; other activities are removed
+            for (trace, weight) in log {
+                let mut counts = vec![0usize; groups.len()];
+                trace.iter().for_each(|activity| counts[activity_2_group[activity]] += 1);
+                let group = (0..groups.len()).rev().max_by_key(|group| counts[*group]).unwrap();
+                let subtrace = trace.iter().filter(|activity| activity_2_group[*activity] == group).cloned().collect();
+                *sublogs[group].entry(subtrace).or_insert_with(Fraction::zero) += weight;
+            }
+        },
+        Operator::Sequence | Operator::Concurrent => {
+            //project each trace on each group
+            for (group, sublog) in sublogs.iter_mut().enumerate() {
+                *sublog = project(log, |activity| activity_2_group[activity] == group);
+            }
+        },
+        Operator::Loop => {
+            //each maximal part of a trace within one group is a trace of that group
+            for (trace, weight) in log {
+                let mut subtrace = vec![];
+                let mut current_group = 0;
+                for activity in trace {
+                    let group = activity_2_group[activity];
+                    if group != current_group && !subtrace.is_empty() {
+                        *sublogs[current_group].entry(subtrace).or_insert_with(Fraction::zero) += weight;
+                        subtrace = vec![];
+                    }
+                    current_group = group;
+                    subtrace.push(*activity);
+                }
+                *sublogs[current_group].entry(subtrace).or_insert_with(Fraction::zero) += weight;
+            }
+        },
+        Operator::Interleaved | Operator::Or => unreachable!()
+    }
+
+    operator(cut_operator, sublogs.into_iter().map(|sublog| mine(sublog, noise_threshold)).collect())
+}
+
+fn project(log: &Log, keep: impl Fn(&Activity) -> bool) -> Log {
+    let mut result = Log::new();
+    for (trace, weight) in log {
+        let subtrace = trace.iter().filter(|activity| keep(activity)).cloned().collect();
+        *result.entry(subtrace).or_insert_with(Fraction::zero) += weight;
+    }
+    result
+}
+
+struct DirectlyFollowsGraph {
+    activities: Vec<Activity>,
+    activity_2_index: HashMap<Activity, usize>,
+    edges: Vec<Vec<Fraction>>,
+    starts: Vec<Fraction>,
+    ends: Vec<Fraction>,
+}
+
+impl DirectlyFollowsGraph {
+    fn new(log: &Log) -> Self {
+        let mut activities = log.keys().flatten().cloned().collect::<Vec<_>>();
+        activities.sort();
+        activities.dedup();
+        let activity_2_index = activities.iter().enumerate().map(|(index, activity)| (*activity, index)).collect::<HashMap<_, _>>();
+
+        let mut edges = vec![vec![Fraction::zero(); activities.len()]; activities.len()];
+        let mut starts = vec![Fraction::zero(); activities.len()];
+        let mut ends = vec![Fraction::zero(); activities.len()];
+        for (trace, weight) in log {
+            if let (Some(first), Some(last)) = (trace.first(), trace.last()) {
+                starts[activity_2_index[first]] += weight;
+                ends[activity_2_index[last]] += weight;
+            }
+            for pair in trace.windows(2) {
+                edges[activity_2_index[&pair[0]]][activity_2_index[&pair[1]]] += weight;
+            }
+        }
+
+        Self {
+            activities,
+            activity_2_index,
+            edges,
+            starts,
+            ends,
+        }
+    }
+
+    /**
+     * Removes each edge that is less frequent than noise_threshold times the most frequent edge from its source, and similarly for start and end activities.
+     */
+    fn filter(&self, noise_threshold: &Fraction) -> Self {
+        let filter = |weights: &Vec<Fraction>| {
+            let minimum = noise_threshold * weights.iter().max().unwrap_or(&Fraction::zero());
+            weights.iter().map(|weight| if weight < &minimum { Fraction::zero() } else { weight.clone() }).collect::<Vec<_>>()
+        };
+
+        Self {
+            activities: self.activities.clone(),
+            activity_2_index: self.activity_2_index.clone(),
+            edges: self.edges.iter().map(&filter).collect(),
+            starts: filter(&self.starts),
+            ends: filter(&self.ends),
+        }
+    }
+
+    fn is_start(&self, activity: &Activity) -> bool {
+        self.starts[self.activity_2_index[activity]].is_positive()
+    }
+
+    fn is_end(&self, activity: &Activity) -> bool {
+        self.ends[self.activity_2_index[activity]].is_positive()
+    }
+
+    fn has_edge(&self, source: usize, target: usize) -> bool {
+        self.edges[source][target].is_positive()
+    }
+
+    fn find_cut(&self) -> Option<(Operator, Vec<Vec<usize>>)> {
+        if let Some(groups) = self.find_xor_cut() {
+            return Some((Operator::Xor, groups));
+        }
+        if let Some(groups) = self.find_sequence_cut() {
+            return Some((Operator::Sequence, groups));
+        }
+        if let Some(groups) = self.find_concurrent_cut() {
+            return Some((Operator::Concurrent, groups));
+        }
+        if let Some(groups) = self.find_loop_cut() {
+            return Some((Operator::Loop, groups));
+        }
+        None
+    }
+
+    /**
+     * The connected components of the graph, ignoring the direction of edges.
+     */
+    fn find_xor_cut(&self) -> Option<Vec<Vec<usize>>> {
+        let all = (0..self.activities.len()).collect::<Vec<_>>();
+        let groups = components(&all, |a, b| self.has_edge(a, b) || self.has_edge(b, a));
+        if groups.len() > 1 {
+            Some(groups)
+        } else {
+            None
+        }
+    }
+
+    /**
+     * The strongly connected components, of which pairwise unreachable ones are merged, in order of reachability.
+     */
+    fn find_sequence_cut(&self) -> Option<Vec<Vec<usize>>> {
+        let reachable = self.get_reachability();
+        let reaches = |group_a: &Vec<usize>, group_b: &Vec<usize>| group_a.iter().any(|a| group_b.iter().any(|b| reachable[*a][*b]));
+
+        let all = (0..self.activities.len()).collect::<Vec<_>>();
+        let mut groups = components(&all, |a, b| reachable[a][b] && reachable[b][a]);
+
+        //merge pairwise unreachable groups
+        'outer: loop {
+            for i in 0..groups.len() {
+                for j in i + 1..groups.len() {
+                    if !reaches(&groups[i], &groups[j]) && !reaches(&groups[j], &groups[i]) {
+                        let group_j = groups.remove(j);
+                        groups[i].extend(group_j);
+                        groups[i].sort();
+                        continue 'outer;
+                    }
+                }
+            }
+            break;
+        }
+        if groups.len() < 2 {
+            return None;
+        }
+
+        //order the groups: earlier groups reach more groups
+        groups.sort_by_cached_key(|group| std::cmp::Reverse(group.iter().map(|a| reachable[*a].iter().filter(|b| **b).count()).max()));
+        for i in 0..groups.len() {
+            for j in i + 1..groups.len() {
+                for a in &groups[i] {
+                    for b in &groups[j] {
+                        if !reachable[*a][*b] || reachable[*b][*a] {
+                            return None;
+                        }
+                    }
+                }
+            }
+        }
+        Some(groups)
+    }
+
+    /**
+     * The connected components of the inverted graph, such that each component has a start and an end activity.
+     */
+    fn find_concurrent_cut(&self) -> Option<Vec<Vec<usize>>> {
+        let all = (0..self.activities.len()).collect::<Vec<_>>();
+        let components = components(&all, |a, b| !self.has_edge(a, b) || !self.has_edge(b, a));
+
+        //components without a start or end activity are merged into another component
+        let (mut groups, incomplete): (Vec<_>, Vec<_>) = components.into_iter().partition(|group| {
+            group.iter().any(|a| self.starts[*a].is_positive()) && group.iter().any(|a| self.ends[*a].is_positive())
+        });
+        if groups.len() < 2 {
+            return None;
+        }
+        groups[0].extend(incomplete.into_iter().flatten());
+        groups[0].sort();
+        Some(groups)
+    }
+
+    /**
+     * The body contains the start and end activities; each other component is a redo part if it is entered only from all end activities and left only to all start activities.
+     */
+    fn find_loop_cut(&self) -> Option<Vec<Vec<usize>>> {
+        let is_start = |a: usize| self.starts[a].is_positive();
+        let is_end = |a: usize| self.ends[a].is_positive();
+
+        let mut body = (0..self.activities.len()).filter(|a| is_start(*a) || is_end(*a)).collect::<Vec<_>>();
+        let others = (0..self.activities.len()).filter(|a| !is_start(*a) && !is_end(*a)).collect::<Vec<_>>();
+
+        let mut redos = vec![];
+        for component in components(&others, |a, b| self.has_edge(a, b) || self.has_edge(b, a)) {
+            let mut entered = false;
+            let mut left = false;
+            let mut is_redo = true;
+            for c in &component {
+                for a in 0..self.activities.len() {
+                    if self.has_edge(a, *c) && !component.contains(&a) {
+                        //edge into the component
+                        if !is_end(a) {
+                            is_redo = false;
+                        }
+                        entered = true;
+                    }
+                    if self.has_edge(*c, a) && !component.contains(&a) {
+                        //edge out of the component
+                        if !is_start(a) {
+                            is_redo = false;
+                        }
+                        left = true;
+                    }
+                }
+
+                //an entry must be reachable from all end activities, and an exit must reach all start activities
+                let all_ends = (0..self.activities.len()).filter(|e| is_end(*e)).all(|e| self.has_edge(e, *c));
+                let any_end = (0..self.activities.len()).filter(|e| is_end(*e)).any(|e| self.has_edge(e, *c));
+                let all_starts = (0..self.activities.len()).filter(|s| is_start(*s)).all(|s| self.has_edge(*c, s));
+                let any_start = (0..self.activities.len()).filter(|s| is_start(*s)).any(|s| self.has_edge(*c, s));
+                if (any_end && !all_ends) || (any_start && !all_starts) {
+                    is_redo = false;
+                }
+            }
+
+            if is_redo && entered && left {
+                redos.push(component);
+            } else {
+                body.extend(component);
+            }
+        }
+
+        if redos.is_empty() || body.is_empty() {
+            return None;
+        }
+        body.sort();
+        let mut groups = vec![body];
+        groups.extend(redos);
+        Some(groups)
+    }
+
+    fn get_reachability(&self) -> Vec<Vec<bool>> {
+        let n = self.activities.len();
+        let mut result = vec![vec![false; n]; n];
+        for source in 0..n {
+            let mut queue = vec![source];
+            while let Some(node) = queue.pop() {
+                for target in 0..n {
+                    if self.has_edge(node, target) && !result[source][target] {
+                        result[source][target] = true;
+                        queue.push(target);
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+/**
+ * The connected components of the given nodes, where connected(a, b) is assumed to be symmetric.
+ */
+pub(crate) fn components(nodes: &[usize], connected: impl Fn(usize, usize) -> bool) -> Vec<Vec<usize>> {
+    let mut result: Vec<Vec<usize>> = vec![];
+    let mut visited = vec![false; nodes.len()];
+    for start in 0..nodes.len() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        let mut component = vec![];
+        let mut queue = vec![start];
+        while let Some(node) = queue.pop() {
+            component.push(nodes[node]);
+            for other in 0..nodes.len() {
+                if !visited[other] && connected(nodes[node], nodes[other]) {
+                    visited[other] = true;
+                    queue.push(other);
+                }
+            }
+        }
+        component.sort();
+        result.push(component);
+    }
+    result
+}
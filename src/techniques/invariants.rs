@@ -0,0 +1,163 @@
+use std::fmt::Display;
+use anyhow::{anyhow, Result};
+
+use crate::{ebi_framework::activity_key::HasActivityKey, ebi_objects::labelled_petri_net::LabelledPetriNet, ebi_traits::ebi_trait_semantics::Semantics};
+
+pub struct InvariantsReport {
+    /**
+     * Minimal place semiflows: weightings of the places of which the weighted token sum is constant.
+     */
+    pub place_semiflows: Vec<Vec<u64>>,
+
+    /**
+     * Minimal transition semiflows: numbers of firings of the transitions that together reproduce the marking.
+     */
+    pub transition_semiflows: Vec<Vec<u64>>,
+    pub(crate) transition_names: Vec<String>,
+}
+
+impl Display for InvariantsReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Minimal place invariants (P-semiflows): {}", self.place_semiflows.len())?;
+        for semiflow in &self.place_semiflows {
+            writeln!(f, "\t{}", Self::semiflow_to_string(semiflow, |place| format!("p{}", place)))?;
+        }
+
+        writeln!(f, "Minimal transition invariants (T-semiflows): {}", self.transition_semiflows.len())?;
+        for semiflow in &self.transition_semiflows {
+            writeln!(f, "\t{}", Self::semiflow_to_string(semiflow, |transition| self.transition_names[transition].clone()))?;
+        }
+
+        write!(f, "")
+    }
+}
+
+impl InvariantsReport {
+    fn semiflow_to_string(semiflow: &Vec<u64>, name: impl Fn(usize) -> String) -> String {
+        semiflow.iter().enumerate().filter(|(_, value)| **value > 0).map(|(index, value)| {
+            if *value == 1 {
+                name(index)
+            } else {
+                format!("{}*{}", value, name(index))
+            }
+        }).collect::<Vec<_>>().join(" + ")
+    }
+}
+
+pub trait Invariants {
+    /**
+     * Computes the minimal place semiflows, that is, the minimal non-negative integer vectors y with y * C = 0, in which C is the incidence matrix.
+     */
+    fn get_place_semiflows(&self) -> Result<Vec<Vec<u64>>>;
+
+    /**
+     * Computes the minimal transition semiflows, that is, the minimal non-negative integer vectors x with C * x = 0, in which C is the incidence matrix.
+     */
+    fn get_transition_semiflows(&self) -> Result<Vec<Vec<u64>>>;
+
+    fn get_invariants(&self) -> Result<InvariantsReport>;
+}
+
+impl Invariants for LabelledPetriNet {
+    fn get_place_semiflows(&self) -> Result<Vec<Vec<u64>>> {
+        //rows are places, columns are transitions
+        let mut incidence = vec![vec![0; self.get_number_of_transitions()]; self.get_number_of_places()];
+        for transition in 0..self.get_number_of_transitions() {
+            for (place, value) in self.incidence_vector(transition).into_iter().enumerate() {
+                incidence[place][transition] = value;
+            }
+        }
+        farkas(incidence, self.get_number_of_transitions())
+    }
+
+    fn get_transition_semiflows(&self) -> Result<Vec<Vec<u64>>> {
+        //rows are transitions, columns are places
+        let incidence = (0..self.get_number_of_transitions()).map(|transition| self.incidence_vector(transition)).collect();
+        farkas(incidence, self.get_number_of_places())
+    }
+
+    fn get_invariants(&self) -> Result<InvariantsReport> {
+        Ok(InvariantsReport {
+            place_semiflows: self.get_place_semiflows()?,
+            transition_semiflows: self.get_transition_semiflows()?,
+            transition_names: (0..self.get_number_of_transitions()).map(|transition| match self.get_transition_label(transition) {
+                Some(activity) => format!("t{} ({})", transition, self.get_activity_key().get_activity_label(&activity)),
+                None => format!("t{} (silent)", transition),
+            }).collect(),
+        })
+    }
+}
+
+/**
+ * The Farkas algorithm: computes the minimal non-negative integer combinations of the rows of the matrix that sum to zero.
+ * Each row is extended with an identity part that records the combination. Column by column, rows are combined pairwise to cancel that column, after which the rows with a non-zero value in that column are dropped.
+ * Rows of which the support of the combination strictly includes the support of another row are not minimal and are removed in each step.
+ */
+fn farkas(matrix: Vec<Vec<i128>>, number_of_columns: usize) -> Result<Vec<Vec<u64>>> {
+    let number_of_rows = matrix.len();
+    let mut rows = matrix.into_iter().enumerate().map(|(index, row)| {
+        let mut combination = vec![0; number_of_rows];
+        combination[index] = 1;
+        (row, combination)
+    }).collect::<Vec<_>>();
+
+    for column in 0..number_of_columns {
+        let (mut next_rows, non_zero): (Vec<_>, Vec<_>) = rows.into_iter().partition(|(row, _)| row[column] == 0);
+
+        for (i, (row1, combination1)) in non_zero.iter().enumerate() {
+            for (row2, combination2) in non_zero.iter().skip(i + 1) {
+                if row1[column].signum() == row2[column].signum() {
+                    continue;
+                }
+
+                //combine the rows such that the column cancels out
+                let factor1 = row2[column].abs();
+                let factor2 = row1[column].abs();
+                let mut row = vec![0; number_of_columns];
+                for (value, (value1, value2)) in row.iter_mut().zip(row1.iter().zip(row2.iter())) {
+                    *value = factor1.checked_mul(*value1).zip(factor2.checked_mul(*value2)).and_then(|(a, b)| a.checked_add(b)).ok_or_else(|| anyhow!("Overflow while computing semiflows."))?;
+                }
+                let mut combination = vec![0; number_of_rows];
+                for (value, (value1, value2)) in combination.iter_mut().zip(combination1.iter().zip(combination2.iter())) {
+                    *value = factor1.checked_mul(*value1).zip(factor2.checked_mul(*value2)).and_then(|(a, b)| a.checked_add(b)).ok_or_else(|| anyhow!("Overflow while computing semiflows."))?;
+                }
+
+                normalise(&mut row, &mut combination);
+                next_rows.push((row, combination));
+            }
+        }
+
+        rows = remove_non_minimal(next_rows);
+    }
+
+    let mut result = rows.into_iter().map(|(_, combination)| combination.into_iter().map(|value| u64::try_from(value)).collect::<std::result::Result<Vec<_>, _>>()).collect::<std::result::Result<Vec<_>, _>>()?;
+    result.sort();
+    Ok(result)
+}
+
+/**
+ * Divides both parts of a row by their greatest common divisor.
+ */
+fn normalise(row: &mut Vec<i128>, combination: &mut Vec<i128>) {
+    let divisor = row.iter().chain(combination.iter()).fold(0, |divisor, value| gcd(divisor, value.abs()));
+    if divisor > 1 {
+        row.iter_mut().chain(combination.iter_mut()).for_each(|value| *value /= divisor);
+    }
+}
+
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn remove_non_minimal(mut rows: Vec<(Vec<i128>, Vec<i128>)>) -> Vec<(Vec<i128>, Vec<i128>)> {
+    rows.sort();
+    rows.dedup();
+
+    let supports = rows.iter().map(|(_, combination)| combination.iter().map(|value| *value != 0).collect::<Vec<_>>()).collect::<Vec<_>>();
+    let is_strict_subset = |small: &Vec<bool>, large: &Vec<bool>| small != large && small.iter().zip(large.iter()).all(|(s, l)| !s || *l);
+
+    rows.into_iter().enumerate().filter(|(index, _)| !supports.iter().any(|other| is_strict_subset(other, &supports[*index]))).map(|(_, row)| row).collect()
+}
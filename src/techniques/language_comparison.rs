@@ -0,0 +1,114 @@
+use std::collections::{HashMap, VecDeque};
+use anyhow::Result;
+
+use crate::{ebi_framework::activity_key::{Activity, ActivityKeyTranslator}, ebi_objects::{deterministic_finite_automaton::DeterministicFiniteAutomaton, finite_language::FiniteLanguage}, ebi_traits::ebi_trait_semantics::EbiTraitSemantics};
+
+use super::determinisation::{Determinise, DEFAULT_MAX_STATES};
+
+pub trait LanguageComparison {
+    /**
+     * Checks whether the language of this model is included in the language of the other model.
+     * Returns an empty language if so, and otherwise a language with a shortest trace of this model that the other model does not support.
+     */
+    fn check_inclusion(&self, other: &Self) -> Result<FiniteLanguage>;
+
+    /**
+     * Checks whether the languages of the models are equal.
+     * Returns an empty language if so, and otherwise a language with a shortest trace that is supported by one model but not by the other.
+     */
+    fn check_equivalence(&self, other: &Self) -> Result<FiniteLanguage>;
+}
+
+impl LanguageComparison for EbiTraitSemantics {
+    fn check_inclusion(&self, other: &Self) -> Result<FiniteLanguage> {
+        let dfa1 = self.determinise(DEFAULT_MAX_STATES)?.minimise();
+        let dfa2 = other.determinise(DEFAULT_MAX_STATES)?.minimise();
+        Ok(dfa1.find_counterexample(&dfa2, false))
+    }
+
+    fn check_equivalence(&self, other: &Self) -> Result<FiniteLanguage> {
+        let dfa1 = self.determinise(DEFAULT_MAX_STATES)?.minimise();
+        let dfa2 = other.determinise(DEFAULT_MAX_STATES)?.minimise();
+        Ok(dfa1.find_counterexample(&dfa2, true))
+    }
+}
+
+impl DeterministicFiniteAutomaton {
+    /**
+     * Searches the product of the two automata breadth-first for a shortest trace that is accepted by this automaton but not by the other (or, if symmetric, by exactly one of them).
+     * Returns a language with that trace, or an empty language if there is no such trace.
+     */
+    pub fn find_counterexample(&self, other: &DeterministicFiniteAutomaton, symmetric: bool) -> FiniteLanguage {
+        log::info!("search the product of two DFAs for a counterexample");
+
+        //bring the activities of the other automaton into a common activity key
+        let mut activity_key = self.activity_key.clone();
+        let translator = ActivityKeyTranslator::new(&other.activity_key, &mut activity_key);
+
+        let mut step1 = HashMap::new();
+        for (source, (target, activity)) in self.get_sources().iter().zip(self.get_targets().iter().zip(self.get_activities().iter())) {
+            step1.entry(*source).or_insert_with(Vec::new).push((*activity, *target));
+        }
+        let mut step2 = HashMap::new();
+        for (source, (target, activity)) in other.get_sources().iter().zip(other.get_targets().iter().zip(other.get_activities().iter())) {
+            step2.insert((*source, translator.translate_activity(activity)), *target);
+        }
+        let mut activities2 = HashMap::new();
+        for (source, activity) in step2.keys() {
+            activities2.entry(*source).or_insert_with(Vec::new).push(*activity);
+        }
+
+        //states of the product; None denotes the rejecting sink of an automaton
+        let initial = (Some(self.initial_state), Some(other.initial_state));
+        let mut parent: HashMap<(Option<usize>, Option<usize>), Option<((Option<usize>, Option<usize>), Activity)>> = HashMap::new();
+        parent.insert(initial, None);
+        let mut queue = VecDeque::new();
+        queue.push_back(initial);
+
+        while let Some(state) = queue.pop_front() {
+            let (state1, state2) = state;
+            let final1 = state1.map_or(false, |s| self.can_terminate_in_state(s));
+            let final2 = state2.map_or(false, |s| other.can_terminate_in_state(s));
+
+            if (final1 && !final2) || (symmetric && final2 && !final1) {
+                //reconstruct the trace
+                let mut trace = vec![];
+                let mut current = state;
+                while let Some(Some((previous, activity))) = parent.get(&current) {
+                    trace.push(*activity);
+                    current = *previous;
+                }
+                trace.reverse();
+
+                let mut traces = FiniteLanguage::new_hashmap();
+                traces.insert(trace);
+                return (activity_key, traces).into();
+            }
+
+            //gather the outgoing activities
+            let mut activities = vec![];
+            if let Some(s) = state1 {
+                activities.extend(step1.get(&s).into_iter().flatten().map(|(activity, _)| *activity));
+            }
+            if symmetric {
+                if let Some(s) = state2 {
+                    activities.extend(activities2.get(&s).into_iter().flatten().cloned());
+                }
+            }
+            activities.sort();
+            activities.dedup();
+
+            for activity in activities {
+                let new_state1 = state1.and_then(|s| step1.get(&s).and_then(|steps| steps.iter().find(|(a, _)| *a == activity).map(|(_, target)| *target)));
+                let new_state2 = state2.and_then(|s| step2.get(&(s, activity)).cloned());
+                let new_state = (new_state1, new_state2);
+                if new_state != (None, None) && !parent.contains_key(&new_state) {
+                    parent.insert(new_state, Some((state, activity)));
+                    queue.push_back(new_state);
+                }
+            }
+        }
+
+        (activity_key, FiniteLanguage::new_hashmap()).into()
+    }
+}
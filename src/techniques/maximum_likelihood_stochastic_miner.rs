@@ -0,0 +1,144 @@
+use std::fmt::Display;
+use anyhow::{anyhow, Context, Result};
+use num_traits::ToPrimitive;
+
+use crate::{ebi_framework::activity_key::{Activity, ActivityKeyTranslator, HasActivityKey}, ebi_objects::{labelled_petri_net::LabelledPetriNet, stochastic_labelled_petri_net::StochasticLabelledPetriNet}, ebi_traits::{ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_queriable_stochastic_language::EbiTraitQueriableStochasticLanguage, ebi_trait_semantics::Semantics}, follower_semantics::FollowerSemantics, math::{fraction::Fraction, log_div::LogDiv}};
+
+use super::entropic_relevance::EntropicRelvance;
+
+pub const MAXIMUM_LIKELIHOOD_ITERATIONS: usize = 100;
+const GRADIENT_STEP: f64 = 1e-6;
+const MINIMUM_STEP_SIZE: f64 = 1e-9;
+
+pub trait MaximumLikelihoodStochasticMiner {
+    /**
+     * Optimises the transition weights to maximise the log-likelihood of the language, by gradient ascent on the logarithms of the weights, starting from uniform weights.
+     * Traces that the model cannot produce do not influence the likelihood, as they are impossible regardless of the weights.
+     * Requires approximate arithmetic.
+     * Returns the net with the optimised weights, and the log-likelihood and entropic relevance before and after optimisation.
+     */
+    fn mine_maximum_likelihood_stochastic(self, language: Box<dyn EbiTraitFiniteStochasticLanguage>) -> Result<(StochasticLabelledPetriNet, MaximumLikelihoodReport)>;
+}
+
+pub struct MaximumLikelihoodReport {
+    pub log_likelihood_before: f64,
+    pub log_likelihood_after: f64,
+    pub entropic_relevance_before: LogDiv,
+    pub entropic_relevance_after: LogDiv,
+}
+
+impl Display for MaximumLikelihoodReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "log-likelihood before optimisation: {}", self.log_likelihood_before)?;
+        writeln!(f, "log-likelihood after optimisation: {}", self.log_likelihood_after)?;
+        writeln!(f, "entropic relevance before optimisation: {}", self.entropic_relevance_before)?;
+        write!(f, "entropic relevance after optimisation: {}", self.entropic_relevance_after)
+    }
+}
+
+impl MaximumLikelihoodStochasticMiner for LabelledPetriNet {
+    fn mine_maximum_likelihood_stochastic(mut self, language: Box<dyn EbiTraitFiniteStochasticLanguage>) -> Result<(StochasticLabelledPetriNet, MaximumLikelihoodReport)> {
+        if Fraction::is_exaxt_globally() {
+            return Err(anyhow!("maximum likelihood weight estimation requires approximate arithmetic"));
+        }
+
+        let translator = ActivityKeyTranslator::new(language.get_activity_key(), self.get_activity_key_mut());
+        let mut traces = vec![];
+        for (trace, probability) in language.iter_trace_probability() {
+            traces.push((translator.translate_trace(trace), to_f64(probability)?));
+        }
+
+        let log_weights = vec![0.0; self.get_number_of_transitions()];
+        let mut slpn: StochasticLabelledPetriNet = (self, vec![Fraction::one(); log_weights.len()]).into();
+
+        //only traces that the model supports matter
+        let mut supported_traces = vec![];
+        for (trace, probability) in traces {
+            if slpn.get_probability(&FollowerSemantics::Trace(&trace))?.is_positive() {
+                supported_traces.push((trace, probability));
+            }
+        }
+        if supported_traces.is_empty() {
+            return Err(anyhow!("the model supports no trace of the language, so there is nothing to optimise"));
+        }
+
+        let log_likelihood_before = get_log_likelihood(&mut slpn, &log_weights, &supported_traces)?;
+        let entropic_relevance_before = language.er(Box::new(slpn.clone())).context("computing entropic relevance")?;
+
+        let (log_weights, log_likelihood_after) = maximise(log_weights, log_likelihood_before, |log_weights| get_log_likelihood(&mut slpn, log_weights, &supported_traces))?;
+
+        set_weights(&mut slpn, &log_weights);
+        let entropic_relevance_after = language.er(Box::new(slpn.clone())).context("computing entropic relevance")?;
+
+        Ok((slpn, MaximumLikelihoodReport {
+            log_likelihood_before,
+            log_likelihood_after,
+            entropic_relevance_before,
+            entropic_relevance_after,
+        }))
+    }
+}
+
+/**
+ * Maximises the function by gradient ascent with a numerical gradient and a backtracking line search, starting from the given point, of which the value is given.
+ * Returns the optimum and its value.
+ */
+pub fn maximise(mut point: Vec<f64>, mut value: f64, mut function: impl FnMut(&[f64]) -> Result<f64>) -> Result<(Vec<f64>, f64)> {
+    let mut step_size = 1.0;
+    for iteration in 0..MAXIMUM_LIKELIHOOD_ITERATIONS {
+        //numerical gradient
+        let mut gradient = vec![0.0; point.len()];
+        for dimension in 0..point.len() {
+            let mut shifted = point.clone();
+            shifted[dimension] += GRADIENT_STEP;
+            gradient[dimension] = (function(&shifted)? - value) / GRADIENT_STEP;
+        }
+
+        //backtracking line search
+        let mut improved = false;
+        while step_size > MINIMUM_STEP_SIZE {
+            let candidate = point.iter().zip(gradient.iter()).map(|(coordinate, slope)| coordinate + step_size * slope).collect::<Vec<_>>();
+            let candidate_value = function(&candidate)?;
+            if candidate_value > value {
+                improved = candidate_value - value > MINIMUM_STEP_SIZE;
+                point = candidate;
+                value = candidate_value;
+                step_size *= 2.0;
+                break;
+            }
+            step_size /= 2.0;
+        }
+
+        log::debug!("iteration {}: value {}", iteration, value);
+        if !improved {
+            break;
+        }
+    }
+    Ok((point, value))
+}
+
+fn to_f64(fraction: &Fraction) -> Result<f64> {
+    match fraction {
+        Fraction::Exact(fraction) => fraction.to_f64(),
+        Fraction::Approx(value) => Some(*value),
+        Fraction::CannotCombineExactAndApprox => None,
+    }.ok_or_else(|| anyhow!("{} cannot be represented as a decimal number", fraction))
+}
+
+/**
+ * The weights are normalised such that their logarithms sum to zero, as scaling all weights does not change the model.
+ */
+fn set_weights(slpn: &mut StochasticLabelledPetriNet, log_weights: &[f64]) {
+    let mean = log_weights.iter().sum::<f64>() / log_weights.len().max(1) as f64;
+    slpn.weights = log_weights.iter().map(|log_weight| Fraction::Approx((log_weight - mean).exp())).collect();
+}
+
+fn get_log_likelihood(slpn: &mut StochasticLabelledPetriNet, log_weights: &[f64], traces: &[(Vec<Activity>, f64)]) -> Result<f64> {
+    set_weights(slpn, log_weights);
+    let mut result = 0.0;
+    for (trace, probability) in traces {
+        let model_probability = to_f64(&slpn.get_probability(&FollowerSemantics::Trace(trace))?)?;
+        result += probability * model_probability.ln();
+    }
+    Ok(result)
+}
@@ -2,23 +2,23 @@ use std::sync::Arc;
 use anyhow::{anyhow, Result};
 use fraction::One;
 
-use crate::{distances::TriangularDistanceMatrix, ebi_objects::finite_language::FiniteLanguage, ebi_traits::ebi_trait_finite_language::EbiTraitFiniteLanguage, math::fraction::Fraction};
+use crate::{distances::TriangularDistanceMatrix, ebi_objects::finite_language::FiniteLanguage, ebi_traits::ebi_trait_finite_language::EbiTraitFiniteLanguage, math::{fraction::Fraction, trace_distance::TraceDistance}};
 
 pub trait MedoidNonStochastic {
-	fn medoid(&self, number_of_traces: usize) -> Result<FiniteLanguage>;
+	fn medoid(&self, number_of_traces: usize, distance: &TraceDistance) -> Result<FiniteLanguage>;
 
 	/**
 	 * Applies an adaption of the FasterPAM algorithm from Fast and Eager k-Medoids Clustering: O(k) Runtime Improvement of the PAM, CLARA, and CLARANS Algorithms?
 	 */
-	fn k_medoids_clustering(&self, number_of_clusters: usize) -> Result<FiniteLanguage>;
+	fn k_medoids_clustering(&self, number_of_clusters: usize, distance: &TraceDistance) -> Result<FiniteLanguage>;
 }
 
 impl <T: ?Sized> MedoidNonStochastic for T where T: EbiTraitFiniteLanguage {
-	fn medoid(&self, number_of_traces: usize) -> Result<FiniteLanguage> {
+	fn medoid(&self, number_of_traces: usize, distance: &TraceDistance) -> Result<FiniteLanguage> {
 		let activity_key = self.get_activity_key().clone();
 		let mut result = FiniteLanguage::new_hashmap();
 
-		let distances = TriangularDistanceMatrix::new(self);
+		let distances = TriangularDistanceMatrix::new(self, self.get_activity_key(), distance);
 
 		if number_of_traces.is_one() {
 			let trace_number = medoid_single(self, &distances);
@@ -67,7 +67,7 @@ impl <T: ?Sized> MedoidNonStochastic for T where T: EbiTraitFiniteLanguage {
 	/**
 	 * Applies an adaption of the FasterPAM algorithm from Fast and Eager k-Medoids Clustering: O(k) Runtime Improvement of the PAM, CLARA, and CLARANS Algorithms?
 	 */
-	fn k_medoids_clustering(&self, number_of_clusters: usize) -> Result<FiniteLanguage> {
+	fn k_medoids_clustering(&self, number_of_clusters: usize, distance: &TraceDistance) -> Result<FiniteLanguage> {
 		//there is a Rust k-medoids crate, but that does not support exact arithmetic
 
 		if self.len() < number_of_clusters {
@@ -77,7 +77,7 @@ impl <T: ?Sized> MedoidNonStochastic for T where T: EbiTraitFiniteLanguage {
 			return Err(anyhow!("No clusters were requested"));
 		}
 
-		let distances = TriangularDistanceMatrix::new(self);
+		let distances = TriangularDistanceMatrix::new(self, self.get_activity_key(), distance);
 		let mut rng = rand::thread_rng();
 		let mut medoids = random_initialization(self.len(), number_of_clusters, &mut rng);
 
@@ -1,22 +1,20 @@
 use std::collections::HashMap;
 
-use crate::{ebi_framework::activity_key::{ActivityKeyTranslator, HasActivityKey}, ebi_objects::{labelled_petri_net::LabelledPetriNet, stochastic_labelled_petri_net::StochasticLabelledPetriNet}, ebi_traits::{ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_semantics::Semantics}, math::fraction::Fraction};
+use anyhow::Result;
+
+use crate::{ebi_framework::activity_key::{Activity, ActivityKey, ActivityKeyTranslator, HasActivityKey}, ebi_objects::{labelled_petri_net::LabelledPetriNet, process_tree::{Node, Operator, ProcessTree}, stochastic_labelled_petri_net::StochasticLabelledPetriNet, stochastic_process_tree::StochasticProcessTree}, ebi_traits::{ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_trait_semantics::Semantics}, math::fraction::Fraction};
 
 pub trait OccurrencesStochasticMiner {
-    fn mine_occurrences_stochastic(self, language: Box<dyn EbiTraitFiniteStochasticLanguage>) -> StochasticLabelledPetriNet;
+    type StochasticModel;
+
+    fn mine_occurrences_stochastic(self, language: Box<dyn EbiTraitFiniteStochasticLanguage>) -> Self::StochasticModel;
 }
 
 impl OccurrencesStochasticMiner for LabelledPetriNet {
+    type StochasticModel = StochasticLabelledPetriNet;
+
     fn mine_occurrences_stochastic(mut self, language: Box<dyn EbiTraitFiniteStochasticLanguage>) -> StochasticLabelledPetriNet {
-        let translator = ActivityKeyTranslator::new(language.get_activity_key(), self.get_activity_key_mut());
-        
-        let mut model_activity2frequency = HashMap::new();
-        for (trace, probability) in language.iter_trace_probability() {
-            for log_activity in trace {
-                let model_activity = translator.translate_activity(log_activity);
-                *model_activity2frequency.entry(model_activity).or_insert(Fraction::zero()) += probability;
-            }
-        }
+        let model_activity2frequency = count_occurrences(self.get_activity_key_mut(), language);
 
         let mut weights: Vec<Fraction> = vec![];
         for transition in 0..self.get_number_of_transitions() {
@@ -32,4 +30,139 @@ impl OccurrencesStochasticMiner for LabelledPetriNet {
 
         (self, weights).into()
     }
-}
\ No newline at end of file
+}
+
+impl OccurrencesStochasticMiner for ProcessTree {
+    type StochasticModel = Result<StochasticProcessTree>;
+
+    /**
+     * Estimates, from the occurrences of the activities, how often each node is executed per trace, and derives the weights of the choices from that.
+     */
+    fn mine_occurrences_stochastic(mut self, language: Box<dyn EbiTraitFiniteStochasticLanguage>) -> Result<StochasticProcessTree> {
+        let model_activity2frequency = count_occurrences(self.get_activity_key_mut(), language);
+
+        //the occurrences of an activity are spread over the leaves with that activity
+        let mut activity2leaves = HashMap::new();
+        for node in &self.transition2node {
+            if let Node::Activity(activity) = self.tree[*node] {
+                *activity2leaves.entry(activity).or_insert(0usize) += 1;
+            }
+        }
+
+        //bottom-up: the executions of each node that can be derived from the activities below it
+        let mut executions: Vec<Option<Fraction>> = vec![None; self.get_number_of_nodes()];
+        for node in (0..self.get_number_of_nodes()).rev() {
+            let known = self.get_children(node).filter_map(|child| executions[child].clone()).collect::<Vec<_>>();
+            executions[node] = match self.tree[node] {
+                Node::Tau => None,
+                Node::Activity(activity) => Some(model_activity2frequency.get(&activity).map_or_else(Fraction::zero, |frequency| frequency / activity2leaves[&activity])),
+                Node::Operator(Operator::Sequence, _) | Node::Operator(Operator::Concurrent, _) | Node::Operator(Operator::Interleaved, _) => {
+                    //each child is executed as often as the node
+                    if known.is_empty() {
+                        None
+                    } else {
+                        Some(&known.iter().sum::<Fraction>() / known.len())
+                    }
+                },
+                Node::Operator(Operator::Xor, _) => {
+                    if known.is_empty() {
+                        None
+                    } else {
+                        Some(known.iter().sum::<Fraction>())
+                    }
+                },
+                Node::Operator(Operator::Or, _) => known.into_iter().max(),
+                Node::Operator(Operator::Loop, _) => {
+                    //the body is executed once more than the redo children
+                    executions[self.get_child(node, 0)].as_ref().map(|body| {
+                        let redo = self.get_children(node).skip(1).filter_map(|child| executions[child].clone()).sum::<Fraction>();
+                        (body - &redo).max(Fraction::zero())
+                    })
+                },
+            };
+        }
+
+        //top-down: the expected executions of each node, starting with one execution of the root
+        let mut expected = vec![Fraction::zero(); self.get_number_of_nodes()];
+        if let Some(root) = expected.first_mut() {
+            *root = Fraction::one();
+        }
+        let mut weights = vec![];
+        for node in 0..self.get_number_of_nodes() {
+            let children = self.get_children(node).collect::<Vec<_>>();
+            let mut node_weights = vec![];
+            match self.tree[node] {
+                Node::Tau | Node::Activity(_) => {},
+                Node::Operator(Operator::Sequence, _) | Node::Operator(Operator::Concurrent, _) | Node::Operator(Operator::Interleaved, _) => {
+                    for child in children {
+                        expected[child] = expected[node].clone();
+                    }
+                },
+                Node::Operator(Operator::Xor, _) => {
+                    node_weights = share(&expected[node], children.iter().map(|child| executions[*child].clone()).collect());
+                    for (child, weight) in children.iter().zip(node_weights.iter()) {
+                        expected[*child] = weight.clone();
+                    }
+                },
+                Node::Operator(Operator::Loop, _) => {
+                    let body = children[0];
+                    let redos = children[1..].iter().map(|child| executions[*child].clone()).collect::<Vec<_>>();
+                    let body_executions = executions[body].clone().unwrap_or_else(|| &expected[node] + &redos.iter().flatten().sum::<Fraction>());
+                    let redo_executions = (&body_executions - &expected[node]).max(Fraction::zero());
+
+                    node_weights.push(expected[node].clone());
+                    node_weights.extend(share(&redo_executions, redos));
+                    expected[body] = body_executions;
+                    for (child, weight) in children[1..].iter().zip(node_weights[1..].iter()) {
+                        expected[*child] = weight.clone();
+                    }
+                },
+                Node::Operator(Operator::Or, _) => {
+                    for child in children {
+                        let probability = match &executions[child] {
+                            Some(child_executions) if expected[node].is_positive() => (child_executions / &expected[node]).min(Fraction::one()),
+                            _ => Fraction::from((1, 2)),
+                        };
+                        expected[child] = &probability * &expected[node];
+                        node_weights.push(probability);
+                    }
+                },
+            }
+
+            //a choice that is never made, or a loop that is never exited, gets uniform weights
+            if node_weights.iter().all(|weight| weight.is_zero()) || node_weights.first().is_some_and(|weight| weight.is_zero()) && matches!(self.tree[node], Node::Operator(Operator::Loop, _)) {
+                node_weights = StochasticProcessTree::get_uniform_weights(&self, node);
+            }
+            weights.push(node_weights);
+        }
+
+        StochasticProcessTree::new(self, weights)
+    }
+}
+
+/**
+ * Returns the known executions of the options, where the options with unknown executions share what remains of the total.
+ */
+fn share(total: &Fraction, options: Vec<Option<Fraction>>) -> Vec<Fraction> {
+    let known = options.iter().flatten().sum::<Fraction>();
+    let number_of_unknown = options.iter().filter(|option| option.is_none()).count();
+    let remainder = if number_of_unknown > 0 {
+        &(total - &known).max(Fraction::zero()) / number_of_unknown
+    } else {
+        Fraction::zero()
+    };
+    options.into_iter().map(|option| option.unwrap_or_else(|| remainder.clone())).collect()
+}
+
+fn count_occurrences(activity_key: &mut ActivityKey, language: Box<dyn EbiTraitFiniteStochasticLanguage>) -> HashMap<Activity, Fraction> {
+    let translator = ActivityKeyTranslator::new(language.get_activity_key(), activity_key);
+    
+    let mut model_activity2frequency = HashMap::new();
+    for (trace, probability) in language.iter_trace_probability() {
+        for log_activity in trace {
+            let model_activity = translator.translate_activity(log_activity);
+            *model_activity2frequency.entry(model_activity).or_insert(Fraction::zero()) += probability;
+        }
+    }
+    model_activity2frequency
+}
@@ -28,6 +28,7 @@ impl ProbabilityQueries for EbiTraitStochasticDeterministicSemantics {
         match self {
             EbiTraitStochasticDeterministicSemantics::Usize(sem) => sem.analyse_minimum_probability(at_least),
             EbiTraitStochasticDeterministicSemantics::PMarking(sem) => sem.analyse_minimum_probability(at_least),
+            EbiTraitStochasticDeterministicSemantics::NodeStates(sem) => sem.analyse_minimum_probability(at_least),
         }
     }
 
@@ -35,6 +36,7 @@ impl ProbabilityQueries for EbiTraitStochasticDeterministicSemantics {
         match self {
             EbiTraitStochasticDeterministicSemantics::Usize(sem) => sem.analyse_most_likely_traces(number_of_traces),
             EbiTraitStochasticDeterministicSemantics::PMarking(sem) => sem.analyse_most_likely_traces(number_of_traces),
+            EbiTraitStochasticDeterministicSemantics::NodeStates(sem) => sem.analyse_most_likely_traces(number_of_traces),
         }
     }
 
@@ -42,6 +44,7 @@ impl ProbabilityQueries for EbiTraitStochasticDeterministicSemantics {
         match self {
             EbiTraitStochasticDeterministicSemantics::Usize(sem) => sem.analyse_probability_coverage(coverage),
             EbiTraitStochasticDeterministicSemantics::PMarking(sem) => sem.analyse_probability_coverage(coverage),
+            EbiTraitStochasticDeterministicSemantics::NodeStates(sem) => sem.analyse_probability_coverage(coverage),
         }
     }
 }
@@ -2,15 +2,16 @@ use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use crate::{
     ebi_traits::ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage,
-    math::{fraction::Fraction, levenshtein},
+    math::{fraction::Fraction, trace_distance::TraceDistance},
 };
 
 pub trait ProcessVariety {
-    fn process_variety(&self) -> Fraction;
+    fn process_variety(&self, distance: &TraceDistance) -> Fraction;
 }
 
 impl ProcessVariety for dyn EbiTraitFiniteStochasticLanguage {
-    fn process_variety(&self) -> Fraction {
+    fn process_variety(&self, distance: &TraceDistance) -> Fraction {
+        let activity_key = self.get_activity_key();
         (0..self.len())
             .into_par_iter()
             .map(|i| {
@@ -21,7 +22,7 @@ impl ProcessVariety for dyn EbiTraitFiniteStochasticLanguage {
                     .map(|j| {
                         let trace_j = self.get_trace(j).unwrap();
                         let probability_j = self.get_trace_probability(j).unwrap();
-                        let mut d = Fraction::from(levenshtein::distance(trace_i, trace_j));
+                        let mut d = distance.distance(activity_key, trace_i, trace_j);
                         d *= probability_i;
                         d *= probability_j;
                         d
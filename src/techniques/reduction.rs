@@ -0,0 +1,427 @@
+use anyhow::Result;
+
+use crate::{ebi_framework::activity_key::{Activity, ActivityKey}, ebi_objects::{labelled_petri_net::LabelledPetriNet, stochastic_labelled_petri_net::StochasticLabelledPetriNet}, marking::Marking, math::fraction::Fraction};
+
+pub trait Reduce: Sized {
+    /**
+     * Applies reduction rules in the style of Murata until none applies anymore, and returns the reduced net.
+     * The language of the net is preserved, and for stochastic nets the stochastic language is preserved by recombining weights.
+     * The rules are:
+     *  - removal of transitions that can never fire, and of places that no transition consumes from;
+     *  - removal of implicit places: places that are parallel to another place, and places that only have self-loops and enough tokens;
+     *  - fusion of parallel transitions, of which the weights are added up;
+     *  - removal of silent self-loop transitions that never are the only enabled transition;
+     *  - fusion of series places and of series transitions over a silent transition;
+     *  - elimination of silent transitions by moving the choice after it forward.
+     * As silent transitions influence the interleaving of concurrent transitions in stochastic nets, the last two rules are only applied to stochastic nets that are state machines with one token.
+     */
+    fn reduce(&self) -> Result<Self>;
+}
+
+impl Reduce for LabelledPetriNet {
+    fn reduce(&self) -> Result<Self> {
+        let mut net = ReductionNet {
+            activity_key: self.activity_key.clone(),
+            initial_marking: self.initial_marking.get_place2token().clone(),
+            labels: self.labels.clone(),
+            inputs: ReductionNet::arcs(&self.transition2input_places, &self.transition2input_places_cardinality),
+            outputs: ReductionNet::arcs(&self.transition2output_places, &self.transition2output_places_cardinality),
+            weights: None,
+            place_alive: vec![true; self.get_number_of_places()],
+            transition_alive: vec![true; self.labels.len()],
+        };
+        net.reduce();
+        let (net, _) = net.into_labelled_petri_net();
+        Ok(net)
+    }
+}
+
+impl Reduce for StochasticLabelledPetriNet {
+    fn reduce(&self) -> Result<Self> {
+        let mut net = ReductionNet {
+            activity_key: self.activity_key.clone(),
+            initial_marking: self.initial_marking.get_place2token().clone(),
+            labels: self.labels.clone(),
+            inputs: ReductionNet::arcs(&self.transition2input_places, &self.transition2input_places_cardinality),
+            outputs: ReductionNet::arcs(&self.transition2output_places, &self.transition2output_places_cardinality),
+            weights: Some(self.weights.clone()),
+            place_alive: vec![true; self.get_number_of_places()],
+            transition_alive: vec![true; self.labels.len()],
+        };
+        net.reduce();
+        let (net, weights) = net.into_labelled_petri_net();
+        Ok(StochasticLabelledPetriNet {
+            activity_key: net.activity_key,
+            initial_marking: net.initial_marking,
+            labels: net.labels,
+            transition2input_places: net.transition2input_places,
+            transition2output_places: net.transition2output_places,
+            transition2input_places_cardinality: net.transition2input_places_cardinality,
+            transition2output_places_cardinality: net.transition2output_places_cardinality,
+            place2output_transitions: net.place2output_transitions,
+            weights: weights.unwrap(),
+        })
+    }
+}
+
+/**
+ * A net in which places and transitions can be removed cheaply. Arcs are kept per transition, sorted by place.
+ */
+struct ReductionNet {
+    activity_key: ActivityKey,
+    initial_marking: Vec<u64>,
+    labels: Vec<Option<Activity>>,
+    inputs: Vec<Vec<(usize, u64)>>,
+    outputs: Vec<Vec<(usize, u64)>>,
+    weights: Option<Vec<Fraction>>,
+    place_alive: Vec<bool>,
+    transition_alive: Vec<bool>,
+}
+
+/**
+ * For each place, the transitions that produce into it and the transitions that consume from it, with the cardinalities of the arcs.
+ */
+struct Adjacency {
+    producers: Vec<Vec<(usize, u64)>>,
+    consumers: Vec<Vec<(usize, u64)>>,
+}
+
+impl ReductionNet {
+    fn arcs(places: &Vec<Vec<usize>>, cardinalities: &Vec<Vec<u64>>) -> Vec<Vec<(usize, u64)>> {
+        places.iter().zip(cardinalities.iter()).map(|(places, cardinalities)| {
+            let mut arcs = places.iter().cloned().zip(cardinalities.iter().cloned()).collect::<Vec<_>>();
+            arcs.sort();
+            arcs
+        }).collect()
+    }
+
+    fn reduce(&mut self) {
+        log::info!("reduce Petri net");
+
+        loop {
+            let adjacency = self.get_adjacency();
+            let state_machine = self.is_state_machine();
+            let stochastic = self.weights.is_some();
+
+            let applied = self.remove_dead_transitions(&adjacency)
+                || self.remove_places_without_consumers(&adjacency)
+                || self.remove_self_loop_places(&adjacency)
+                || self.remove_parallel_places(&adjacency)
+                || self.fuse_parallel_transitions()
+                || self.remove_silent_self_loop_transitions()
+                || ((!stochastic || state_machine) && self.fuse_series_places(&adjacency))
+                || ((!stochastic || state_machine) && self.fuse_series_transitions(&adjacency))
+                || (state_machine && self.eliminate_silent_transition(&adjacency));
+
+            if !applied {
+                return;
+            }
+        }
+    }
+
+    fn transitions(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.labels.len()).filter(|transition| self.transition_alive[*transition])
+    }
+
+    fn places(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.initial_marking.len()).filter(|place| self.place_alive[*place])
+    }
+
+    fn get_adjacency(&self) -> Adjacency {
+        let mut result = Adjacency {
+            producers: vec![vec![]; self.initial_marking.len()],
+            consumers: vec![vec![]; self.initial_marking.len()],
+        };
+        for transition in self.transitions() {
+            for (place, cardinality) in &self.inputs[transition] {
+                result.consumers[*place].push((transition, *cardinality));
+            }
+            for (place, cardinality) in &self.outputs[transition] {
+                result.producers[*place].push((transition, *cardinality));
+            }
+        }
+        result
+    }
+
+    /**
+     * A state machine has one token, and each transition consumes it from one place and produces it in at most one place.
+     * Then, there is no concurrency and the choices between transitions are local to places.
+     */
+    fn is_state_machine(&self) -> bool {
+        self.places().map(|place| self.initial_marking[place]).sum::<u64>() <= 1
+            && self.transitions().all(|transition| {
+                self.inputs[transition].len() == 1 && self.inputs[transition][0].1 == 1
+                    && self.outputs[transition].len() <= 1 && self.outputs[transition].iter().all(|(_, cardinality)| *cardinality == 1)
+            })
+    }
+
+    fn is_silent(&self, transition: usize) -> bool {
+        self.labels[transition].is_none()
+    }
+
+    fn remove_place(&mut self, place: usize) {
+        self.place_alive[place] = false;
+        for transition in 0..self.labels.len() {
+            self.inputs[transition].retain(|(p, _)| *p != place);
+            self.outputs[transition].retain(|(p, _)| *p != place);
+        }
+    }
+
+    fn add_arc(arcs: &mut Vec<(usize, u64)>, place: usize, cardinality: u64) {
+        match arcs.binary_search_by_key(&place, |(p, _)| *p) {
+            Ok(index) => arcs[index].1 += cardinality,
+            Err(index) => arcs.insert(index, (place, cardinality)),
+        }
+    }
+
+    /**
+     * A transition can never fire if it has no weight, or if it needs more tokens from a place than that place can ever get.
+     */
+    fn remove_dead_transitions(&mut self, adjacency: &Adjacency) -> bool {
+        let dead = self.transitions().filter(|transition| {
+            self.weights.as_ref().is_some_and(|weights| !weights[*transition].is_positive())
+                || self.inputs[*transition].iter().any(|(place, cardinality)| adjacency.producers[*place].is_empty() && self.initial_marking[*place] < *cardinality)
+        }).collect::<Vec<_>>();
+        for transition in &dead {
+            self.transition_alive[*transition] = false;
+        }
+        !dead.is_empty()
+    }
+
+    /**
+     * A place from which no transition consumes does not influence the behaviour of the net.
+     */
+    fn remove_places_without_consumers(&mut self, adjacency: &Adjacency) -> bool {
+        let place = self.places().find(|place| adjacency.consumers[*place].is_empty());
+        if let Some(place) = place {
+            self.remove_place(place);
+            return true;
+        }
+        false
+    }
+
+    /**
+     * A place that every transition puts back what it takes, and that initially has enough tokens, never disables a transition.
+     */
+    fn remove_self_loop_places(&mut self, adjacency: &Adjacency) -> bool {
+        let place = self.places().find(|place| {
+            let mut consumers = adjacency.consumers[*place].clone();
+            let mut producers = adjacency.producers[*place].clone();
+            consumers.sort();
+            producers.sort();
+            consumers == producers && consumers.iter().all(|(_, cardinality)| self.initial_marking[*place] >= *cardinality)
+        });
+        if let Some(place) = place {
+            self.remove_place(place);
+            return true;
+        }
+        false
+    }
+
+    /**
+     * Of two places with the same arcs and the same initial marking, one is implicit.
+     */
+    fn remove_parallel_places(&mut self, adjacency: &Adjacency) -> bool {
+        let places = self.places().collect::<Vec<_>>();
+        for (i, place1) in places.iter().enumerate() {
+            for place2 in places.iter().skip(i + 1) {
+                if self.initial_marking[*place1] == self.initial_marking[*place2]
+                    && adjacency.producers[*place1] == adjacency.producers[*place2]
+                    && adjacency.consumers[*place1] == adjacency.consumers[*place2] {
+                    self.remove_place(*place2);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /**
+     * Two transitions with the same label and the same arcs behave as one transition with the sum of their weights.
+     */
+    fn fuse_parallel_transitions(&mut self) -> bool {
+        let transitions = self.transitions().collect::<Vec<_>>();
+        for (i, transition1) in transitions.iter().enumerate() {
+            for transition2 in transitions.iter().skip(i + 1) {
+                if self.labels[*transition1] == self.labels[*transition2]
+                    && self.inputs[*transition1] == self.inputs[*transition2]
+                    && self.outputs[*transition1] == self.outputs[*transition2] {
+                    if let Some(weights) = &mut self.weights {
+                        let weight = weights[*transition2].clone();
+                        weights[*transition1] += weight;
+                    }
+                    self.transition_alive[*transition2] = false;
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /**
+     * A silent transition that does not change the marking can be removed, provided that another transition is enabled whenever it is.
+     * Then, removing it does not introduce deadlocks, and for stochastic nets the other transitions keep their relative probabilities.
+     */
+    fn remove_silent_self_loop_transitions(&mut self) -> bool {
+        let transition = self.transitions().find(|transition| {
+            self.is_silent(*transition)
+                && self.inputs[*transition] == self.outputs[*transition]
+                && self.transitions().any(|other| {
+                    other != *transition && self.inputs[other].iter().all(|(place, cardinality)| {
+                        self.inputs[*transition].iter().any(|(p, c)| p == place && c >= cardinality)
+                    })
+                })
+        });
+        if let Some(transition) = transition {
+            self.transition_alive[transition] = false;
+            return true;
+        }
+        false
+    }
+
+    /**
+     * A silent transition that is the only consumer of its single input place moves the tokens of that place to its single output place without choice.
+     * The input place is merged into the output place.
+     */
+    fn fuse_series_places(&mut self, adjacency: &Adjacency) -> bool {
+        let transition = self.transitions().find(|transition| {
+            self.is_silent(*transition)
+                && self.inputs[*transition].len() == 1 && self.inputs[*transition][0].1 == 1
+                && self.outputs[*transition].len() == 1 && self.outputs[*transition][0].1 == 1
+                && self.inputs[*transition][0].0 != self.outputs[*transition][0].0
+                && adjacency.consumers[self.inputs[*transition][0].0].len() == 1
+        });
+        if let Some(transition) = transition {
+            let place = self.inputs[transition][0].0;
+            let target = self.outputs[transition][0].0;
+            self.transition_alive[transition] = false;
+            for (producer, cardinality) in &adjacency.producers[place] {
+                Self::add_arc(&mut self.outputs[*producer], target, *cardinality);
+            }
+            self.initial_marking[target] += self.initial_marking[place];
+            self.remove_place(place);
+            return true;
+        }
+        false
+    }
+
+    /**
+     * A silent transition that consumes from a single unmarked place, of which it is the only consumer and another transition the only producer, fires directly after that producer.
+     * The silent transition is merged into the producer.
+     */
+    fn fuse_series_transitions(&mut self, adjacency: &Adjacency) -> bool {
+        let transition = self.transitions().find(|transition| {
+            if !self.is_silent(*transition) || self.inputs[*transition].len() != 1 || self.inputs[*transition][0].1 != 1 {
+                return false;
+            }
+            let place = self.inputs[*transition][0].0;
+            self.initial_marking[place] == 0
+                && adjacency.consumers[place].len() == 1
+                && adjacency.producers[place].len() == 1
+                && adjacency.producers[place][0].1 == 1
+                && adjacency.producers[place][0].0 != *transition
+        });
+        if let Some(transition) = transition {
+            let place = self.inputs[transition][0].0;
+            let producer = adjacency.producers[place][0].0;
+            self.transition_alive[transition] = false;
+            self.remove_place(place);
+            for (output_place, cardinality) in self.outputs[transition].clone() {
+                Self::add_arc(&mut self.outputs[producer], output_place, cardinality);
+            }
+            return true;
+        }
+        false
+    }
+
+    /**
+     * In a state machine, a silent transition from place p to a place p' that has no other producers is removed, by letting the consumers of p' consume from p directly.
+     * The weights of the other consumers of p are multiplied by the total weight of the consumers of p', and each new transition gets the weight of the silent transition multiplied by the weight of the consumer it copies, such that all probabilities are preserved.
+     */
+    fn eliminate_silent_transition(&mut self, adjacency: &Adjacency) -> bool {
+        let transition = self.transitions().find(|transition| {
+            if !self.is_silent(*transition) || self.outputs[*transition].len() != 1 {
+                return false;
+            }
+            let place = self.inputs[*transition][0].0;
+            let target = self.outputs[*transition][0].0;
+            place != target
+                && self.initial_marking[target] == 0
+                && adjacency.producers[target].len() == 1
+                && !adjacency.consumers[target].is_empty()
+                && adjacency.consumers[target].iter().all(|(consumer, _)| self.outputs[*consumer].iter().all(|(p, _)| *p != target))
+        });
+        if let Some(transition) = transition {
+            let place = self.inputs[transition][0].0;
+            let target = self.outputs[transition][0].0;
+
+            if let Some(weights) = &mut self.weights {
+                let target_weight = adjacency.consumers[target].iter().map(|(consumer, _)| &weights[*consumer]).sum::<Fraction>();
+                for (consumer, _) in &adjacency.consumers[place] {
+                    if *consumer != transition {
+                        weights[*consumer] *= &target_weight;
+                    }
+                }
+            }
+
+            for (consumer, _) in &adjacency.consumers[target] {
+                self.labels.push(self.labels[*consumer]);
+                self.inputs.push(vec![(place, 1)]);
+                self.outputs.push(self.outputs[*consumer].clone());
+                self.transition_alive.push(true);
+                if let Some(weights) = &mut self.weights {
+                    let weight = &weights[transition] * &weights[*consumer];
+                    weights.push(weight);
+                }
+                self.transition_alive[*consumer] = false;
+            }
+
+            self.transition_alive[transition] = false;
+            self.remove_place(target);
+            return true;
+        }
+        false
+    }
+
+    /**
+     * Builds a net of the remaining places and transitions. Returns the weights of the remaining transitions as well, if any.
+     */
+    fn into_labelled_petri_net(self) -> (LabelledPetriNet, Option<Vec<Fraction>>) {
+        let mut place2new = vec![0; self.initial_marking.len()];
+        let mut initial_marking = vec![];
+        for place in self.places() {
+            place2new[place] = initial_marking.len();
+            initial_marking.push(self.initial_marking[place]);
+        }
+        let number_of_places = initial_marking.len();
+
+        let mut result = LabelledPetriNet {
+            activity_key: self.activity_key.clone(),
+            initial_marking: Marking::from_vec(initial_marking),
+            labels: vec![],
+            place2output_transitions: vec![vec![]; number_of_places],
+            transition2input_places: vec![],
+            transition2output_places: vec![],
+            transition2input_places_cardinality: vec![],
+            transition2output_places_cardinality: vec![],
+        };
+        let mut weights = self.weights.as_ref().map(|_| vec![]);
+
+        for transition in self.transitions() {
+            let new_transition = result.labels.len();
+            result.labels.push(self.labels[transition]);
+            result.transition2input_places.push(self.inputs[transition].iter().map(|(place, _)| place2new[*place]).collect());
+            result.transition2input_places_cardinality.push(self.inputs[transition].iter().map(|(_, cardinality)| *cardinality).collect());
+            result.transition2output_places.push(self.outputs[transition].iter().map(|(place, _)| place2new[*place]).collect());
+            result.transition2output_places_cardinality.push(self.outputs[transition].iter().map(|(_, cardinality)| *cardinality).collect());
+            for (place, _) in &self.inputs[transition] {
+                result.place2output_transitions[place2new[*place]].push(new_transition);
+            }
+            if let (Some(weights), Some(old_weights)) = (&mut weights, &self.weights) {
+                weights.push(old_weights[transition].clone());
+            }
+        }
+
+        (result, weights)
+    }
+}
@@ -14,6 +14,7 @@ impl Sampler for EbiTraitStochasticSemantics {
         match self {
             EbiTraitStochasticSemantics::Marking(s) => s.sample(number_of_traces),
             EbiTraitStochasticSemantics::Usize(s) => s.sample(number_of_traces),
+            EbiTraitStochasticSemantics::NodeStates(s) => s.sample(number_of_traces),
         }
     }
 }
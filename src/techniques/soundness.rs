@@ -0,0 +1,230 @@
+use std::fmt::Display;
+use anyhow::Result;
+
+use crate::{ebi_objects::labelled_petri_net::LabelledPetriNet, ebi_traits::{ebi_trait_semantics::Semantics, ebi_trait_stochastic_semantics::TransitionIndex}};
+
+use super::coverability::{Coverability, CoverabilityGraph};
+
+pub struct SoundnessReport {
+    /**
+     * Reasons why the net is not a workflow net. If there are any, the other checks have not been performed.
+     */
+    pub workflow_net_problems: Vec<String>,
+    pub unbounded_places: Vec<usize>,
+
+    /**
+     * Reachable markings from which the final marking cannot be reached. Only computed for bounded nets.
+     */
+    pub markings_without_option_to_complete: Option<Vec<Vec<u64>>>,
+
+    /**
+     * Reachable markings that mark the sink place, but are not the final marking.
+     */
+    pub improper_final_markings: Vec<Vec<u64>>,
+    pub dead_transitions: Vec<String>,
+}
+
+impl SoundnessReport {
+    pub fn is_sound(&self) -> bool {
+        self.workflow_net_problems.is_empty()
+            && self.unbounded_places.is_empty()
+            && self.markings_without_option_to_complete.as_ref().is_some_and(|markings| markings.is_empty())
+            && self.improper_final_markings.is_empty()
+            && self.dead_transitions.is_empty()
+    }
+}
+
+impl Display for SoundnessReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_sound() {
+            return writeln!(f, "The workflow net is sound.");
+        }
+        writeln!(f, "The net is not sound.")?;
+
+        if !self.workflow_net_problems.is_empty() {
+            writeln!(f, "The net is not a workflow net:")?;
+            for problem in &self.workflow_net_problems {
+                writeln!(f, "\t{}", problem)?;
+            }
+            return write!(f, "");
+        }
+
+        if !self.unbounded_places.is_empty() {
+            writeln!(f, "The net is unbounded in places {}; option to complete has not been checked.", self.unbounded_places.iter().map(|place| format!("p{}", place)).collect::<Vec<_>>().join(", "))?;
+        }
+
+        if let Some(markings) = &self.markings_without_option_to_complete {
+            if !markings.is_empty() {
+                writeln!(f, "No option to complete: the final marking cannot be reached from the reachable markings")?;
+                for marking in markings {
+                    writeln!(f, "\t{}", CoverabilityGraph::display_marking(marking))?;
+                }
+            }
+        }
+
+        if !self.improper_final_markings.is_empty() {
+            writeln!(f, "No proper completion: the sink place is marked together with other tokens in the reachable markings")?;
+            for marking in &self.improper_final_markings {
+                writeln!(f, "\t{}", CoverabilityGraph::display_marking(marking))?;
+            }
+        }
+
+        if !self.dead_transitions.is_empty() {
+            writeln!(f, "Dead transitions:")?;
+            for transition in &self.dead_transitions {
+                writeln!(f, "\t{}", transition)?;
+            }
+        }
+
+        write!(f, "")
+    }
+}
+
+pub trait Soundness {
+    /**
+     * Decides classical soundness of a workflow net: option to complete, proper completion and no dead transitions.
+     * As the coverability graph is used, this terminates for unbounded nets as well; unbounded workflow nets are never sound.
+     */
+    fn check_soundness(&self) -> Result<SoundnessReport>;
+}
+
+impl Soundness for LabelledPetriNet {
+    fn check_soundness(&self) -> Result<SoundnessReport> {
+        let mut result = SoundnessReport {
+            workflow_net_problems: vec![],
+            unbounded_places: vec![],
+            markings_without_option_to_complete: None,
+            improper_final_markings: vec![],
+            dead_transitions: vec![],
+        };
+
+        let sink = match self.get_workflow_net_problems(&mut result.workflow_net_problems) {
+            Some(sink) if result.workflow_net_problems.is_empty() => sink,
+            _ => return Ok(result),
+        };
+
+        let graph = self.get_coverability_graph()?;
+        result.unbounded_places = graph.get_unbounded_places();
+
+        let is_final = |marking: &Vec<u64>| marking.iter().enumerate().all(|(place, tokens)| if place == sink { *tokens == 1 } else { *tokens == 0 });
+
+        //option to complete; in a bounded net the coverability graph is the reachability graph
+        if graph.is_bounded() {
+            let can_complete = graph.can_reach(|node| is_final(&graph.markings[node]));
+            result.markings_without_option_to_complete = Some(graph.markings.iter().zip(can_complete).filter_map(|(marking, can)| if can { None } else { Some(marking.clone()) }).collect());
+        }
+
+        //proper completion
+        for marking in &graph.markings {
+            if marking[sink] > 0 && !is_final(marking) {
+                result.improper_final_markings.push(marking.clone());
+            }
+        }
+
+        //dead transitions
+        let mut alive = vec![false; self.get_number_of_transitions()];
+        for (transition, _) in graph.edges.iter().flatten() {
+            alive[*transition] = true;
+        }
+        for (transition, alive) in alive.into_iter().enumerate() {
+            if !alive {
+                result.dead_transitions.push(self.display_transition(transition));
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl LabelledPetriNet {
+    /**
+     * Adds the reasons why this net is not a workflow net to problems, and returns the sink place if there is exactly one.
+     */
+    fn get_workflow_net_problems(&self, problems: &mut Vec<String>) -> Option<usize> {
+        let places = self.get_number_of_places();
+        let transitions = self.get_number_of_transitions();
+
+        let mut place2input_transitions = vec![vec![]; places];
+        for transition in 0..transitions {
+            for place in &self.transition2output_places[transition] {
+                place2input_transitions[*place].push(transition);
+            }
+        }
+
+        let sources = (0..places).filter(|place| place2input_transitions[*place].is_empty()).collect::<Vec<_>>();
+        let sinks = (0..places).filter(|place| self.place2output_transitions[*place].is_empty()).collect::<Vec<_>>();
+        if sources.len() != 1 {
+            problems.push(format!("There should be exactly one source place, but there are {}.", sources.len()));
+        }
+        if sinks.len() != 1 {
+            problems.push(format!("There should be exactly one sink place, but there are {}.", sinks.len()));
+        }
+        if sources.len() != 1 || sinks.len() != 1 {
+            return None;
+        }
+        let source = sources[0];
+        let sink = sinks[0];
+
+        let initial_marking = self.initial_marking.get_place2token();
+        if initial_marking.iter().enumerate().any(|(place, tokens)| if place == source { *tokens != 1 } else { *tokens != 0 }) {
+            problems.push(format!("The initial marking should contain a single token in the source place p{}.", source));
+        }
+
+        //every node must be on a path from the source to the sink
+        let mut forward_places = vec![false; places];
+        let mut forward_transitions = vec![false; transitions];
+        forward_places[source] = true;
+        let mut queue = vec![source];
+        while let Some(place) = queue.pop() {
+            for transition in &self.place2output_transitions[place] {
+                if !forward_transitions[*transition] {
+                    forward_transitions[*transition] = true;
+                    for output_place in &self.transition2output_places[*transition] {
+                        if !forward_places[*output_place] {
+                            forward_places[*output_place] = true;
+                            queue.push(*output_place);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut backward_places = vec![false; places];
+        let mut backward_transitions = vec![false; transitions];
+        backward_places[sink] = true;
+        let mut queue = vec![sink];
+        while let Some(place) = queue.pop() {
+            for transition in &place2input_transitions[place] {
+                if !backward_transitions[*transition] {
+                    backward_transitions[*transition] = true;
+                    for input_place in &self.transition2input_places[*transition] {
+                        if !backward_places[*input_place] {
+                            backward_places[*input_place] = true;
+                            queue.push(*input_place);
+                        }
+                    }
+                }
+            }
+        }
+
+        for place in 0..places {
+            if !forward_places[place] || !backward_places[place] {
+                problems.push(format!("Place p{} is not on a path from the source place to the sink place.", place));
+            }
+        }
+        for transition in 0..transitions {
+            if !forward_transitions[transition] || !backward_transitions[transition] {
+                problems.push(format!("{} is not on a path from the source place to the sink place.", self.display_transition(transition)));
+            }
+        }
+
+        Some(sink)
+    }
+
+    fn display_transition(&self, transition: TransitionIndex) -> String {
+        match self.get_transition_label(transition) {
+            Some(activity) => format!("Transition {} (label {})", transition, self.activity_key.get_activity_label(&activity)),
+            None => format!("Transition {} (silent)", transition),
+        }
+    }
+}
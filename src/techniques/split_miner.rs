@@ -0,0 +1,215 @@
+use std::collections::BTreeSet;
+
+use anyhow::{anyhow, Result};
+
+use crate::{ebi_framework::activity_key::{ActivityKeyTranslator, HasActivityKey}, ebi_objects::labelled_petri_net::LabelledPetriNet, ebi_traits::ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage, ebi_traits::ebi_trait_stochastic_semantics::TransitionIndex, math::fraction::Fraction};
+
+use super::inductive_miner::components;
+
+pub trait SplitMiner {
+    /**
+     * Discovers a labelled Petri net in the style of Split Miner.
+     * Two activities that follow one another in both directions with similar frequencies, and not in a short loop, are concurrent according to the parallelism threshold: the relative difference of their frequencies is below it.
+     * Then, the directly follows graph is filtered: the most frequent incoming and outgoing edge of each activity is kept, as well as each edge at least as frequent as the filter percentile of these most frequent edges.
+     * Finally, the successors and predecessors of each activity are combined into nested and- and xor-splits and -joins, using the concurrency relation, which are translated into silent transitions.
+     */
+    fn mine_split(&self, filter_percentile: &Fraction, parallelism_threshold: &Fraction) -> Result<LabelledPetriNet>;
+}
+
+impl SplitMiner for dyn EbiTraitFiniteStochasticLanguage {
+    fn mine_split(&self, filter_percentile: &Fraction, parallelism_threshold: &Fraction) -> Result<LabelledPetriNet> {
+        for threshold in [filter_percentile, parallelism_threshold] {
+            if threshold.is_negative() || threshold > &Fraction::one() {
+                return Err(anyhow!("threshold {} should be between 0 and 1", threshold));
+            }
+        }
+
+        let mut result = LabelledPetriNet::new();
+        let translator = ActivityKeyTranslator::new(self.get_activity_key(), result.get_activity_key_mut());
+
+        //nodes: 0 is the start, 1 is the end, and the others are activities
+        let mut activities = vec![];
+        let mut traces = vec![];
+        for (trace, probability) in self.iter_trace_probability() {
+            let trace = trace.iter().map(|activity| translator.translate_activity(activity)).collect::<Vec<_>>();
+            activities.extend(trace.iter().cloned());
+            traces.push((trace, probability));
+        }
+        activities.sort();
+        activities.dedup();
+        let n = activities.len() + 2;
+        let get_node = |activity| activities.binary_search(activity).unwrap() + 2;
+
+        //directly follows graph and short loops
+        let mut frequencies = vec![vec![Fraction::zero(); n]; n];
+        let mut short_loops = vec![vec![false; n]; n];
+        for (trace, probability) in &traces {
+            let nodes = [0].into_iter().chain(trace.iter().map(get_node)).chain([1]).collect::<Vec<_>>();
+            for pair in nodes.windows(2) {
+                frequencies[pair[0]][pair[1]] += *probability;
+            }
+            for triple in nodes.windows(3) {
+                if triple[0] == triple[2] && triple[0] != triple[1] {
+                    short_loops[triple[0]][triple[1]] = true;
+                    short_loops[triple[1]][triple[0]] = true;
+                }
+            }
+        }
+
+        //concurrency oracle
+        let mut concurrent = vec![vec![false; n]; n];
+        for a in 2..n {
+            for b in a + 1..n {
+                let (ab, ba) = (&frequencies[a][b], &frequencies[b][a]);
+                if ab.is_positive() && ba.is_positive() && !short_loops[a][b] {
+                    let difference = if ab > ba { ab - ba } else { ba - ab };
+                    if difference < parallelism_threshold * &(ab + ba) {
+                        concurrent[a][b] = true;
+                        concurrent[b][a] = true;
+                        frequencies[a][b] = Fraction::zero();
+                        frequencies[b][a] = Fraction::zero();
+                    }
+                }
+            }
+        }
+
+        //filtering
+        let edges = get_filtered_edges(&frequencies, filter_percentile);
+
+        //transitions and places
+        let mut node_2_transition = vec![result.add_transition(None), result.add_transition(None)];
+        for activity in &activities {
+            node_2_transition.push(result.add_transition(Some(*activity)));
+        }
+        let source = result.add_place();
+        result.get_initial_marking_mut().increase(source, 1)?;
+        result.add_place_transition_arc(source, node_2_transition[0], 1)?;
+        let sink = result.add_place();
+        result.add_transition_place_arc(node_2_transition[1], sink, 1)?;
+        let mut edge_2_place = vec![vec![None; n]; n];
+        for (a, b) in &edges {
+            edge_2_place[*a][*b] = Some(result.add_place());
+        }
+
+        //splits and joins
+        for node in 0..n {
+            let successors = edges.iter().filter(|(a, _)| *a == node).map(|(_, b)| *b).collect::<Vec<_>>();
+            if !successors.is_empty() {
+                let gateway = Gateway::new(&successors, &concurrent);
+                gateway.split_from_transition(&mut result, node_2_transition[node], &|successor| edge_2_place[node][successor].unwrap())?;
+            }
+
+            let predecessors = edges.iter().filter(|(_, b)| *b == node).map(|(a, _)| *a).collect::<Vec<_>>();
+            if !predecessors.is_empty() {
+                let gateway = Gateway::new(&predecessors, &concurrent);
+                gateway.join_to_transition(&mut result, node_2_transition[node], &|predecessor| edge_2_place[predecessor][node].unwrap())?;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/**
+ * Keeps the most frequent incoming and outgoing edge of each node, and each edge that is at least as frequent as the percentile of the frequencies of these most frequent edges.
+ * A percentile of 0 keeps all edges.
+ */
+fn get_filtered_edges(frequencies: &[Vec<Fraction>], percentile: &Fraction) -> BTreeSet<(usize, usize)> {
+    let n = frequencies.len();
+    let all_edges = (0..n).flat_map(|a| (0..n).map(move |b| (a, b))).filter(|(a, b)| frequencies[*a][*b].is_positive()).collect::<BTreeSet<_>>();
+    if percentile.is_zero() {
+        return all_edges;
+    }
+
+    let mut best_edges = BTreeSet::new();
+    for node in 0..n {
+        if let Some(edge) = all_edges.iter().filter(|(a, _)| *a == node).max_by(|(a1, b1), (a2, b2)| frequencies[*a1][*b1].cmp(&frequencies[*a2][*b2])) {
+            best_edges.insert(*edge);
+        }
+        if let Some(edge) = all_edges.iter().filter(|(_, b)| *b == node).max_by(|(a1, b1), (a2, b2)| frequencies[*a1][*b1].cmp(&frequencies[*a2][*b2])) {
+            best_edges.insert(*edge);
+        }
+    }
+
+    //the smallest frequency such that at least the percentile of the best edges is not more frequent
+    let mut best_frequencies = best_edges.iter().map(|(a, b)| &frequencies[*a][*b]).collect::<Vec<_>>();
+    best_frequencies.sort();
+    let count = Fraction::from(best_frequencies.len());
+    let threshold = best_frequencies.iter().enumerate()
+        .find(|(index, _)| Fraction::from(index + 1) >= percentile * &count)
+        .map(|(_, frequency)| (*frequency).clone());
+
+    all_edges.into_iter().filter(|edge| best_edges.contains(edge) || threshold.as_ref().is_some_and(|threshold| &frequencies[edge.0][edge.1] >= threshold)).collect()
+}
+
+/**
+ * A nested structure of and- and xor-gateways over nodes.
+ */
+enum Gateway {
+    Node(usize),
+    And(Vec<Gateway>),
+    Xor(Vec<Gateway>),
+}
+
+impl Gateway {
+    /**
+     * Nodes that are concurrent with one another are put in an and-gateway; others in a xor-gateway.
+     */
+    fn new(nodes: &[usize], concurrent: &[Vec<bool>]) -> Self {
+        if nodes.len() == 1 {
+            return Self::Node(nodes[0]);
+        }
+
+        let groups = components(nodes, |a, b| !concurrent[a][b]);
+        if groups.len() > 1 {
+            return Self::And(groups.iter().map(|group| Self::new(group, concurrent)).collect());
+        }
+
+        let groups = components(nodes, |a, b| concurrent[a][b]);
+        if groups.len() > 1 {
+            return Self::Xor(groups.iter().map(|group| Self::new(group, concurrent)).collect());
+        }
+
+        Self::Xor(nodes.iter().map(|node| Self::Node(*node)).collect())
+    }
+
+    /**
+     * Adds arcs from the transition such that each node of the gateway gets a token in its place.
+     */
+    fn split_from_transition(&self, net: &mut LabelledPetriNet, transition: TransitionIndex, node_2_place: &impl Fn(usize) -> usize) -> Result<()> {
+        match self {
+            Self::Node(node) => net.add_transition_place_arc(transition, node_2_place(*node), 1),
+            Self::And(children) => children.iter().try_for_each(|child| child.split_from_transition(net, transition, node_2_place)),
+            Self::Xor(children) => {
+                let place = net.add_place();
+                net.add_transition_place_arc(transition, place, 1)?;
+                for child in children {
+                    let silent = net.add_transition(None);
+                    net.add_place_transition_arc(place, silent, 1)?;
+                    child.split_from_transition(net, silent, node_2_place)?;
+                }
+                Ok(())
+            },
+        }
+    }
+
+    /**
+     * Adds arcs to the transition such that it consumes a token from the places of the nodes of the gateway.
+     */
+    fn join_to_transition(&self, net: &mut LabelledPetriNet, transition: TransitionIndex, node_2_place: &impl Fn(usize) -> usize) -> Result<()> {
+        match self {
+            Self::Node(node) => net.add_place_transition_arc(node_2_place(*node), transition, 1),
+            Self::And(children) => children.iter().try_for_each(|child| child.join_to_transition(net, transition, node_2_place)),
+            Self::Xor(children) => {
+                let place = net.add_place();
+                net.add_place_transition_arc(place, transition, 1)?;
+                for child in children {
+                    let silent = net.add_transition(None);
+                    net.add_transition_place_arc(silent, place, 1)?;
+                    child.join_to_transition(net, silent, node_2_place)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
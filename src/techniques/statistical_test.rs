@@ -1,7 +1,7 @@
 use std::sync::{atomic::{AtomicUsize, Ordering}, Arc};
 use anyhow::{anyhow, Result};
 
-use crate::{distances::TriangularDistanceMatrix, ebi_framework::ebi_command::EbiCommand, ebi_traits::ebi_trait_event_log::{AttributeKey, EbiTraitEventLog}, math::{average::Average, fraction::Fraction}, techniques::sample};
+use crate::{distances::TriangularDistanceMatrix, ebi_framework::ebi_command::EbiCommand, ebi_traits::ebi_trait_event_log::{AttributeKey, EbiTraitEventLog}, math::{average::Average, fraction::Fraction, trace_distance::TraceDistance}, techniques::sample};
 
 pub trait StatisticalTests {
     /**
@@ -9,12 +9,12 @@ pub trait StatisticalTests {
      * 
      * See Statistical Tests and Association Measures for Business Processes. Sander J.J. Leemans, James M. McGree, Artem Polyvyanyy, Arthur H.M. ter Hofstede. IEEE Transactions on Knowledge and Data Engineering 2023. 
      */
-    fn log_categorical_attribute(&self, number_of_samples: usize, trace_attribute: &String, alpha: &Fraction) -> Result<(Fraction, bool)>;
+    fn log_categorical_attribute(&self, number_of_samples: usize, trace_attribute: &String, alpha: &Fraction, distance: &TraceDistance) -> Result<(Fraction, bool)>;
 }
 
 
 impl StatisticalTests for dyn EbiTraitEventLog {
-    fn log_categorical_attribute(&self, number_of_samples: usize, trace_attribute: &String, alpha: &Fraction) -> Result<(Fraction, bool)> {
+    fn log_categorical_attribute(&self, number_of_samples: usize, trace_attribute: &String, alpha: &Fraction, distance: &TraceDistance) -> Result<(Fraction, bool)> {
 
         let mut attribute_key = AttributeKey::new();
         let traces_with_attributes = self.get_traces_with_categorical_attributes(&mut attribute_key, trace_attribute).into_iter().collect::<Vec<_>>();
@@ -38,7 +38,7 @@ impl StatisticalTests for dyn EbiTraitEventLog {
         let attribute_indices = Arc::new(attribute_indices);
 
         //compute the distances
-        let distances = TriangularDistanceMatrix::new(&traces_with_attributes);
+        let distances = TriangularDistanceMatrix::new(&traces_with_attributes, self.get_activity_key(), distance);
         let average_base = Arc::new(Average::new(distances)?);
 
         let err = AtomicUsize::new(0);
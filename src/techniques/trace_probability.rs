@@ -1,7 +1,7 @@
 use anyhow::{Context, Result};
 use std::{collections::{HashMap, HashSet}, fmt::{self, Display}, hash::Hash, ops::Add, rc::Rc};
 
-use crate::{ebi_objects::{labelled_petri_net::LPNMarking, stochastic_deterministic_finite_automaton::StochasticDeterministicFiniteAutomaton, stochastic_labelled_petri_net::StochasticLabelledPetriNet}, ebi_traits::{ebi_trait_queriable_stochastic_language::EbiTraitQueriableStochasticLanguage, ebi_trait_stochastic_semantics::StochasticSemantics, ebi_trait_semantics::Semantics}, follower_semantics::FollowerSemantics, math::{fraction::Fraction, matrix::Matrix}};
+use crate::{ebi_objects::{labelled_petri_net::LPNMarking, process_tree_semantics::NodeStates, stochastic_deterministic_finite_automaton::StochasticDeterministicFiniteAutomaton, stochastic_directly_follows_model::StochasticDirectlyFollowsModel, stochastic_labelled_petri_net::StochasticLabelledPetriNet, stochastic_process_tree::StochasticProcessTree}, ebi_traits::{ebi_trait_queriable_stochastic_language::EbiTraitQueriableStochasticLanguage, ebi_trait_stochastic_deterministic_semantics::StochasticDeterministicSemantics, ebi_trait_stochastic_semantics::StochasticSemantics, ebi_trait_semantics::Semantics}, follower_semantics::FollowerSemantics, math::{fraction::Fraction, matrix::Matrix}};
 
 
 //generic implementation
@@ -96,6 +96,7 @@ macro_rules! default_trace_probability {
 }
 
 default_trace_probability!(StochasticLabelledPetriNet, LPNMarking);
+default_trace_probability!(StochasticProcessTree, NodeStates);
 
 impl EbiTraitQueriableStochasticLanguage for StochasticDeterministicFiniteAutomaton {
 
@@ -123,6 +124,31 @@ impl EbiTraitQueriableStochasticLanguage for StochasticDeterministicFiniteAutoma
 
 }
 
+impl EbiTraitQueriableStochasticLanguage for StochasticDirectlyFollowsModel {
+
+    fn get_probability(&self, follower: &FollowerSemantics) -> Result<Fraction> {
+        match follower {
+            FollowerSemantics::Trace(trace) => {
+                let mut state = self.get_deterministic_initial_state()?;
+                let mut result = Fraction::one();
+
+                for activity in trace.iter() {
+                    result *= self.get_deterministic_activity_probability(&state, *activity);
+                    if result.is_zero() {
+                        return Ok(result);
+                    }
+                    state = self.execute_deterministic_activity(&state, *activity)?;
+                }
+
+                result *= self.get_deterministic_termination_probability(&state);
+
+                Ok(result)
+            },
+        }
+    }
+
+}
+
 
 
 fn process_new_state<T: StochasticSemantics<StoSemState = A>, A: Eq + Hash + Clone + Display>(semantics: &T, 
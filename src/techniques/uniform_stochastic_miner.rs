@@ -1,12 +1,27 @@
-use crate::{ebi_objects::{labelled_petri_net::LabelledPetriNet, stochastic_labelled_petri_net::StochasticLabelledPetriNet}, ebi_traits::ebi_trait_semantics::Semantics, math::fraction::Fraction};
+use anyhow::Result;
+
+use crate::{ebi_objects::{labelled_petri_net::LabelledPetriNet, process_tree::ProcessTree, stochastic_labelled_petri_net::StochasticLabelledPetriNet, stochastic_process_tree::StochasticProcessTree}, ebi_traits::ebi_trait_semantics::Semantics, math::fraction::Fraction};
 
 pub trait UniformStochasticMiner {
-    fn mine_uniform_stochastic(self) -> StochasticLabelledPetriNet;
+    type StochasticModel;
+
+    fn mine_uniform_stochastic(self) -> Self::StochasticModel;
 }
 
 impl UniformStochasticMiner for LabelledPetriNet {
+    type StochasticModel = StochasticLabelledPetriNet;
+
     fn mine_uniform_stochastic(self) -> StochasticLabelledPetriNet {
         let len = self.get_number_of_transitions();
         (self, vec![Fraction::one(); len]).into()
     }
-}
\ No newline at end of file
+}
+
+impl UniformStochasticMiner for ProcessTree {
+    type StochasticModel = Result<StochasticProcessTree>;
+
+    fn mine_uniform_stochastic(self) -> Result<StochasticProcessTree> {
+        let weights = (0..self.get_number_of_nodes()).map(|node| StochasticProcessTree::get_uniform_weights(&self, node)).collect();
+        StochasticProcessTree::new(self, weights)
+    }
+}
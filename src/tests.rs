@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use std::{
+        collections::HashMap,
         fs::{self, File},
         io::Cursor,
         ops::Neg,
@@ -8,46 +9,58 @@ mod tests {
 
     use fraction::{GenericFraction, Zero};
     use num_bigint::ToBigUint;
+    use process_mining::event_log::AttributeValue;
 
     use crate::{
+        ebi_commands::{ebi_command_convert::{EBI_CONVERT_LOG, EBI_CONVERT_SDFA}, ebi_command_probability::EBI_PROBABILITY_MODEL},
         ebi_framework::{
-            activity_key::HasActivityKey, ebi_file_handler::EBI_FILE_HANDLERS,
-            ebi_output::EbiOutput,
+            activity_key::{ActivityKey, HasActivityKey}, ebi_command::EbiCommand, ebi_file_handler::EBI_FILE_HANDLERS, ebi_input::{self, EbiInput},
+            ebi_object::{EbiObject, EbiObjectType, EbiTraitObject}, ebi_output::EbiOutput, ebi_trait::EbiTrait, exportable::Exportable, importable::Importable, infoable::Infoable,
         },
         ebi_objects::{
+            business_process_model_and_notation::BusinessProcessModelAndNotation,
+            csv_event_log::{CsvEventLog, CsvImportOptions},
+            csv_executions::CsvExecutions,
             deterministic_finite_automaton::DeterministicFiniteAutomaton,
             directly_follows_model::DirectlyFollowsModel,
             event_log::EventLog,
-            finite_language::FiniteLanguage,
+            executions::Executions,
+            finite_language::{FiniteLanguage, EBI_FINITE_LANGUAGE},
             finite_stochastic_language::FiniteStochasticLanguage,
             labelled_petri_net::{LPNMarking, LabelledPetriNet},
             language_of_alignments::Move,
+            object_centric_event_log::ObjectCentricEventLog,
+            petri_net_markup_language::PetriNetMarkupLanguage,
             process_tree::ProcessTree,
+            process_tree_markup_language::ProcessTreeMarkupLanguage,
             stochastic_deterministic_finite_automaton::StochasticDeterministicFiniteAutomaton,
-            stochastic_labelled_petri_net::StochasticLabelledPetriNet,
+            stochastic_directly_follows_model::StochasticDirectlyFollowsModel,
+            stochastic_labelled_petri_net::{StochasticLabelledPetriNet, EBI_STOCHASTIC_LABELLED_PETRI_NET},
+            stochastic_process_tree::StochasticProcessTree,
         },
         ebi_traits::{
             ebi_trait_event_log::{EbiTraitEventLog, IndexTrace},
+            ebi_trait_finite_language::EbiTraitFiniteLanguage,
             ebi_trait_finite_stochastic_language::EbiTraitFiniteStochasticLanguage,
             ebi_trait_queriable_stochastic_language::EbiTraitQueriableStochasticLanguage,
-            ebi_trait_semantics::{Semantics, ToSemantics},
+            ebi_trait_semantics::{EbiTraitSemantics, Semantics, ToSemantics},
             ebi_trait_stochastic_deterministic_semantics::{
                 EbiTraitStochasticDeterministicSemantics, StochasticDeterministicSemantics,
                 ToStochasticDeterministicSemantics,
             },
         },
         follower_semantics::FollowerSemantics,
-        math::{fraction::Fraction, log_div::LogDiv, matrix::Matrix, root_log_div::RootLogDiv},
+        math::{fraction::Fraction, log_div::LogDiv, matrix::Matrix, root_log_div::RootLogDiv, trace_distance::TraceDistance},
         medoid,
         multiple_reader::MultipleReader,
-        optimization_algorithms::network_simplex::NetworkSimplex,
+        optimization_algorithms::{network_simplex::NetworkSimplex, simplex},
         techniques::{
-            align::Align, deterministic_semantics_for_stochastic_semantics::PMarking,
+            alergia_miner::AlergiaMiner, align::{Align, AlignmentHeuristics}, alignment_stochastic_miner::AlignmentMiner, alpha_miner::AlphaMiner, coverability::Coverability, determinisation::Determinise, directly_follows_miner::DirectlyFollowsMiner, estimator_stochastic_miner::{EstimatorStochasticMiner, WeightEstimator}, executions::FindExecutions, executions_stochastic_miner::ExecutionsStochasticMiner, inductive_miner::InductiveMiner, language_comparison::LanguageComparison, maximum_likelihood_stochastic_miner::{self, MaximumLikelihoodStochasticMiner}, deterministic_semantics_for_stochastic_semantics::PMarking, invariants::Invariants,
             earth_movers_stochastic_conformance::EarthMoversStochasticConformance,
             jensen_shannon_stochastic_conformance::JensenShannonStochasticConformance,
             medoid_non_stochastic::MedoidNonStochastic,
             occurrences_stochastic_miner::OccurrencesStochasticMiner,
-            probability_queries::ProbabilityQueries, process_variety::ProcessVariety,
+            probability_queries::ProbabilityQueries, process_variety::ProcessVariety, reduction::Reduce, soundness::Soundness, split_miner::SplitMiner,
             statistical_test::StatisticalTests, uniform_stochastic_miner::UniformStochasticMiner,
             unit_earth_movers_stochastic_conformance::UnitEarthMoversStochasticConformance,
         },
@@ -77,7 +90,7 @@ mod tests {
         let fin = fs::read_to_string("testfiles/aa-ab-ba.slang").unwrap();
         let slang = fin.parse::<FiniteStochasticLanguage>().unwrap();
         let fout = fs::read_to_string("testfiles/ba.lang").unwrap();
-        let medoid = medoid::medoid(&slang, &1).unwrap();
+        let medoid = medoid::medoid(&slang, &1, &TraceDistance::NormalisedLevenshtein).unwrap();
         assert_eq!(fout, medoid.to_string())
     }
 
@@ -86,7 +99,7 @@ mod tests {
         let fin = fs::read_to_string("testfiles/aa-ab-ba.slang").unwrap();
         let slang = fin.parse::<FiniteStochasticLanguage>().unwrap();
         let fout = fs::read_to_string("testfiles/aa.lang").unwrap();
-        let medoid = slang.medoid(1).unwrap();
+        let medoid = slang.medoid(1, &TraceDistance::NormalisedLevenshtein).unwrap();
         assert_eq!(fout, medoid.to_string())
     }
 
@@ -94,7 +107,7 @@ mod tests {
     fn non_stochastic_clustering() {
         let fin = fs::read_to_string("testfiles/aa-ab-ba.slang").unwrap();
         let slang = fin.parse::<FiniteStochasticLanguage>().unwrap();
-        let cluster = slang.k_medoids_clustering(1).unwrap();
+        let cluster = slang.k_medoids_clustering(1, &TraceDistance::NormalisedLevenshtein).unwrap();
         let fout = fs::read_to_string("testfiles/aa.lang").unwrap();
         assert_eq!(fout, cluster.to_string())
     }
@@ -317,7 +330,7 @@ mod tests {
         let event_log: Box<dyn EbiTraitEventLog> = Box::new(fin.parse::<EventLog>().unwrap());
 
         let (_, sustain) = event_log
-            .log_categorical_attribute(500, &"attribute".to_string(), &Fraction::from((1, 20)))
+            .log_categorical_attribute(500, &"attribute".to_string(), &Fraction::from((1, 20)), &TraceDistance::NormalisedLevenshtein)
             .unwrap();
         assert!(sustain) //The hypothesis should be rejected if we consider the meaning of things, however, as we have only two traces, it will be sustained.
     }
@@ -636,6 +649,61 @@ mod tests {
         assert_eq!(*alignment.get(0).unwrap(), correct_1);
     }
 
+    #[test]
+    fn align_lpn_trace() {
+        let fin = fs::read_to_string("testfiles/aa-ab-ba.lpn").unwrap();
+        let lpn = fin.parse::<LabelledPetriNet>().unwrap();
+        let mut semantics = EbiTraitSemantics::Marking(Box::new(lpn));
+
+        let a = semantics.get_activity_key_mut().process_activity("a");
+        let b = semantics.get_activity_key_mut().process_activity("b");
+
+        //the marking-equation heuristic must not change the cost of optimal alignments
+        let (_, cost) = semantics.align_trace(&vec![a, b]).unwrap();
+        assert_eq!(cost, 0);
+
+        let (_, cost) = semantics.align_trace(&vec![b, b]).unwrap();
+        assert_eq!(cost, 20000);
+
+        let (_, cost) = semantics.align_trace(&vec![b, a, b, a]).unwrap();
+        assert_eq!(cost, 20000);
+    }
+
+    #[test]
+    fn align_tree_trace() {
+        let fin = fs::read_to_string("testfiles/aa-ab-ba.ptree").unwrap();
+        let mut tree = fin.parse::<ProcessTree>().unwrap();
+
+        let a = tree.get_activity_key_mut().process_activity("a");
+        let b = tree.get_activity_key_mut().process_activity("b");
+
+        //at the start, two activities must be executed and at most one b can be matched
+        let trace = vec![b, b];
+        let cache = tree.initialise_alignment_heuristic_cache();
+        let estimate = tree.underestimate_cost_to_final_synchronous_state(&trace, &0, &tree.get_initial_state(), &cache);
+        assert_eq!(estimate, 20000);
+
+        let semantics = EbiTraitSemantics::NodeStates(Box::new(tree));
+        let (_, cost) = semantics.align_trace(&trace).unwrap();
+        assert_eq!(cost, 20001);
+
+        let (_, cost) = semantics.align_trace(&vec![a, b]).unwrap();
+        assert_eq!(cost, 1);
+    }
+
+    #[test]
+    fn simplex_maximise() {
+        //maximise 3x + 2y subject to x + y <= 4, x + 3y <= 6, x <= 3
+        let objective = vec![3.0, 2.0];
+        let constraints = vec![vec![1.0, 1.0], vec![1.0, 3.0], vec![1.0, 0.0]];
+        let bounds = vec![4.0, 6.0, 3.0];
+        let (value, solution) = simplex::maximise(&objective, &constraints, &bounds).unwrap();
+
+        assert!((value - 11.0).abs() < 1e-9);
+        assert!((solution[0] - 3.0).abs() < 1e-9);
+        assert!((solution[1] - 1.0).abs() < 1e-9);
+    }
+
     #[test]
     fn probability_sdfa_livelock_zeroweight() {
         let fin1 = fs::read_to_string("testfiles/a-livelock-zeroweight.sdfa").unwrap();
@@ -747,7 +815,40 @@ mod tests {
         let slang: Box<dyn EbiTraitFiniteStochasticLanguage> =
             Box::new(fin.parse::<FiniteStochasticLanguage>().unwrap());
 
-        assert_eq!(slang.process_variety(), Fraction::from((2, 5)));
+        assert_eq!(slang.process_variety(&TraceDistance::Levenshtein), Fraction::from((2, 5)));
+    }
+
+    #[test]
+    fn trace_distances() {
+        let mut activity_key = ActivityKey::new();
+        let abc = activity_key.process_trace(&vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+        let bac = activity_key.process_trace(&vec!["b".to_string(), "a".to_string(), "c".to_string()]);
+        let bc = activity_key.process_trace(&vec!["b".to_string(), "c".to_string()]);
+        let aab = activity_key.process_trace(&vec!["a".to_string(), "a".to_string(), "b".to_string()]);
+        let abb = activity_key.process_trace(&vec!["a".to_string(), "b".to_string(), "b".to_string()]);
+
+        assert_eq!(TraceDistance::Levenshtein.distance(&activity_key, &abc, &bac), Fraction::from(2));
+        assert_eq!(TraceDistance::NormalisedLevenshtein.distance(&activity_key, &abc, &bac), Fraction::from((2, 3)));
+        assert_eq!(TraceDistance::DamerauLevenshtein.distance(&activity_key, &abc, &bac), Fraction::from((1, 3)));
+        assert_eq!(TraceDistance::JaccardSet.distance(&activity_key, &aab, &abb), Fraction::zero());
+        assert_eq!(TraceDistance::JaccardMultiset.distance(&activity_key, &aab, &abb), Fraction::from((1, 2)));
+        assert_eq!(TraceDistance::ZeroOne.distance(&activity_key, &abc, &bac), Fraction::one());
+        assert_eq!(TraceDistance::ZeroOne.distance(&activity_key, &abc, &abc), Fraction::zero());
+
+        //without costs, the weighted edit distance is the normalised Levenshtein distance
+        let weighted = TraceDistance::new("weighted-edit", HashMap::new()).unwrap();
+        assert_eq!(weighted.distance(&activity_key, &abc, &bac), Fraction::from((2, 3)));
+        let weighted = TraceDistance::new("weighted-edit", HashMap::from([("a".to_string(), Fraction::from(2))])).unwrap();
+        assert_eq!(weighted.distance(&activity_key, &abc, &bc), Fraction::from((1, 2)));
+
+        //with the zero-one distance, EMSC is one minus the total variation distance
+        let fin1 = fs::read_to_string("testfiles/aa-ab-ba.slang").unwrap();
+        let slang1: Box<dyn EbiTraitFiniteStochasticLanguage> = Box::new(fin1.parse::<FiniteStochasticLanguage>().unwrap());
+        let fin2 = fs::read_to_string("testfiles/ba.slang").unwrap();
+        let mut slang2: Box<dyn EbiTraitFiniteStochasticLanguage> = Box::new(fin2.parse::<FiniteStochasticLanguage>().unwrap());
+        assert_eq!(slang1.earth_movers_stochastic_conformance(slang2.as_mut(), &TraceDistance::ZeroOne).unwrap(), Fraction::from((3, 5)));
+        assert_eq!(slang1.earth_movers_stochastic_conformance(slang2.as_mut(), &TraceDistance::NormalisedLevenshtein).unwrap(), Fraction::from((7, 10)));
+        assert!(slang1.earth_movers_stochastic_conformance(slang2.as_mut(), &TraceDistance::Levenshtein).is_err());
     }
 
     #[test]
@@ -917,6 +1018,703 @@ mod tests {
         assert_eq!(slang2.len(), 0);
     }
 
+    #[test]
+    fn slpn_to_sdfa() {
+        let fin = fs::read_to_string("testfiles/aa-ab-ba_uni.slpn").unwrap();
+        let slpn = fin.parse::<StochasticLabelledPetriNet>().unwrap();
+        let sdfa = slpn.get_stochastic_deterministic_finite_automaton(100).unwrap();
+
+        //the three final markings collapse into one state
+        assert_eq!(sdfa.get_max_state(), 3);
+        assert_eq!(sdfa.get_number_of_transitions(), 5);
+        assert!(sdfa.get_termination_probability(sdfa.get_initial_state()).is_zero());
+        assert!(sdfa.get_probabilities().iter().all(|probability| probability == &Fraction::from((1, 2)) || probability.is_one()));
+
+        let fin = fs::read_to_string("testfiles/unbounded_empty.slpn").unwrap();
+        let slpn = fin.parse::<StochasticLabelledPetriNet>().unwrap();
+        assert!(slpn.get_stochastic_deterministic_finite_automaton(100).is_err());
+
+        //this net has infinitely many distinct distributions over its markings
+        let fin = fs::read_to_string("testfiles/irregular.slpn").unwrap();
+        let slpn = fin.parse::<StochasticLabelledPetriNet>().unwrap();
+        assert!(slpn.get_stochastic_deterministic_finite_automaton(100).is_err());
+    }
+
+    #[test]
+    fn convert_slpn_to_sdfa() {
+        //not every net has a finite SDFA, so a net is not imported as one
+        let mut reader = MultipleReader::from_file(File::open("testfiles/aa-ab-ba_uni.slpn").unwrap());
+        assert!(ebi_input::read_as_object(&EbiObjectType::StochasticDeterministicFiniteAutomaton, &mut reader).is_err());
+
+        //the conversion command does convert it
+        let fin = fs::read_to_string("testfiles/aa-ab-ba_uni.slpn").unwrap();
+        let slpn = fin.parse::<StochasticLabelledPetriNet>().unwrap();
+        let inputs = vec![EbiInput::Object(EbiObject::StochasticLabelledPetriNet(slpn), &EBI_STOCHASTIC_LABELLED_PETRI_NET)];
+        match EBI_CONVERT_SDFA {
+            EbiCommand::Command { execute, .. } => match execute(inputs, None).unwrap() {
+                EbiOutput::Object(EbiObject::StochasticDeterministicFiniteAutomaton(sdfa)) => assert_eq!(sdfa.get_max_state(), 3),
+                _ => panic!("expected a stochastic deterministic finite automaton"),
+            },
+            _ => panic!("expected a command"),
+        }
+    }
+
+    #[test]
+    fn determinise_minimise() {
+        //the minimal DFA of {aa, ab, ba} has four states and five transitions
+        let fin = fs::read_to_string("testfiles/aa-ab-ba.dfa").unwrap();
+        let dfa = fin.parse::<DeterministicFiniteAutomaton>().unwrap().minimise();
+        assert_eq!(dfa.get_max_state(), 3);
+        assert_eq!(dfa.get_sources().len(), 5);
+
+        let fin = fs::read_to_string("testfiles/aa-ab-ba.lpn").unwrap();
+        let lpn = fin.parse::<LabelledPetriNet>().unwrap();
+        let dfa = lpn.determinise(100).unwrap().minimise();
+        assert_eq!(dfa.get_max_state(), 3);
+        assert_eq!(dfa.get_sources().len(), 5);
+
+        let fin = fs::read_to_string("testfiles/aa-ab-ba.ptree").unwrap();
+        let tree = fin.parse::<ProcessTree>().unwrap();
+        let dfa = tree.determinise(100).unwrap().minimise();
+        assert_eq!(dfa.get_max_state(), 3);
+        assert_eq!(dfa.get_sources().len(), 5);
+
+        //a b+
+        let fin = fs::read_to_string("testfiles/a-b_star.dfm").unwrap();
+        let dfm = fin.parse::<DirectlyFollowsModel>().unwrap();
+        let dfa = dfm.determinise(100).unwrap().minimise();
+        assert_eq!(dfa.get_max_state(), 2);
+        assert_eq!(dfa.get_sources().len(), 3);
+    }
+
+    #[test]
+    fn compare_languages() {
+        let fin = fs::read_to_string("testfiles/aa-ab-ba.lpn").unwrap();
+        let lpn = EbiTraitSemantics::Marking(Box::new(fin.parse::<LabelledPetriNet>().unwrap()));
+        let fin = fs::read_to_string("testfiles/aa-ab-ba.ptree").unwrap();
+        let tree = fin.parse::<ProcessTree>().unwrap().to_semantics();
+        assert_eq!(lpn.check_equivalence(&tree).unwrap().len(), 0);
+        assert_eq!(tree.check_inclusion(&lpn).unwrap().len(), 0);
+
+        //a b+ is not included in {aa, ab, ba}, as it contains abb
+        let fin = fs::read_to_string("testfiles/a-b_star.dfm").unwrap();
+        let dfm = fin.parse::<DirectlyFollowsModel>().unwrap().to_semantics();
+        let counterexample = dfm.check_inclusion(&lpn).unwrap();
+        assert_eq!(counterexample.len(), 1);
+        assert_eq!(counterexample.get_trace(0).unwrap().len(), 3);
+        assert_eq!(dfm.check_equivalence(&lpn).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn coverability_bounds() {
+        let fin = fs::read_to_string("testfiles/aa-ab-ba.lpn").unwrap();
+        let lpn = fin.parse::<LabelledPetriNet>().unwrap();
+        let graph = lpn.get_coverability_graph().unwrap();
+        assert!(graph.is_bounded());
+        assert_eq!(graph.get_place_bounds(), vec![Some(1), Some(1), Some(1)]);
+        assert!(lpn.verify_bounded().is_ok());
+
+        //the first transition keeps putting tokens in the second place
+        let fin = fs::read_to_string("testfiles/unbounded_empty.slpn").unwrap();
+        let slpn = fin.parse::<StochasticLabelledPetriNet>().unwrap();
+        let graph = slpn.get_coverability_graph().unwrap();
+        assert!(!graph.is_bounded());
+        assert_eq!(graph.get_place_bounds(), vec![Some(1), None]);
+        assert_eq!(graph.get_unbounded_places(), vec![1]);
+        assert!(slpn.verify_bounded().is_err());
+    }
+
+    #[test]
+    fn probability_unbounded() {
+        //an unbounded net cannot be converted to an SDFA
+        let fin = fs::read_to_string("testfiles/unbounded_empty.slpn").unwrap();
+        let slpn = fin.parse::<StochasticLabelledPetriNet>().unwrap();
+        let error = slpn.get_stochastic_deterministic_finite_automaton(100).unwrap_err();
+        assert!(error.to_string().starts_with("The net is unbounded"));
+
+        //neither can its probabilities be computed
+        let fin = fs::read_to_string("testfiles/aa-ab-ba.lang").unwrap();
+        let lang = fin.parse::<FiniteLanguage>().unwrap();
+        let inputs = vec![
+            EbiInput::Object(EbiObject::StochasticLabelledPetriNet(slpn), &EBI_STOCHASTIC_LABELLED_PETRI_NET),
+            EbiInput::Trait(EbiTraitObject::FiniteLanguage(Box::new(lang)), &EBI_FINITE_LANGUAGE),
+        ];
+        match EBI_PROBABILITY_MODEL {
+            EbiCommand::Command { execute, .. } => {
+                let error = execute(inputs, None).err().unwrap();
+                assert!(error.to_string().starts_with("The net is unbounded"));
+            },
+            _ => panic!("expected a command"),
+        }
+    }
+
+    #[test]
+    fn petri_net_invariants() {
+        //aa-ab-ba has no cycles and loses its token
+        let fin = fs::read_to_string("testfiles/aa-ab-ba.lpn").unwrap();
+        let lpn = fin.parse::<LabelledPetriNet>().unwrap();
+        assert!(lpn.get_place_semiflows().unwrap().is_empty());
+        assert!(lpn.get_transition_semiflows().unwrap().is_empty());
+
+        //a cycle of two transitions, of which the first also produces a token that the second consumes
+        let mut lpn = LabelledPetriNet::new();
+        let places = [lpn.add_place(), lpn.add_place(), lpn.add_place()];
+        let a = lpn.get_activity_key_mut().process_activity("a");
+        let t0 = lpn.add_transition(Some(a));
+        let t1 = lpn.add_transition(None);
+        lpn.add_place_transition_arc(places[0], t0, 1).unwrap();
+        lpn.add_transition_place_arc(t0, places[1], 1).unwrap();
+        lpn.add_transition_place_arc(t0, places[2], 1).unwrap();
+        lpn.add_place_transition_arc(places[1], t1, 1).unwrap();
+        lpn.add_place_transition_arc(places[2], t1, 1).unwrap();
+        lpn.add_transition_place_arc(t1, places[0], 1).unwrap();
+
+        assert_eq!(lpn.get_place_semiflows().unwrap(), vec![vec![1, 0, 1], vec![1, 1, 0]]);
+        assert_eq!(lpn.get_transition_semiflows().unwrap(), vec![vec![1, 1]]);
+        assert!(lpn.get_invariants().unwrap().to_string().contains("t0 (a) + t1 (silent)"));
+    }
+
+    #[test]
+    fn reduce_nets() {
+        //the net of a DFM has a sink place and silent transitions to it
+        let fin = fs::read_to_string("testfiles/a-b_star.dfm").unwrap();
+        let lpn = fin.parse::<DirectlyFollowsModel>().unwrap().get_labelled_petri_net();
+        let reduced = lpn.reduce().unwrap();
+        assert!(reduced.get_number_of_places() < lpn.get_number_of_places());
+        assert!(reduced.get_number_of_transitions() <= lpn.get_number_of_transitions());
+        let lpn = EbiTraitSemantics::Marking(Box::new(lpn));
+        let reduced = EbiTraitSemantics::Marking(Box::new(reduced));
+        assert_eq!(lpn.check_equivalence(&reduced).unwrap().len(), 0);
+
+        //a silent choice: a with probability 1/2, b with 3/8 and c with 1/8
+        let fin = "stochastic labelled Petri net\n3\n1\n0\n0\n4\nsilent\n1\n1\n0\n1\n1\nlabel a\n1\n1\n0\n1\n2\nlabel b\n3\n1\n1\n0\nlabel c\n1\n1\n1\n0\n";
+        let mut slpn = fin.parse::<StochasticLabelledPetriNet>().unwrap();
+        let reduced = slpn.reduce().unwrap();
+        assert_eq!(reduced.get_number_of_places(), 1);
+        assert_eq!(reduced.get_number_of_transitions(), 3);
+        for (activity, probability) in [("a", "1/2"), ("b", "3/8"), ("c", "1/8")] {
+            let trace = vec![slpn.get_activity_key_mut().process_activity(activity)];
+            let follower = FollowerSemantics::Trace(&trace);
+            assert_eq!(slpn.get_probability(&follower).unwrap(), probability.parse::<Fraction>().unwrap());
+            assert_eq!(reduced.get_probability(&follower).unwrap(), probability.parse::<Fraction>().unwrap());
+        }
+    }
+
+    #[test]
+    fn object_centric_event_log() {
+        let fin = fs::read_to_string("testfiles/a-b.jsonocel").unwrap();
+        let ocel = fin.parse::<ObjectCentricEventLog>().unwrap();
+        assert_eq!(ocel.get_object_types(), vec!["order", "item"]);
+
+        let mut info = vec![];
+        ocel.info(&mut info).unwrap();
+        let info = String::from_utf8(info).unwrap();
+        assert!(info.contains("\torder\t2 objects\t3 events"));
+        assert!(info.contains("\titem\t1 objects\t2 events"));
+
+        //without an object type, the log cannot be read as an event log
+        let mut reader = MultipleReader::String(fin.clone());
+        assert!(ebi_input::read_as_object(&EbiObjectType::EventLog, &mut reader).is_err());
+        assert!(ebi_input::read_as_trait(&EbiTrait::EventLog, &mut reader).is_err());
+
+        //the item is referred to twice by the same event, which appears once in its trace, after the earlier event a
+        let log = ocel.flatten_on("item").unwrap();
+        assert_eq!(log.len(), 1);
+        let trace = log.get_trace(0).unwrap().iter().map(|activity| log.get_activity_key().get_activity_label(activity).to_string()).collect::<Vec<_>>();
+        assert_eq!(trace, vec!["a", "c"]);
+
+        let log = ocel.flatten_on("order").unwrap();
+        assert_eq!(log.len(), 2);
+
+        assert!(ocel.flatten_on("customer").is_err());
+    }
+
+    #[test]
+    fn alergia() {
+        //a, followed by a geometric number of bs, followed by c
+        let mut fin = "case,activity\n".to_string();
+        let mut case = 0;
+        for (bs, count) in [(0, 16), (1, 8), (2, 4), (3, 2), (4, 1)] {
+            for _ in 0..count {
+                fin.push_str(&format!("{},a\n", case));
+                (0..bs).for_each(|_| fin.push_str(&format!("{},b\n", case)));
+                fin.push_str(&format!("{},c\n", case));
+                case += 1;
+            }
+        }
+        let log: Box<dyn EbiTraitEventLog> = Box::new(CsvEventLog::import_with_options(&mut Cursor::new(&fin), &CsvImportOptions::default()).unwrap());
+
+        let sdfa = log.mine_alergia(&Fraction::from((1, 20))).unwrap();
+        assert_eq!(sdfa.get_max_state(), 2);
+        assert_eq!(sdfa.get_number_of_transitions(), 3);
+        assert!(sdfa.get_termination_probability(2).is_one());
+
+        assert!(log.mine_alergia(&Fraction::zero()).is_err());
+    }
+
+    #[test]
+    fn alpha_and_split_miner() {
+        let fin = "finite stochastic language\n3\n1/3\n4\na\nb\nc\nd\n1/3\n4\na\nc\nb\nd\n1/3\n3\na\ne\nd";
+        let slang = fin.parse::<FiniteStochasticLanguage>().unwrap();
+
+        //alpha: e is in choice with both b and c, which are concurrent
+        let language: Box<dyn EbiTraitFiniteLanguage> = Box::new(slang.clone());
+        let lpn = language.mine_alpha();
+        assert_eq!(lpn.get_number_of_places(), 6);
+        assert_eq!(lpn.get_number_of_transitions(), 5);
+        let mut slpn = lpn.mine_uniform_stochastic();
+        for (trace, probability) in [(vec!["a", "b", "c", "d"], (1, 3)), (vec!["a", "c", "b", "d"], (1, 3)), (vec!["a", "e", "d"], (1, 3)), (vec!["a", "b", "d"], (0, 1))] {
+            let trace = slpn.get_activity_key_mut().process_trace(&trace.into_iter().map(|activity| activity.to_string()).collect());
+            assert_eq!(slpn.get_probability(&FollowerSemantics::Trace(&trace)).unwrap(), Fraction::from(probability));
+        }
+
+        //alpha: a trace of a length-one loop only, which is a self-loop on the source, can reach the sink silently
+        let fin = fs::read_to_string("testfiles/aa.lang").unwrap();
+        let language: Box<dyn EbiTraitFiniteLanguage> = Box::new(fin.parse::<FiniteLanguage>().unwrap());
+        let mut slpn = language.mine_alpha().mine_uniform_stochastic();
+        for (trace, probability) in [(vec!["a", "a"], (1, 8)), (vec![], (1, 2))] {
+            let trace = slpn.get_activity_key_mut().process_trace(&trace.into_iter().map(|activity: &str| activity.to_string()).collect());
+            assert_eq!(slpn.get_probability(&FollowerSemantics::Trace(&trace)).unwrap(), Fraction::from(probability));
+        }
+
+        //split: the silent xor-split chooses between e and the and-split of b and c
+        let language: Box<dyn EbiTraitFiniteStochasticLanguage> = Box::new(slang);
+        let mut slpn = language.mine_split(&Fraction::zero(), &Fraction::from((1, 5))).unwrap().mine_uniform_stochastic();
+        for (trace, probability) in [(vec!["a", "b", "c", "d"], (1, 4)), (vec!["a", "c", "b", "d"], (1, 4)), (vec!["a", "e", "d"], (1, 2)), (vec!["a", "b", "d"], (0, 1))] {
+            let trace = slpn.get_activity_key_mut().process_trace(&trace.into_iter().map(|activity| activity.to_string()).collect());
+            assert_eq!(slpn.get_probability(&FollowerSemantics::Trace(&trace)).unwrap(), Fraction::from(probability));
+        }
+
+        assert!(language.mine_split(&Fraction::from(2), &Fraction::zero()).is_err());
+    }
+
+    #[test]
+    fn weight_estimators() {
+        let fin = "finite stochastic language\n3\n1/5\n4\na\nb\nc\nd\n1/5\n4\na\nc\nb\nd\n3/5\n3\na\ne\nd";
+        let slang = fin.parse::<FiniteStochasticLanguage>().unwrap();
+        let language: Box<dyn EbiTraitFiniteLanguage> = Box::new(slang.clone());
+        let lpn = language.mine_alpha();
+
+        //weights of b and d: b is concurrent with c and in choice with e, and d follows b, c and e
+        for (estimator, weight_b, weight_d) in [
+            (WeightEstimator::Frequency, (2, 5), (1, 1)),
+            (WeightEstimator::LeftHandPair, (1, 5), (1, 1)),
+            (WeightEstimator::RightHandPair, (1, 5), (1, 1)),
+            (WeightEstimator::PairScale, (1, 5), (2, 1)),
+            (WeightEstimator::Fork, (2, 5), (1, 1)),
+            (WeightEstimator::BillClinton, (4, 15), (1, 1)),
+        ] {
+            let mut slpn = lpn.clone().mine_estimator_stochastic(Box::new(slang.clone()), estimator);
+            let b = slpn.get_activity_key_mut().process_activity("b");
+            let d = slpn.get_activity_key_mut().process_activity("d");
+            let transition_b = (0..slpn.get_number_of_transitions()).find(|transition| slpn.get_transition_label(*transition) == Some(b)).unwrap();
+            let transition_d = (0..slpn.get_number_of_transitions()).find(|transition| slpn.get_transition_label(*transition) == Some(d)).unwrap();
+            assert_eq!(slpn.weights[transition_b], Fraction::from(weight_b), "{:?}", estimator);
+            assert_eq!(slpn.weights[transition_d], Fraction::from(weight_d), "{:?}", estimator);
+        }
+    }
+
+    #[test]
+    fn executions_round_trip() {
+        let fin = fs::read_to_string("testfiles/a-b.exs").unwrap();
+        let executions = fin.parse::<Executions>().unwrap();
+        assert_eq!(executions.executions.len(), 6);
+
+        //json
+        let exported = executions.to_string();
+        assert_eq!(exported.parse::<Executions>().unwrap(), executions);
+
+        //a different version, or a transition that is not enabled, is rejected
+        assert!(exported.replace("\"version\":1", "\"version\":2").parse::<Executions>().is_err());
+        assert!(exported.replacen("\"transition\":0", "\"transition\":4", 1).parse::<Executions>().is_err());
+
+        //csv: the cost and resource of the first event are features of the enablement of the second transition
+        let mut c = Cursor::new(Vec::new());
+        CsvExecutions::export(&executions, &mut c).unwrap();
+        let csv = String::from_utf8(c.into_inner()).unwrap();
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0], "trace,transition,enabled transitions at enablement,time of enablement,time of execution,event:concept:name,event:cost,event:resource,event:time:timestamp,trace:concept:name,trace:priority");
+        assert_eq!(lines[4], "1,3,2 3,2024-01-01T09:00:00+00:00,2024-01-02T09:00:00+00:00,a,5.5,Bob; Jr.,2024-01-01T09:00:00+00:00,2,low");
+        assert!(CsvExecutions::validate(&mut Cursor::new(&csv)).is_ok());
+        assert!(CsvExecutions::validate(&mut Cursor::new(&lines[..5].join("\n").replace("1,3,2 3", "1,3"))).is_err());
+    }
+
+    #[test]
+    fn executions_find() {
+        let fin = fs::read_to_string("testfiles/a-b.csv").unwrap();
+        let log: Box<dyn EbiTraitEventLog> = Box::new(CsvEventLog::import_with_options(&mut Cursor::new(&fin), &CsvImportOptions::default()).unwrap());
+        let fin2 = fs::read_to_string("testfiles/aa-ab-ba.lpn").unwrap();
+        let lpn = fin2.parse::<LabelledPetriNet>().unwrap();
+
+        let executions = EbiTraitSemantics::Marking(Box::new(lpn)).find_executions(log).unwrap();
+        let fin3 = fs::read_to_string("testfiles/a-b.exs").unwrap();
+        assert_eq!(executions, fin3.parse::<Executions>().unwrap());
+    }
+
+    #[test]
+    fn executions_weights() {
+        let fin = "case,activity\n1,a\n1,b\n2,a\n2,a\n3,a\n3,b\n4,b\n4,a\n";
+        let log: Box<dyn EbiTraitEventLog> = Box::new(CsvEventLog::import_with_options(&mut Cursor::new(&fin), &CsvImportOptions::default()).unwrap());
+        let fin2 = fs::read_to_string("testfiles/aa-ab-ba.lpn").unwrap();
+        let lpn = fin2.parse::<LabelledPetriNet>().unwrap();
+
+        //the second transition of each trace is enabled by the first
+        let executions = EbiTraitSemantics::Marking(Box::new(lpn.clone())).find_executions(log).unwrap();
+        assert_eq!(executions.executions.len(), 8);
+        assert_eq!(executions.executions[1].enabled_transitions_at_enablement, Some(vec![2, 3]));
+
+        //a is chosen 3 out of 4 times at the start, and b 2 out of 3 times after a
+        let (slpn, report) = lpn.mine_executions_stochastic(&executions).unwrap();
+        let weights = [(3, 2), (1, 2), (2, 3), (4, 3), (1, 1)].into_iter().map(Fraction::from).collect::<Vec<_>>();
+        assert_eq!(slpn.weights, weights);
+        assert!(report.converged);
+    }
+
+    #[test]
+    fn executions_silent() {
+        let fin = "case,activity\n1,a\n1,b\n";
+        let log: Box<dyn EbiTraitEventLog> = Box::new(CsvEventLog::import_with_options(&mut Cursor::new(&fin), &CsvImportOptions::default()).unwrap());
+        let fin2 = "labelled Petri net\n# number of places\n3\n# initial marking\n1\n0\n0\n# number of transitions\n3\n# transition 0\nlabel a\n# number of input places\n1\n0\n# number of output places\n1\n1\n# transition 1\nsilent\n# number of input places\n1\n1\n# number of output places\n1\n2\n# transition 2\nlabel b\n# number of input places\n1\n2\n# number of output places\n0\n";
+        let lpn = fin2.parse::<LabelledPetriNet>().unwrap();
+
+        //each transition is enabled by the move just before it, also if that move is silent, such that the transition is in its own enabled transitions
+        let executions = EbiTraitSemantics::Marking(Box::new(lpn)).find_executions(log).unwrap();
+        assert_eq!(executions.executions.iter().map(|execution| execution.transition).collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(executions.executions.iter().map(|execution| execution.enabled_transitions_at_enablement.clone()).collect::<Vec<_>>(), vec![Some(vec![0]), Some(vec![1]), Some(vec![2])]);
+        assert_eq!(executions.to_string().parse::<Executions>().unwrap(), executions);
+    }
+
+    #[test]
+    fn maximum_likelihood_requires_approximate_arithmetic() {
+        //the optimisation itself uses approximate arithmetic, which cannot be enabled globally while other tests run in parallel
+        let fin = fs::read_to_string("testfiles/aa-ab-ba.slang").unwrap();
+        let slang = fin.parse::<FiniteStochasticLanguage>().unwrap();
+        let fin2 = fs::read_to_string("testfiles/aa-ab-ba.lpn").unwrap();
+        let lpn = fin2.parse::<LabelledPetriNet>().unwrap();
+
+        assert!(lpn.mine_maximum_likelihood_stochastic(Box::new(slang)).is_err());
+    }
+
+    #[test]
+    fn maximum_likelihood_optimiser() {
+        //a choice between a and b, of which the log has a three times as often as b
+        let log_likelihood = |log_weights: &[f64]| {
+            let (a, b) = (log_weights[0].exp(), log_weights[1].exp());
+            Ok(0.75 * (a / (a + b)).ln() + 0.25 * (b / (a + b)).ln())
+        };
+        let start = vec![0.0, 0.0];
+        let start_value = log_likelihood(&start).unwrap();
+        let (log_weights, value) = maximum_likelihood_stochastic_miner::maximise(start, start_value, log_likelihood).unwrap();
+
+        assert!(((log_weights[0] - log_weights[1]).exp() - 3.0).abs() < 1e-3);
+        assert!((value - (0.75 * 0.75f64.ln() + 0.25 * 0.25f64.ln())).abs() < 1e-6);
+        assert!(value > start_value);
+    }
+
+    #[test]
+    fn csv_event_log() {
+        let fin = fs::read_to_string("testfiles/a-b.csv").unwrap();
+        let log = CsvEventLog::import_with_options(&mut Cursor::new(&fin), &CsvImportOptions::default()).unwrap();
+
+        //the events of case 3 are in the file in the wrong order
+        assert_eq!(log.len(), 3);
+        let slang = log.get_finite_stochastic_language();
+        assert_eq!(slang.len(), 1);
+
+        //priority is constant within each case, whereas cost and resource are not
+        let trace_attributes = log.get_trace_attributes();
+        assert!(trace_attributes.contains_key("priority"));
+        assert!(!trace_attributes.contains_key("cost"));
+        let event = &log.get_log().traces[1].events[0];
+        assert_eq!(event.attributes.iter().find(|attribute| attribute.key == "cost").unwrap().value, AttributeValue::Float(5.5));
+        assert_eq!(event.attributes.iter().find(|attribute| attribute.key == "resource").unwrap().value, AttributeValue::String("Bob; Jr.".to_string()));
+
+        //choose the resource as activity
+        let options = CsvImportOptions {
+            activity_column: Some("resource".to_string()),
+            ..Default::default()
+        };
+        let log = CsvEventLog::import_with_options(&mut Cursor::new(&fin), &options).unwrap();
+        assert_eq!(log.get_finite_stochastic_language().len(), 2);
+
+        let options = CsvImportOptions {
+            case_column: Some("order".to_string()),
+            ..Default::default()
+        };
+        assert!(CsvEventLog::import_with_options(&mut Cursor::new(&fin), &options).is_err());
+        assert!(CsvEventLog::import_with_options(&mut Cursor::new("case,activity\n1,a,b\n"), &CsvImportOptions::default()).is_err());
+        assert!(CsvEventLog::import_with_options(&mut Cursor::new("id,name\n1,a\n"), &CsvImportOptions::default()).is_err());
+
+        //columns that cannot be recognised by their names cannot be read directly
+        let mut reader = MultipleReader::String("id,name\n1,a\n1,b\n2,a\n".to_string());
+        assert!(ebi_input::read_as_object(&EbiObjectType::EventLog, &mut reader).is_err());
+
+        //convert event-log reads the file itself
+        let inputs = vec![EbiInput::String("testfiles/a-b.csv".to_string())];
+        match EBI_CONVERT_LOG {
+            EbiCommand::Command { execute, .. } => match execute(inputs, None).unwrap() {
+                EbiOutput::Object(EbiObject::EventLog(log)) => assert_eq!(log.get_log().traces.len(), 3),
+                _ => panic!("expected an event log"),
+            },
+            _ => panic!("expected a command"),
+        }
+    }
+
+    #[test]
+    fn pnml_stochastic_labelled_petri_net() {
+        let fin = fs::read_to_string("testfiles/aa-ab-ba_ali.slpn").unwrap();
+        let mut slpn = fin.parse::<StochasticLabelledPetriNet>().unwrap();
+        slpn.weights[0] = Fraction::from((3, 8));
+
+        //the weights must survive a round trip through PNML
+        let mut pnml = vec![];
+        PetriNetMarkupLanguage::export_stochastic_labelled_petri_net(&slpn, &mut pnml).unwrap();
+        let slpn2 = PetriNetMarkupLanguage::import_as_stochastic_labelled_petri_net(&mut Cursor::new(&pnml)).unwrap();
+        assert_eq!(slpn2.get_transition_weight(0), &Fraction::from((3, 8)));
+        assert_eq!(slpn.to_string(), slpn2.to_string());
+
+        //decimal weights and invisible transitions as written by ProM
+        let prom = "<pnml><net id=\"n\"><page id=\"p\">
+            <place id=\"p0\"><initialMarking><text>1</text></initialMarking></place>
+            <transition id=\"t0\"><name><text>a</text></name><toolspecific tool=\"StochasticPetriNet\"><property key=\"weight\">0.25</property></toolspecific></transition>
+            <transition id=\"t1\"><name><text>tau</text></name><toolspecific tool=\"ProM\" activity=\"$invisible$\"/></transition>
+            <arc id=\"a0\" source=\"p0\" target=\"t0\"><inscription><text>1</text></inscription></arc>
+            <arc id=\"a1\" source=\"p0\" target=\"t1\"/>
+        </page></net></pnml>";
+        let slpn3 = PetriNetMarkupLanguage::import_as_stochastic_labelled_petri_net(&mut Cursor::new(prom)).unwrap();
+        assert_eq!(slpn3.get_transition_weight(0), &Fraction::from((1, 4)));
+        assert_eq!(slpn3.get_transition_weight(1), &Fraction::one());
+        assert!(slpn3.is_transition_silent(1));
+
+        //a net without weights is a labelled Petri net, but not a stochastic one
+        let plain = "<pnml><net id=\"n\"><page id=\"p\">
+            <place id=\"p0\"><initialMarking><text>1</text></initialMarking></place>
+            <transition id=\"t0\"><name><text>a</text></name></transition>
+            <arc id=\"a0\" source=\"p0\" target=\"t0\"/>
+        </page></net></pnml>";
+        assert!(PetriNetMarkupLanguage::import_as_stochastic_labelled_petri_net(&mut Cursor::new(plain)).is_err());
+        assert!(ebi_input::read_as_trait(&EbiTrait::StochasticSemantics, &mut MultipleReader::String(plain.to_string())).is_err());
+
+        let fin = fs::read_to_string("testfiles/a.pnml").unwrap();
+        let slpn4 = PetriNetMarkupLanguage::import_as_stochastic_labelled_petri_net(&mut Cursor::new(&fin)).unwrap();
+        assert_eq!(slpn4.get_transition_weight(0), &Fraction::from((1, 2)));
+    }
+
+    #[test]
+    fn bpmn() {
+        let mut fin = fs::read("testfiles/aa-ab-ba.bpmn").unwrap();
+        let lpn: LabelledPetriNet = BusinessProcessModelAndNotation::import(&mut Cursor::new(&mut fin)).unwrap().into();
+        let mut slpn = lpn.mine_uniform_stochastic();
+
+        //the sub-process yields aa and ab, the parallel gateways yield ab and ba
+        for (trace, probability) in [(vec!["a", "a"], (1, 4)), (vec!["a", "b"], (1, 2)), (vec!["b", "a"], (1, 4)), (vec!["b", "b"], (0, 1))] {
+            let trace = slpn.get_activity_key_mut().process_trace(&trace.into_iter().map(|activity| activity.to_string()).collect());
+            assert_eq!(slpn.get_probability(&FollowerSemantics::Trace(&trace)).unwrap(), Fraction::from(probability));
+        }
+
+        //unsupported elements are reported with their line
+        let text = String::from_utf8(fin).unwrap().replace("<task id=\"b\" name=\"b\">", "<task id=\"b\" name=\"b\"><standardLoopCharacteristics/>");
+        let error = BusinessProcessModelAndNotation::import(&mut Cursor::new(text)).err().unwrap();
+        assert_eq!(error.to_string(), "BPMN element `standardLoopCharacteristics` at line 55 is not supported.");
+
+        //parallel sequence flows between the same elements each get their own place
+        let text = "<definitions xmlns=\"http://www.omg.org/spec/BPMN/20100524/MODEL\"><process id=\"process\">
+            <startEvent id=\"start\"/><parallelGateway id=\"split\"/><parallelGateway id=\"join\"/><task id=\"a\" name=\"a\"/><endEvent id=\"end\"/>
+            <sequenceFlow id=\"f1\" sourceRef=\"start\" targetRef=\"split\"/>
+            <sequenceFlow id=\"f2\" sourceRef=\"split\" targetRef=\"join\"/>
+            <sequenceFlow id=\"f3\" sourceRef=\"split\" targetRef=\"join\"/>
+            <sequenceFlow id=\"f4\" sourceRef=\"join\" targetRef=\"a\"/>
+            <sequenceFlow id=\"f5\" sourceRef=\"a\" targetRef=\"end\"/>
+        </process></definitions>";
+        let lpn: LabelledPetriNet = BusinessProcessModelAndNotation::import(&mut Cursor::new(text)).unwrap().into();
+        assert_eq!(lpn.get_number_of_places(), 6);
+        let mut slpn = lpn.mine_uniform_stochastic();
+        let trace = slpn.get_activity_key_mut().process_trace(&vec!["a".to_string()]);
+        assert_eq!(slpn.get_probability(&FollowerSemantics::Trace(&trace)).unwrap(), Fraction::one());
+
+        //a sub-process of which the parallel branches end in separate end events would complete twice
+        let text = "<definitions xmlns=\"http://www.omg.org/spec/BPMN/20100524/MODEL\"><process id=\"process\">
+            <startEvent id=\"start\"/><subProcess id=\"sub\">
+                <startEvent id=\"sub_start\"/><parallelGateway id=\"split\"/><task id=\"a\" name=\"a\"/><task id=\"b\" name=\"b\"/><endEvent id=\"end_a\"/><endEvent id=\"end_b\"/>
+                <sequenceFlow id=\"s1\" sourceRef=\"sub_start\" targetRef=\"split\"/>
+                <sequenceFlow id=\"s2\" sourceRef=\"split\" targetRef=\"a\"/>
+                <sequenceFlow id=\"s3\" sourceRef=\"split\" targetRef=\"b\"/>
+                <sequenceFlow id=\"s4\" sourceRef=\"a\" targetRef=\"end_a\"/>
+                <sequenceFlow id=\"s5\" sourceRef=\"b\" targetRef=\"end_b\"/>
+            </subProcess><endEvent id=\"end\"/>
+            <sequenceFlow id=\"f1\" sourceRef=\"start\" targetRef=\"sub\"/>
+            <sequenceFlow id=\"f2\" sourceRef=\"sub\" targetRef=\"end\"/>
+        </process></definitions>";
+        let error = BusinessProcessModelAndNotation::import(&mut Cursor::new(text)).err().unwrap();
+        assert_eq!(error.to_string(), "Sub-process `sub` at line 2 can reach an end event while another part of it is still active, which is not supported.");
+    }
+
+    #[test]
+    fn ptml_round_trip() {
+        for file in ["aa.ptree", "aa-ab-ba.ptree", "all_operators.ptree", "tree.ptree"] {
+            let tree = fs::read_to_string(format!("testfiles/{}", file)).unwrap().parse::<ProcessTree>().unwrap();
+            let expected = tree.to_string();
+
+            let mut ptml = vec![];
+            ProcessTreeMarkupLanguage::from(tree).export(&mut ptml).unwrap();
+            let tree2: ProcessTree = ProcessTreeMarkupLanguage::import(&mut Cursor::new(ptml)).unwrap().into();
+            assert_eq!(expected, tree2.to_string());
+        }
+
+        let mut fin = fs::read("testfiles/all_operators.ptml").unwrap();
+        let tree: ProcessTree = ProcessTreeMarkupLanguage::import(&mut Cursor::new(&mut fin)).unwrap().into();
+        assert_eq!(fs::read_to_string("testfiles/all_operators.ptree").unwrap().parse::<ProcessTree>().unwrap().to_string(), tree.to_string());
+    }
+
+    #[test]
+    fn pm4py_process_tree() {
+        let tree = ProcessTree::from_pm4py_notation("->('a', X('b', tau), *('c', tau))").unwrap();
+        assert_eq!(tree.get_number_of_nodes(), 8);
+        assert_eq!(tree.to_pm4py_notation(), "->('a', X('b', tau), *('c', tau))");
+
+        //round trip of all operators and of quotes in labels
+        let fin = fs::read_to_string("testfiles/all_operators.ptree").unwrap();
+        let tree = fin.parse::<ProcessTree>().unwrap();
+        let tree2 = ProcessTree::from_pm4py_notation(&tree.to_pm4py_notation()).unwrap();
+        assert_eq!(tree.to_string(), tree2.to_string());
+        let tree = ProcessTree::from_pm4py_notation(" + ( \"it's\" , 'a\\\\b' ) ").unwrap();
+        assert_eq!(tree.to_pm4py_notation(), "+('it\\'s', 'a\\\\b')");
+
+        let fin = fs::read_to_string("testfiles/all_operators.pm4py").unwrap();
+        assert_eq!(ProcessTree::from_pm4py_notation(&fin).unwrap().to_pm4py_notation() + "\n", fin);
+
+        assert!(ProcessTree::from_pm4py_notation("X()").is_err());
+        assert!(ProcessTree::from_pm4py_notation("->('a', 'b'").is_err());
+        assert!(ProcessTree::from_pm4py_notation("'a' 'b'").is_err());
+    }
+
+    #[test]
+    fn inductive_miner() {
+        let fin = "finite stochastic language\n4\n4/10\n4\na\nb\nc\nd\n3/10\n4\na\nc\nb\nd\n2/10\n7\na\nb\nc\ne\nc\nb\nd\n1/10\n3\na\nc\nd";
+        let slang: Box<dyn EbiTraitFiniteStochasticLanguage> = Box::new(fin.parse::<FiniteStochasticLanguage>().unwrap());
+
+        let tree = slang.mine_inductive();
+        let expected = "process tree\nsequence\n3\nactivity a\nconcurrent\n2\nxor\n2\ntau\nloop\n2\nactivity b\nactivity e\nloop\n2\nactivity c\ntau\nactivity d";
+        assert_eq!(tree.to_string(), expected.parse::<ProcessTree>().unwrap().to_string());
+
+        //the trace without b is infrequent
+        let tree = slang.mine_inductive_infrequent(&Fraction::from((1, 2))).unwrap();
+        let expected = "process tree\nsequence\n3\nactivity a\nconcurrent\n2\nloop\n2\nactivity b\nactivity e\nloop\n2\nactivity c\ntau\nactivity d";
+        assert_eq!(tree.to_string(), expected.parse::<ProcessTree>().unwrap().to_string());
+
+        assert_eq!(slang.mine_inductive_infrequent(&Fraction::zero()).unwrap().to_string(), slang.mine_inductive().to_string());
+        assert!(slang.mine_inductive_infrequent(&Fraction::from(2)).is_err());
+    }
+
+    #[test]
+    fn stochastic_process_tree() {
+        //a is chosen with 3/4, and the loop is exited after each c with 1/2
+        let fin = "stochastic process tree\nsequence\n2\nxor\n2\n3\n1\nactivity a\nactivity b\nloop\n2\n1\n1\nactivity c\ntau";
+        let tree = fin.parse::<StochasticProcessTree>().unwrap();
+        assert_stochastic_process_tree(tree, vec![(vec!["a", "c"], (3, 8)), (vec!["b", "c", "c"], (1, 16)), (vec!["a"], (0, 1))]);
+
+        //the weight of a xor is the probability of its child, regardless of the leaves below; an or chooses each child with its own probability
+        let fin = "stochastic process tree\nxor\n2\n1\n2\nconcurrent\n2\nactivity a\nactivity b\nor\n2\n1/2\n1/2\nactivity c\nactivity d";
+        let tree = fin.parse::<StochasticProcessTree>().unwrap();
+        assert_stochastic_process_tree(tree, vec![(vec!["a", "b"], (1, 6)), (vec!["b", "a"], (1, 6)), (vec!["c"], (2, 9)), (vec!["c", "d"], (1, 9)), (vec!["d", "c"], (1, 9))]);
+
+        //an interleaved node with a loop: the loop decides only after its body
+        let fin = "stochastic process tree\ninterleaved\n2\nloop\n2\n2\n1\nactivity a\ntau\nactivity b";
+        let tree = fin.parse::<StochasticProcessTree>().unwrap();
+        assert_stochastic_process_tree(tree, vec![(vec!["b", "a"], (1, 3)), (vec!["a", "b"], (1, 3)), (vec!["a", "a", "b"], (1, 9))]);
+
+        //nested concurrency cannot be translated into a net with the same distribution
+        let fin = "stochastic process tree\nconcurrent\n2\nactivity a\nor\n2\n1/2\n1/2\nactivity b\nactivity c";
+        let tree = fin.parse::<StochasticProcessTree>().unwrap();
+        assert!(tree.get_stochastic_labelled_petri_net().is_err());
+
+        //invalid weights
+        assert!("stochastic process tree\nxor\n2\n0\n0\nactivity a\nactivity b".parse::<StochasticProcessTree>().is_err());
+        assert!("stochastic process tree\nloop\n2\n-1\n2\nactivity a\nactivity b".parse::<StochasticProcessTree>().is_err());
+        assert!("stochastic process tree\nor\n2\n2\n1/2\nactivity a\nactivity b".parse::<StochasticProcessTree>().is_err());
+        assert!("stochastic process tree\nxor\n2\n1\nactivity a\nactivity b".parse::<StochasticProcessTree>().is_err());
+
+        let fin = fs::read_to_string("testfiles/all_operators.sptree").unwrap();
+        let tree = fin.parse::<StochasticProcessTree>().unwrap();
+        assert_eq!(tree.get_weights_of_node(3), Some(&vec![Fraction::from((1, 2)), Fraction::one()]));
+        assert_eq!(tree.to_string().parse::<StochasticProcessTree>().unwrap().to_string(), tree.to_string());
+
+        //discovery on a process tree
+        let tree = fs::read_to_string("testfiles/aa-ab-ba.ptree").unwrap().parse::<ProcessTree>().unwrap();
+        let slang = fs::read_to_string("testfiles/aa-ab-ba.slang").unwrap().parse::<FiniteStochasticLanguage>().unwrap();
+        let uniform = tree.clone().mine_uniform_stochastic().unwrap();
+        assert_eq!(uniform.get_weights_of_node(0), Some(&vec![Fraction::one(), Fraction::one()]));
+        let occurrences = tree.clone().mine_occurrences_stochastic(Box::new(slang.clone())).unwrap();
+        assert_eq!(occurrences.get_weights_of_node(0), Some(&vec![Fraction::from((2, 5)), Fraction::from((3, 5))]));
+        let mut aligned = tree.mine_stochastic_alignment(Box::new(slang)).unwrap();
+        for (trace, probability) in [(vec!["a", "a"], (1, 5)), (vec!["a", "b"], (1, 5)), (vec!["b", "a"], (3, 5))] {
+            let trace = aligned.get_activity_key_mut().process_trace_ref(&trace);
+            assert_eq!(aligned.get_probability(&FollowerSemantics::Trace(&trace)).unwrap(), Fraction::from(probability));
+        }
+    }
+
+    /**
+     * Checks the probabilities of traces in the tree and in its translation to a stochastic labelled Petri net.
+     */
+    fn assert_stochastic_process_tree(mut tree: StochasticProcessTree, traces: Vec<(Vec<&str>, (usize, usize))>) {
+        let mut slpn = tree.get_stochastic_labelled_petri_net().unwrap();
+        for (trace, probability) in traces {
+            let trace: Vec<String> = trace.into_iter().map(|activity| activity.to_string()).collect();
+            let tree_trace = tree.get_activity_key_mut().process_trace(&trace);
+            assert_eq!(tree.get_probability(&FollowerSemantics::Trace(&tree_trace)).unwrap(), Fraction::from(probability));
+            let slpn_trace = slpn.get_activity_key_mut().process_trace(&trace);
+            assert_eq!(slpn.get_probability(&FollowerSemantics::Trace(&slpn_trace)).unwrap(), Fraction::from(probability));
+        }
+    }
+
+    #[test]
+    fn workflow_net_soundness() {
+        //aa-ab-ba has no sink place
+        let fin = fs::read_to_string("testfiles/aa-ab-ba.lpn").unwrap();
+        let lpn = fin.parse::<LabelledPetriNet>().unwrap();
+        let report = lpn.check_soundness().unwrap();
+        assert!(!report.workflow_net_problems.is_empty());
+        assert!(!report.is_sound());
+
+        //a choice between b and c
+        let mut lpn = LabelledPetriNet::new();
+        let activities = ["a", "b", "c", "d"].map(|label| lpn.get_activity_key_mut().process_activity(label));
+        let places = [lpn.add_place(), lpn.add_place(), lpn.add_place()];
+        lpn.get_initial_marking_mut().increase(places[0], 1).unwrap();
+        for (transition, input, output) in [(activities[0], 0, 1), (activities[1], 1, 2), (activities[2], 1, 2)] {
+            let transition = lpn.add_transition(Some(transition));
+            lpn.add_place_transition_arc(places[input], transition, 1).unwrap();
+            lpn.add_transition_place_arc(transition, places[output], 1).unwrap();
+        }
+        let report = lpn.check_soundness().unwrap();
+        assert!(report.is_sound());
+
+        //a parallel split that is not synchronised, and a transition that needs two tokens
+        let place = lpn.add_place();
+        lpn.add_transition_place_arc(0, place, 1).unwrap();
+        let transition = lpn.add_transition(Some(activities[3]));
+        lpn.add_place_transition_arc(place, transition, 1).unwrap();
+        lpn.add_place_transition_arc(place, transition, 1).unwrap();
+        lpn.add_transition_place_arc(transition, places[2], 1).unwrap();
+        let report = lpn.check_soundness().unwrap();
+        assert!(!report.is_sound());
+        assert!(report.workflow_net_problems.is_empty());
+        assert!(report.unbounded_places.is_empty());
+        assert!(!report.improper_final_markings.is_empty());
+        assert_eq!(report.dead_transitions.len(), 1);
+
+        //a loop that keeps producing tokens
+        let loop_transition = lpn.add_transition(None);
+        lpn.add_place_transition_arc(places[1], loop_transition, 1).unwrap();
+        lpn.add_transition_place_arc(loop_transition, places[1], 1).unwrap();
+        lpn.add_transition_place_arc(loop_transition, place, 1).unwrap();
+        let report = lpn.check_soundness().unwrap();
+        assert!(!report.is_sound());
+        assert!(report.unbounded_places.contains(&place));
+        assert!(report.markings_without_option_to_complete.is_none());
+    }
+
     #[test]
     fn tree_semantics() {
         let fin = fs::read_to_string("testfiles/aa.ptree").unwrap();
@@ -967,6 +1765,25 @@ mod tests {
         assert!(tree.is_final_state(&state));
     }
 
+    #[test]
+    fn tree_termination_silent() {
+        let fin = fs::read_to_string("testfiles/aa.ptree").unwrap();
+        let mut tree = fin.parse::<ProcessTree>().unwrap();
+        let a = tree.get_activity_key_mut().process_activity("a");
+
+        //the last transition terminates the tree and has no activity, so it must be silent
+        let termination = tree.get_number_of_transitions() - 1;
+        assert!(!tree.is_transition_silent(0));
+        assert!(tree.is_transition_silent(termination));
+        assert_eq!(tree.get_transition_activity(termination), None);
+
+        //aligning a trace ends with a model move on the termination, which must be a silent move
+        let semantics = EbiTraitSemantics::NodeStates(Box::new(tree));
+        let (alignment, cost) = semantics.align_trace(&vec![a, a]).unwrap();
+        assert_eq!(cost, 1);
+        assert_eq!(alignment.last(), Some(&Move::SilentMove(termination)));
+    }
+
     #[test]
     fn tree_semantics_3() {
         let fin = fs::read_to_string("testfiles/all_operators.ptree").unwrap();
@@ -1034,6 +1851,56 @@ mod tests {
         assert!(tree.is_final_state(&state));
     }
 
+    #[test]
+    fn stochastic_directly_follows_model() {
+        let fin = fs::read_to_string("testfiles/a-b_star.sdfm").unwrap();
+        let mut sdfm = fin.parse::<StochasticDirectlyFollowsModel>().unwrap();
+        let mut sdfa = sdfm.get_stochastic_deterministic_finite_automaton().unwrap();
+
+        //after each b, another b follows with 1/3
+        for (trace, probability) in [(vec!["a", "b"], (2, 3)), (vec!["a", "b", "b"], (2, 9)), (vec!["a"], (0, 1)), (vec!["b"], (0, 1))] {
+            let sdfm_trace = sdfm.get_activity_key_mut().process_trace_ref(&trace);
+            assert_eq!(sdfm.get_probability(&FollowerSemantics::Trace(&sdfm_trace)).unwrap(), Fraction::from(probability));
+            let sdfa_trace = sdfa.get_activity_key_mut().process_trace_ref(&trace);
+            assert_eq!(sdfa.get_probability(&FollowerSemantics::Trace(&sdfa_trace)).unwrap(), Fraction::from(probability));
+        }
+        assert_eq!(sdfm.to_string().parse::<StochasticDirectlyFollowsModel>().unwrap().to_string(), sdfm.to_string());
+
+        //summarise a log
+        let slang = fs::read_to_string("testfiles/aa-ab-ba.slang").unwrap().parse::<FiniteStochasticLanguage>().unwrap();
+        let mut sdfm = StochasticDirectlyFollowsModel::from_finite_stochastic_language(&slang);
+        assert_eq!(sdfm.get_number_of_edges(), 3);
+        let trace = sdfm.get_activity_key_mut().process_trace_ref(&vec!["a", "a"]);
+        assert_eq!(sdfm.get_probability(&FollowerSemantics::Trace(&trace)).unwrap(), Fraction::from((2, 45)));
+
+        assert!("stochastic directly follows model\n0\n2\na\na\n0\n0\n0".parse::<StochasticDirectlyFollowsModel>().is_err());
+    }
+
+    #[test]
+    fn discover_directly_follows() {
+        let fin = fs::read_to_string("testfiles/aa-ab-ba.lang").unwrap();
+        let lang: Box<dyn EbiTraitFiniteLanguage> = Box::new(fin.parse::<FiniteLanguage>().unwrap());
+
+        let dfm = lang.mine_directly_follows(&Fraction::zero(), &Fraction::zero()).unwrap();
+        assert_eq!(dfm.get_number_of_nodes(), 2);
+        assert_eq!(dfm.get_number_of_edges(), 3);
+        assert_eq!(dfm.start_nodes.len(), 2);
+        assert_eq!(dfm.end_nodes.len(), 2);
+        assert!(!dfm.empty_traces);
+
+        //b occurs in half as many events as a
+        let dfm = lang.mine_directly_follows(&Fraction::from((2, 3)), &Fraction::zero()).unwrap();
+        assert_eq!(dfm.get_number_of_nodes(), 1);
+        assert_eq!(dfm.get_number_of_edges(), 1);
+
+        //after filtering b, a follows a once and a ends three times
+        let dfm = lang.mine_directly_follows(&Fraction::from((2, 3)), &Fraction::from((1, 2))).unwrap();
+        assert_eq!(dfm.get_number_of_edges(), 0);
+        assert_eq!(dfm.end_nodes.len(), 1);
+
+        assert!(lang.mine_directly_follows(&Fraction::from(2), &Fraction::zero()).is_err());
+    }
+
     #[test]
     fn dfm_semantics() {
         let fin = fs::read_to_string("testfiles/a-b_star.dfm").unwrap();
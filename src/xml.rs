@@ -0,0 +1,6 @@
+/**
+ * Escapes the characters that have a special meaning in XML attributes and text.
+ */
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;").replace('\'', "&apos;")
+}
@@ -0,0 +1,101 @@
+{
+    "version": 1,
+    "executions": [
+        {
+            "trace": 0,
+            "transition": 0,
+            "enabled_transitions_at_enablement": [
+                0,
+                1
+            ],
+            "time_of_enablement": null,
+            "time_of_execution": "2024-01-01T10:00:00Z",
+            "features_at_enablement": {
+                "trace:concept:name": "1",
+                "trace:priority": "high"
+            }
+        },
+        {
+            "trace": 0,
+            "transition": 3,
+            "enabled_transitions_at_enablement": [
+                2,
+                3
+            ],
+            "time_of_enablement": "2024-01-01T10:00:00Z",
+            "time_of_execution": "2024-01-01T11:00:00Z",
+            "features_at_enablement": {
+                "event:concept:name": "a",
+                "event:cost": 10.0,
+                "event:resource": "Ann",
+                "event:time:timestamp": "2024-01-01T10:00:00+00:00",
+                "trace:concept:name": "1",
+                "trace:priority": "high"
+            }
+        },
+        {
+            "trace": 1,
+            "transition": 0,
+            "enabled_transitions_at_enablement": [
+                0,
+                1
+            ],
+            "time_of_enablement": null,
+            "time_of_execution": "2024-01-01T09:00:00Z",
+            "features_at_enablement": {
+                "trace:concept:name": "2",
+                "trace:priority": "low"
+            }
+        },
+        {
+            "trace": 1,
+            "transition": 3,
+            "enabled_transitions_at_enablement": [
+                2,
+                3
+            ],
+            "time_of_enablement": "2024-01-01T09:00:00Z",
+            "time_of_execution": "2024-01-02T09:00:00Z",
+            "features_at_enablement": {
+                "event:concept:name": "a",
+                "event:cost": 5.5,
+                "event:resource": "Bob; Jr.",
+                "event:time:timestamp": "2024-01-01T09:00:00+00:00",
+                "trace:concept:name": "2",
+                "trace:priority": "low"
+            }
+        },
+        {
+            "trace": 2,
+            "transition": 0,
+            "enabled_transitions_at_enablement": [
+                0,
+                1
+            ],
+            "time_of_enablement": null,
+            "time_of_execution": "2024-01-03T07:00:00Z",
+            "features_at_enablement": {
+                "trace:concept:name": "3",
+                "trace:priority": "low"
+            }
+        },
+        {
+            "trace": 2,
+            "transition": 3,
+            "enabled_transitions_at_enablement": [
+                2,
+                3
+            ],
+            "time_of_enablement": "2024-01-03T07:00:00Z",
+            "time_of_execution": "2024-01-03T08:00:00Z",
+            "features_at_enablement": {
+                "event:concept:name": "a",
+                "event:cost": 1.0,
+                "event:resource": "Bob; Jr.",
+                "event:time:timestamp": "2024-01-03T07:00:00+00:00",
+                "trace:concept:name": "3",
+                "trace:priority": "low"
+            }
+        }
+    ]
+}